@@ -0,0 +1,969 @@
+use soroban_sdk::{symbol_short, Address, Bytes, Env, Symbol, TryFromVal, Vec};
+
+use crate::errors::Error;
+use crate::leaderboard;
+use crate::payout;
+use crate::quest;
+use crate::reputation;
+use crate::storage;
+use crate::types::{
+    Badge, BadgeTier, LegacyUserStats, PlatformSnapshot, StorageMetrics, SubmissionBackup,
+    SubmissionStatus, TtlHealthReport, UserStats,
+};
+use crate::validation;
+
+/// Contract version published in the `version` metadata entry
+pub const CONTRACT_VERSION: &[u8] = b"1.0.0";
+
+/// Feature bits published in the `features` metadata entry (big-endian u32)
+pub const FEATURE_REFERRALS: u32 = 1 << 0;
+pub const FEATURE_AUTO_APPROVE: u32 = 1 << 1;
+pub const FEATURE_QUEST_STREAMS: u32 = 1 << 2;
+pub const FEATURE_CREATION_FEES: u32 = 1 << 3;
+pub const FEATURE_SEASONS: u32 = 1 << 4;
+pub const FEATURE_WHITELISTS: u32 = 1 << 5;
+pub const FEATURE_PERMITS: u32 = 1 << 6;
+
+/// Features enabled in this build
+pub const ENABLED_FEATURES: u32 = FEATURE_REFERRALS
+    | FEATURE_AUTO_APPROVE
+    | FEATURE_QUEST_STREAMS
+    | FEATURE_CREATION_FEES
+    | FEATURE_SEASONS
+    | FEATURE_WHITELISTS
+    | FEATURE_PERMITS;
+
+/// Initialize the contract with an admin (one-time)
+pub fn initialize(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+
+    if storage::get_admin(env).is_some() {
+        return Err(Error::AlreadyInitialized);
+    }
+
+    storage::set_admin(env, admin);
+    storage::set_deployment_ledger(env, env.ledger().sequence());
+
+    // Publish version and feature flags for client discovery
+    let mut metadata = storage::get_contract_metadata(env);
+    metadata.set(
+        Symbol::new(env, "version"),
+        Bytes::from_slice(env, CONTRACT_VERSION),
+    );
+    metadata.set(
+        Symbol::new(env, "features"),
+        Bytes::from_array(env, &ENABLED_FEATURES.to_be_bytes()),
+    );
+    storage::set_contract_metadata(env, &metadata);
+    Ok(())
+}
+
+/// Set a contract metadata entry such as `version` or `audit_url` (admin only)
+pub fn set_contract_metadata(
+    env: &Env,
+    admin: &Address,
+    key: Symbol,
+    value: Bytes,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let mut metadata = storage::get_contract_metadata(env);
+    metadata.set(key.clone(), value);
+    storage::set_contract_metadata(env, &metadata);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "metadata_set"), key), admin.clone());
+    Ok(())
+}
+
+/// Get a contract metadata entry
+pub fn get_contract_metadata(env: &Env, key: &Symbol) -> Option<Bytes> {
+    storage::get_contract_metadata(env).get(key.clone())
+}
+
+/// Get the keys of all contract metadata entries
+pub fn get_all_metadata_keys(env: &Env) -> Vec<Symbol> {
+    storage::get_contract_metadata(env).keys()
+}
+
+/// Require that the caller is the contract admin
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    match storage::get_admin(env) {
+        Some(admin) if admin == *caller => Ok(()),
+        _ => Err(Error::Unauthorized),
+    }
+}
+
+/// Seconds a proposed admin must wait before accepting the role (48 hours)
+pub const ADMIN_ROTATION_TIMELOCK: u64 = 48 * 3600;
+
+/// Propose handing the admin role to `new_admin` (admin only). The new admin can accept
+/// once `ADMIN_ROTATION_TIMELOCK` has passed; a new proposal replaces a pending one.
+/// Returns the timestamp from which the rotation can be confirmed.
+pub fn propose_admin_rotation(
+    env: &Env,
+    current_admin: &Address,
+    new_admin: &Address,
+) -> Result<u64, Error> {
+    require_admin(env, current_admin)?;
+
+    let effective_at = env
+        .ledger()
+        .timestamp()
+        .saturating_add(ADMIN_ROTATION_TIMELOCK);
+    storage::set_pending_admin_rotation(env, new_admin, effective_at);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "rotation_proposed"), current_admin.clone()),
+        (new_admin.clone(), effective_at),
+    );
+
+    Ok(effective_at)
+}
+
+/// Accept a proposed admin rotation (proposed admin only, after the timelock)
+pub fn confirm_admin_rotation(env: &Env, new_admin: &Address) -> Result<(), Error> {
+    // Verify new admin authorization
+    new_admin.require_auth();
+
+    let (proposed, effective_at) =
+        storage::get_pending_admin_rotation(env).ok_or(Error::Unauthorized)?;
+    if proposed != *new_admin {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().timestamp() < effective_at {
+        return Err(Error::RotationTimelockActive);
+    }
+
+    storage::set_admin(env, new_admin);
+    storage::remove_pending_admin_rotation(env);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "rotation_confirmed"),), new_admin.clone());
+
+    Ok(())
+}
+
+/// Withdraw a pending admin rotation (admin only)
+pub fn cancel_admin_rotation(env: &Env, current_admin: &Address) -> Result<(), Error> {
+    require_admin(env, current_admin)?;
+
+    if storage::get_pending_admin_rotation(env).is_some() {
+        storage::remove_pending_admin_rotation(env);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "rotation_cancelled"),),
+            current_admin.clone(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Get the pending admin rotation as (new_admin, effective_at)
+pub fn get_pending_rotation(env: &Env) -> Option<(Address, u64)> {
+    storage::get_pending_admin_rotation(env)
+}
+
+/// Runtime toggle for inline proof documents on submissions
+pub const FLAG_INLINE_DOCUMENTS: Symbol = symbol_short!("inline_dc");
+/// Runtime toggle for publishing verification templates
+pub const FLAG_VERIFICATION_TEMPLATES: Symbol = symbol_short!("templates");
+/// Runtime toggle for verifier review claims
+pub const FLAG_REVIEW_CLAIMS: Symbol = symbol_short!("rev_claim");
+
+/// Enable or disable a runtime feature toggle (admin only)
+pub fn set_feature_flag(
+    env: &Env,
+    admin: &Address,
+    flag: Symbol,
+    enabled: bool,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let mut flags = storage::get_feature_flags(env);
+    flags.set(flag.clone(), enabled);
+    storage::set_feature_flags(env, &flags);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "feature_flag_set"), flag), enabled);
+
+    Ok(())
+}
+
+/// Check whether a runtime feature toggle is on. Unset flags are on for features that shipped
+/// before toggles existed (inline documents, templates, review claims) and off otherwise.
+pub fn is_feature_enabled(env: &Env, flag: &Symbol) -> bool {
+    storage::get_feature_flags(env)
+        .get(flag.clone())
+        .unwrap_or_else(|| is_shipped_feature(flag))
+}
+
+/// Whether a toggle guards a feature that was already live when toggles were introduced
+fn is_shipped_feature(flag: &Symbol) -> bool {
+    *flag == FLAG_INLINE_DOCUMENTS
+        || *flag == FLAG_VERIFICATION_TEMPLATES
+        || *flag == FLAG_REVIEW_CLAIMS
+}
+
+/// Reject calls into a feature that has been switched off
+pub fn require_feature_enabled(env: &Env, flag: &Symbol) -> Result<(), Error> {
+    if !is_feature_enabled(env, flag) {
+        return Err(Error::FeatureDisabled);
+    }
+    Ok(())
+}
+
+/// Set the ledger from which quests may be created (admin only, can only move forward)
+pub fn set_go_live_ledger(env: &Env, admin: &Address, ledger: u32) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    // Go-live ledger cannot be decreased once set
+    if let Some(current) = storage::get_go_live_ledger(env) {
+        if ledger < current {
+            return Err(Error::InvalidConfig);
+        }
+    }
+
+    storage::set_go_live_ledger(env, ledger);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "go_live_set"),), ledger);
+
+    Ok(())
+}
+
+/// Check whether the contract has reached its go-live ledger
+pub fn is_live(env: &Env) -> bool {
+    match storage::get_go_live_ledger(env) {
+        Some(ledger) => env.ledger().sequence() >= ledger,
+        None => true,
+    }
+}
+
+/// Get the ledger sequence at which the contract was initialized
+pub fn get_deployment_ledger(env: &Env) -> Option<u32> {
+    storage::get_deployment_ledger(env)
+}
+
+/// Copy an approved or paid submission into instance storage (admin only)
+pub fn backup_submission_to_instance(
+    env: &Env,
+    admin: &Address,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    // Instance storage is limited, so only settled submissions are backed up
+    if !submission.status.is_approved() && submission.status != SubmissionStatus::Paid {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    let backup = SubmissionBackup {
+        proof_hash: submission.proof_hash,
+        status: submission.status,
+        timestamp: submission.timestamp,
+    };
+    storage::set_submission_backup(env, quest_id, submitter, &backup);
+
+    Ok(())
+}
+
+/// Seconds after quest creation during which cancelling refunds the creation fee
+pub const CREATION_FEE_REFUND_WINDOW: u64 = 3600;
+
+/// Set the fee creators pay to register a quest (admin only, 0 disables)
+pub fn set_quest_creation_fee(
+    env: &Env,
+    admin: &Address,
+    fee: i128,
+    asset: &Address,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    if fee < 0 {
+        return Err(Error::InvalidConfig);
+    }
+
+    storage::set_creation_fee(env, fee, asset);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "creation_fee"),), (fee, asset.clone()));
+
+    Ok(())
+}
+
+/// Get the quest creation fee and its asset, if configured
+pub fn get_creation_fee(env: &Env) -> Option<(i128, Address)> {
+    storage::get_creation_fee(env)
+}
+
+/// Set the address receiving platform fees (admin only)
+pub fn set_treasury(env: &Env, admin: &Address, treasury: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+    storage::set_treasury(env, treasury);
+    Ok(())
+}
+
+/// Get the treasury address, defaulting to the admin
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    storage::get_treasury(env).or_else(|| storage::get_admin(env))
+}
+
+/// Accept an additional URI scheme for proofs, e.g. `ipns://` (admin only)
+pub fn add_allowed_uri_scheme(env: &Env, admin: &Address, scheme: Bytes) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    // Validate scheme
+    if scheme.is_empty() || !validation::is_printable_ascii(&scheme) {
+        return Err(Error::InvalidProofUri);
+    }
+
+    let mut schemes = validation::allowed_uri_schemes(env);
+    if !schemes.contains(&scheme) {
+        schemes.push_back(scheme);
+        storage::set_allowed_uri_schemes(env, &schemes);
+    }
+    Ok(())
+}
+
+/// List the URI schemes accepted for proofs
+pub fn list_allowed_uri_schemes(env: &Env) -> Vec<Bytes> {
+    validation::allowed_uri_schemes(env)
+}
+
+/// Set the XP granted at referral count milestones as (referral_count, xp) (admin only).
+/// Counts must be non-zero and strictly increasing.
+pub fn set_referral_milestone_xp(
+    env: &Env,
+    admin: &Address,
+    milestones: Vec<(u32, u32)>,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    // Validate milestones
+    let mut previous = 0;
+    for (count, _) in milestones.iter() {
+        if count <= previous {
+            return Err(Error::InvalidConfig);
+        }
+        previous = count;
+    }
+
+    storage::set_referral_milestone_xp(env, &milestones);
+    Ok(())
+}
+
+/// Configure XP decay for inactive users (admin only). Every `decay_interval_days` without
+/// activity removes `decay_rate_bps` of a user's remaining XP; a rate of 0 disables decay.
+pub fn configure_xp_decay(
+    env: &Env,
+    admin: &Address,
+    decay_rate_bps: u32,
+    decay_interval_days: u32,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    if decay_rate_bps > 10_000 || (decay_rate_bps > 0 && decay_interval_days == 0) {
+        return Err(Error::InvalidConfig);
+    }
+
+    storage::set_xp_decay_config(env, decay_rate_bps, decay_interval_days);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "xp_decay_set"),),
+        (decay_rate_bps, decay_interval_days),
+    );
+
+    Ok(())
+}
+
+/// Freeze a user's account pending an investigation (admin only). Unlike the fraud
+/// registry, a freeze is temporary and also holds payouts of approved submissions.
+pub fn freeze_user(env: &Env, admin: &Address, user: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let mut frozen = storage::get_frozen_users(env);
+    if !frozen.contains(user) {
+        frozen.push_back(user.clone());
+        storage::set_frozen_users(env, &frozen);
+    }
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "user_frozen"),), user.clone());
+
+    Ok(())
+}
+
+/// Lift the freeze on a user's account, making held payouts claimable again (admin only)
+pub fn unfreeze_user(env: &Env, admin: &Address, user: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let mut frozen = storage::get_frozen_users(env);
+    if let Some(index) = frozen.first_index_of(user) {
+        frozen.remove(index);
+        storage::set_frozen_users(env, &frozen);
+    }
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "user_unfrozen"),), user.clone());
+
+    Ok(())
+}
+
+/// Get the users whose accounts are frozen
+pub fn get_frozen_users(env: &Env) -> Vec<Address> {
+    storage::get_frozen_users(env)
+}
+
+/// Check if a user's account is frozen
+pub fn is_user_frozen(env: &Env, user: &Address) -> bool {
+    storage::is_frozen(env, user)
+}
+
+/// Rebuild a verifier's pending queue entries for a quest from the quest's submissions
+/// (admin only). Entries are kept only for submissions awaiting review on a quest the
+/// verifier is assigned to. Returns the number of entries removed or added.
+pub fn reindex_verifier_queue(
+    env: &Env,
+    admin: &Address,
+    verifier: &Address,
+    quest_id: &Symbol,
+) -> Result<u32, Error> {
+    require_admin(env, admin)?;
+
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Submitters that should be queued for this verifier
+    let mut expected = Vec::new(env);
+    if quest.verifier == *verifier {
+        for submitter in storage::get_quest_submitters(env, quest_id).iter() {
+            let awaiting = storage::get_submission(env, quest_id, &submitter)
+                .map(|s| s.status.is_awaiting_review())
+                .unwrap_or(false);
+            if awaiting && !expected.contains(&submitter) {
+                expected.push_back(submitter);
+            }
+        }
+    }
+
+    // Drop stale and duplicate entries for the quest, keeping other quests untouched
+    let mut before = 0;
+    let mut corrected = 0;
+    let mut kept = Vec::new(env);
+    let mut queue = Vec::new(env);
+    for (id, submitter) in storage::get_verifier_pending(env, verifier).iter() {
+        if id != *quest_id {
+            queue.push_back((id, submitter));
+            continue;
+        }
+        before += 1;
+        if expected.contains(&submitter) && !kept.contains(&submitter) {
+            kept.push_back(submitter.clone());
+            queue.push_back((id, submitter));
+        } else {
+            corrected += 1;
+        }
+    }
+
+    // Queue missing entries
+    for submitter in expected.iter() {
+        if !kept.contains(&submitter) {
+            queue.push_back((quest_id.clone(), submitter));
+            corrected += 1;
+        }
+    }
+    storage::set_verifier_pending(env, verifier, &queue);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "queue_reindexed"), verifier.clone()),
+        (quest_id.clone(), before, expected.len()),
+    );
+
+    Ok(corrected)
+}
+
+/// Rebuild a user's submission index from the quest registry (admin only), listing each
+/// quest the user has a live or rejected submission for once, in registration order.
+/// Scans every quest. Returns the number of entries removed or added.
+pub fn reindex_user_submission_list(
+    env: &Env,
+    admin: &Address,
+    user: &Address,
+) -> Result<u32, Error> {
+    require_admin(env, admin)?;
+
+    let mut rebuilt = Vec::new(env);
+    for quest_id in storage::get_quest_ids(env).iter() {
+        let submitted = storage::get_submission(env, &quest_id, user).is_some()
+            || !storage::get_rejected_submissions(env, &quest_id, user).is_empty();
+        if submitted {
+            rebuilt.push_back(quest_id);
+        }
+    }
+
+    let current = storage::get_user_submissions(env, user);
+    let mut seen = Vec::new(env);
+    let mut corrected = 0;
+    for quest_id in current.iter() {
+        if !rebuilt.contains(&quest_id) || seen.contains(&quest_id) {
+            corrected += 1;
+        } else {
+            seen.push_back(quest_id);
+        }
+    }
+    corrected += rebuilt.len() - seen.len();
+    storage::set_user_submissions(env, user, &rebuilt);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "user_submissions_reindexed"), user.clone()),
+        (current.len(), rebuilt.len()),
+    );
+
+    Ok(corrected)
+}
+
+/// Add a verifier to the pool offered for quest assignment (admin only)
+pub fn register_verifier(env: &Env, admin: &Address, verifier: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let mut verifiers = storage::get_registered_verifiers(env);
+    if !verifiers.contains(verifier) {
+        verifiers.push_back(verifier.clone());
+        storage::set_registered_verifiers(env, &verifiers);
+    }
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "verifier_registered"),), verifier.clone());
+
+    Ok(())
+}
+
+/// Remove a verifier from the assignment pool (admin only)
+pub fn deregister_verifier(env: &Env, admin: &Address, verifier: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let mut verifiers = storage::get_registered_verifiers(env);
+    if let Some(index) = verifiers.first_index_of(verifier) {
+        verifiers.remove(index);
+        storage::set_registered_verifiers(env, &verifiers);
+    }
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "verifier_deregistered"),),
+        verifier.clone(),
+    );
+
+    Ok(())
+}
+
+/// Get the verifiers available for quest assignment, in registration order
+pub fn list_registered_verifiers(env: &Env) -> Vec<Address> {
+    storage::get_registered_verifiers(env)
+}
+
+/// Tokens of an asset held by the contract beyond what quest escrows, held creation
+/// fees and the referral pool account for, e.g. sent directly to the contract address
+pub fn get_orphaned_balance(env: &Env, asset: &Address) -> i128 {
+    let committed = storage::get_escrow_total(env, asset)
+        + storage::get_held_creation_fees(env, asset)
+        + storage::get_referral_pool(env, asset);
+    (payout::contract_balance(env, asset) - committed).max(0)
+}
+
+/// Transfer orphaned tokens of an asset to the treasury (admin only).
+/// Returns the amount recovered.
+pub fn drain_orphaned_escrow(env: &Env, admin: &Address, asset: &Address) -> Result<i128, Error> {
+    require_admin(env, admin)?;
+
+    let orphaned = get_orphaned_balance(env, asset);
+    if orphaned == 0 {
+        return Ok(0);
+    }
+
+    let treasury = get_treasury(env).unwrap_or_else(|| admin.clone());
+    payout::release_from_escrow(env, asset, &treasury, orphaned);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "orphaned_escrow_drained"), asset.clone()),
+        orphaned,
+    );
+
+    Ok(orphaned)
+}
+
+/// Number of quests a creator may create unless the admin configures otherwise
+pub const DEFAULT_MAX_QUESTS_PER_CREATOR: u32 = 100;
+
+/// Set the number of quests each creator may create (admin only)
+pub fn set_max_quests_per_creator(env: &Env, admin: &Address, limit: u32) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    storage::set_max_quests_per_creator(env, limit);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "creator_limit"),), limit);
+
+    Ok(())
+}
+
+/// Get the number of quests each creator may create
+pub fn get_max_quests_per_creator(env: &Env) -> u32 {
+    storage::get_max_quests_per_creator(env).unwrap_or(DEFAULT_MAX_QUESTS_PER_CREATOR)
+}
+
+/// Give a trusted creator a quest creation limit replacing the global one (admin only)
+pub fn override_creator_limit(
+    env: &Env,
+    admin: &Address,
+    creator: &Address,
+    limit: u32,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    storage::set_creator_limit_override(env, creator, limit);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "creator_limit_override"), creator.clone()),
+        limit,
+    );
+
+    Ok(())
+}
+
+/// Get the number of quests a creator may create, including any override
+pub fn get_creator_limit(env: &Env, creator: &Address) -> u32 {
+    storage::get_creator_limit_override(env, creator)
+        .unwrap_or_else(|| get_max_quests_per_creator(env))
+}
+
+/// Set the review history a verifier needs before being assigned a new quest (admin only).
+/// `min_on_time_pct` is the minimum percentage of reviews completed before timing out.
+pub fn set_verifier_quality_thresholds(
+    env: &Env,
+    admin: &Address,
+    min_reviewed: u32,
+    min_on_time_pct: u32,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    if min_on_time_pct > 100 {
+        return Err(Error::InvalidScore);
+    }
+    storage::set_verifier_quality_thresholds(env, min_reviewed, min_on_time_pct);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "verifier_thresholds"),),
+        (min_reviewed, min_on_time_pct),
+    );
+
+    Ok(())
+}
+
+/// Get the verifier quality thresholds as (min_reviewed, min_on_time_pct)
+pub fn get_verifier_quality_thresholds(env: &Env) -> (u32, u32) {
+    storage::get_verifier_quality_thresholds(env)
+}
+
+/// Let a trusted verifier be assigned quests regardless of review history (admin only)
+pub fn exempt_verifier(env: &Env, admin: &Address, verifier: Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    storage::set_verifier_exempt(env, &verifier);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "verifier_exempted"),), verifier);
+
+    Ok(())
+}
+
+/// Check that a verifier meets the quality thresholds, or is exempt from them.
+/// A verifier's on-time percentage is the share of reviews not lost to timeouts, 0 with no reviews.
+pub fn ensure_verifier_qualified(env: &Env, verifier: &Address) -> Result<(), Error> {
+    let (min_reviewed, min_on_time_pct) = storage::get_verifier_quality_thresholds(env);
+    if (min_reviewed == 0 && min_on_time_pct == 0) || storage::is_verifier_exempt(env, verifier) {
+        return Ok(());
+    }
+
+    let stats = storage::get_verifier_stats(env, verifier);
+    let on_time_pct = ((stats.total_reviewed - stats.total_timeouts) * 100)
+        .checked_div(stats.total_reviewed)
+        .unwrap_or(0);
+    if stats.total_reviewed < min_reviewed || on_time_pct < min_on_time_pct {
+        return Err(Error::VerifierInsufficientHistory);
+    }
+
+    Ok(())
+}
+
+/// Set the maximum submissions per user per day (admin only)
+pub fn set_max_daily_submissions(env: &Env, admin: &Address, limit: u32) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    storage::set_max_daily_submissions(env, limit);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "daily_limit"),), limit);
+
+    Ok(())
+}
+
+/// Estimate the persistent storage used by all quests on the platform, in bytes
+pub fn get_contract_storage_estimate(env: &Env) -> u64 {
+    let mut total = 0;
+    for quest_id in storage::get_quest_ids(env).iter() {
+        total += storage::estimate_storage_cost(env, &quest_id);
+    }
+    total
+}
+
+/// Get storage usage metrics for the contract (admin only)
+pub fn get_storage_metrics(env: &Env, admin: &Address) -> Result<StorageMetrics, Error> {
+    require_admin(env, admin)?;
+
+    Ok(StorageMetrics {
+        entry_count: storage::get_storage_entry_count(env),
+        estimated_bytes: get_contract_storage_estimate(env),
+        last_compaction_timestamp: storage::get_last_compaction_time(env),
+    })
+}
+
+/// Number of submission entries sampled by a TTL health check
+pub const TTL_SAMPLE_SIZE: u32 = 10;
+
+/// Report how close a quest's storage entries are to expiring (admin only).
+/// Checks the quest entry and samples its first `TTL_SAMPLE_SIZE` submissions.
+pub fn ttl_health_check(
+    env: &Env,
+    admin: &Address,
+    quest_id: &Symbol,
+) -> Result<TtlHealthReport, Error> {
+    require_admin(env, admin)?;
+
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    let sequence = env.ledger().sequence();
+
+    let quest_ttl_remaining = quest.live_until_ledger.saturating_sub(sequence);
+    let mut min_ttl_remaining = quest_ttl_remaining;
+    let mut entries_expiring_soon = 0;
+    if quest_ttl_remaining < storage::TTL_WARNING_THRESHOLD {
+        entries_expiring_soon += 1;
+    }
+
+    let submitters = storage::get_quest_submitters(env, quest_id);
+    for submitter in submitters.iter().take(TTL_SAMPLE_SIZE as usize) {
+        if let Some(submission) = storage::get_submission(env, quest_id, &submitter) {
+            let remaining = submission.live_until_ledger.saturating_sub(sequence);
+            min_ttl_remaining = min_ttl_remaining.min(remaining);
+            if remaining < storage::TTL_WARNING_THRESHOLD {
+                entries_expiring_soon += 1;
+            }
+        }
+    }
+
+    Ok(TtlHealthReport {
+        quest_ttl_remaining,
+        submission_count: submitters.len(),
+        entries_expiring_soon,
+        min_ttl_remaining,
+    })
+}
+
+/// End the current leaderboard season, archiving its top users (admin only)
+pub fn reset_monthly_leaderboard(env: &Env, admin: &Address) -> Result<(), Error> {
+    require_admin(env, admin)?;
+    leaderboard::reset_monthly_leaderboard(env);
+    Ok(())
+}
+
+/// Quest ID recorded on badges migrated from the legacy layout
+pub const LEGACY_BADGE_QUEST: &str = "legacy";
+
+/// Convert users' stats from the legacy `Vec<Symbol>` badge layout (admin only).
+/// Migrated badges are Bronze with an unknown award time. Returns the number of users migrated.
+pub fn migrate_badges(env: &Env, admin: &Address, users: Vec<Address>) -> Result<u32, Error> {
+    require_admin(env, admin)?;
+
+    if users.len() > MAX_EXPORT_BATCH {
+        return Err(Error::BatchTooLarge);
+    }
+
+    let mut migrated = 0;
+    for user in users.iter() {
+        let legacy = match storage::get_user_stats_raw(env, &user)
+            .and_then(|raw| LegacyUserStats::try_from_val(env, &raw).ok())
+        {
+            Some(legacy) => legacy,
+            None => continue,
+        };
+
+        // Current-layout stats have non-symbol badge entries; empty lists need no change
+        if legacy.badges.is_empty() || legacy.badges.try_iter().any(|b| b.is_err()) {
+            continue;
+        }
+
+        let mut badges = Vec::new(env);
+        for symbol in legacy.badges.iter() {
+            badges.push_back(Badge {
+                symbol,
+                quest_id: Symbol::new(env, LEGACY_BADGE_QUEST),
+                awarded_at: 0,
+                tier: BadgeTier::Bronze,
+            });
+        }
+
+        let stats = UserStats {
+            address: legacy.address,
+            total_xp: legacy.total_xp,
+            level: legacy.level,
+            quests_completed: legacy.quests_completed,
+            badges,
+        };
+        storage::set_user_stats(env, &stats);
+        migrated += 1;
+    }
+
+    Ok(migrated)
+}
+
+/// Maximum number of quest records converted per `run_storage_migration` call
+pub const MAX_MIGRATION_BATCH: u32 = 20;
+
+/// Rewrite quests stored in an earlier layout in the current one (admin only).
+/// Missing and already current quests are skipped. Returns the number of quests migrated.
+pub fn run_storage_migration(
+    env: &Env,
+    admin: &Address,
+    quest_ids: Vec<Symbol>,
+) -> Result<u32, Error> {
+    require_admin(env, admin)?;
+
+    if quest_ids.len() > MAX_MIGRATION_BATCH {
+        return Err(Error::BatchTooLarge);
+    }
+
+    let mut migrated = 0;
+    for quest_id in quest_ids.iter() {
+        if !storage::has_quest(env, &quest_id) || storage::is_quest_current(env, &quest_id) {
+            continue;
+        }
+        let quest = storage::get_quest_versioned(env, &quest_id)?;
+        storage::set_quest(env, &quest);
+        migrated += 1;
+    }
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "storage_migrated"),), migrated);
+
+    Ok(migrated)
+}
+
+/// Move a quest's pending submissions from its current verifier to a new one (admin only).
+/// Returns the number of submissions transferred.
+pub fn transfer_pending_queue(
+    env: &Env,
+    admin: &Address,
+    old_verifier: &Address,
+    new_verifier: &Address,
+    quest_id: &Symbol,
+) -> Result<u32, Error> {
+    require_admin(env, admin)?;
+
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    quest::ensure_not_archived(&quest)?;
+
+    // Verify the quest is currently assigned to old_verifier
+    if quest.verifier != *old_verifier {
+        return Err(Error::UnauthorizedVerifier);
+    }
+
+    let count = if old_verifier == new_verifier {
+        0
+    } else {
+        quest::reassign_verifier(env, &mut quest, new_verifier)
+    };
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "queue_transferred"), quest_id.clone()),
+        (old_verifier.clone(), new_verifier.clone(), count),
+    );
+
+    Ok(count)
+}
+
+/// Maximum number of users exported per batch
+pub const MAX_EXPORT_BATCH: u32 = 100;
+
+/// Number of top users included in a platform snapshot
+pub const SNAPSHOT_TOP_USERS: u32 = 10;
+
+/// Minimum seconds between platform snapshots
+pub const SNAPSHOT_COOLDOWN_SECS: u64 = 3600;
+
+/// Export stats for a batch of users (admin only). Users without stats get fresh stats.
+pub fn export_user_stats_batch(
+    env: &Env,
+    admin: &Address,
+    users: Vec<Address>,
+) -> Result<Vec<UserStats>, Error> {
+    require_admin(env, admin)?;
+
+    if users.len() > MAX_EXPORT_BATCH {
+        return Err(Error::BatchTooLarge);
+    }
+
+    let mut result = Vec::new(env);
+    for user in users.iter() {
+        result.push_back(reputation::get_or_init_stats(env, &user));
+    }
+
+    Ok(result)
+}
+
+/// Capture a snapshot of the platform state (admin only, rate limited)
+pub fn export_platform_snapshot(env: &Env, admin: &Address) -> Result<PlatformSnapshot, Error> {
+    require_admin(env, admin)?;
+
+    let now = env.ledger().timestamp();
+    if let Some(last) = storage::get_last_snapshot_time(env) {
+        if now < last.saturating_add(SNAPSHOT_COOLDOWN_SECS) {
+            return Err(Error::SnapshotCooldown);
+        }
+    }
+    storage::set_last_snapshot_time(env, now);
+
+    Ok(PlatformSnapshot {
+        stats: storage::get_platform_stats(env),
+        top_users: leaderboard::get_leaderboard(env, SNAPSHOT_TOP_USERS),
+        quest_count: storage::get_quest_ids(env).len(),
+        snapshot_timestamp: now,
+    })
+}
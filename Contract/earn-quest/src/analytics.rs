@@ -0,0 +1,107 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage;
+
+/// Number of seconds in one day, used to derive day epochs
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Maximum number of days that can be queried at once
+pub const MAX_QUERY_RANGE_DAYS: u64 = 90;
+
+/// Maximum number of days covered by a user activity heatmap
+pub const MAX_HEATMAP_DAYS: u32 = 365;
+
+/// Get the current day epoch (ledger timestamp / 86400)
+pub fn current_day(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECONDS_PER_DAY
+}
+
+/// Record a submission for a quest on the current day
+pub fn record_daily_submission(env: &Env, quest_id: &Symbol) {
+    let day = current_day(env);
+
+    // Increment today's counter
+    let count = storage::get_daily_submissions(env, quest_id, day) + 1;
+    storage::set_daily_submissions(env, quest_id, day, count);
+
+    // Track the peak day so it can be read without scanning
+    let is_new_peak = match storage::get_peak_submission_day(env, quest_id) {
+        Some((_, peak_count)) => count > peak_count,
+        None => true,
+    };
+    if is_new_peak {
+        storage::set_peak_submission_day(env, quest_id, day, count);
+    }
+}
+
+/// Record an approval for a quest on the current day
+pub fn record_daily_approval(env: &Env, quest_id: &Symbol) {
+    let day = current_day(env);
+    let count = storage::get_daily_approvals(env, quest_id, day) + 1;
+    storage::set_daily_approvals(env, quest_id, day, count);
+}
+
+/// Get the number of submissions for a quest over the last `days` days, including today
+pub fn get_recent_submissions(env: &Env, quest_id: &Symbol, days: u64) -> u32 {
+    let today = current_day(env);
+    let first_day = today.saturating_sub(days.saturating_sub(1));
+    (first_day..=today)
+        .map(|day| storage::get_daily_submissions(env, quest_id, day))
+        .sum()
+}
+
+/// Get (day_epoch, count) pairs for every day in `[from_day, to_day]` with at least one submission.
+/// The range is capped to `MAX_QUERY_RANGE_DAYS` days starting at `from_day`.
+pub fn get_daily_submission_counts(
+    env: &Env,
+    quest_id: &Symbol,
+    from_day: u64,
+    to_day: u64,
+) -> Vec<(u64, u32)> {
+    let mut counts = Vec::new(env);
+    if from_day > to_day {
+        return counts;
+    }
+
+    // Cap the query range
+    let last_day = to_day.min(from_day.saturating_add(MAX_QUERY_RANGE_DAYS - 1));
+
+    for day in from_day..=last_day {
+        let count = storage::get_daily_submissions(env, quest_id, day);
+        if count > 0 {
+            counts.push_back((day, count));
+        }
+    }
+
+    counts
+}
+
+/// Get the day with the highest number of submissions for a quest
+pub fn get_peak_submission_day(env: &Env, quest_id: &Symbol) -> Option<(u64, u32)> {
+    storage::get_peak_submission_day(env, quest_id)
+}
+
+/// Record a submission by a user on the current day
+pub fn record_user_activity(env: &Env, user: &Address) {
+    let day = current_day(env);
+    let count = storage::get_user_daily_activity(env, user, day) + 1;
+    storage::set_user_daily_activity(env, user, day, count);
+}
+
+/// Get (day_epoch, count) pairs for the last `days` days up to and including today, oldest first.
+/// Days without submissions are included with a count of zero. Capped to `MAX_HEATMAP_DAYS`.
+pub fn get_user_activity_heatmap(env: &Env, user: &Address, days: u32) -> Vec<(u64, u32)> {
+    let mut heatmap = Vec::new(env);
+    let days = days.min(MAX_HEATMAP_DAYS) as u64;
+    if days == 0 {
+        return heatmap;
+    }
+
+    let today = current_day(env);
+    let first_day = today.saturating_sub(days - 1);
+    for day in first_day..=today {
+        heatmap.push_back((day, storage::get_user_daily_activity(env, user, day)));
+    }
+
+    heatmap
+}
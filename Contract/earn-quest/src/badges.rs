@@ -0,0 +1,143 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+use crate::admin;
+use crate::errors::Error;
+use crate::quest;
+use crate::reputation;
+use crate::storage;
+use crate::types::{Badge, BadgeTier, PlatformBadge, Quest, UserStats};
+
+/// Award a badge to a user (admin only)
+pub fn award_badge(
+    env: &Env,
+    address: &Address,
+    symbol: Symbol,
+    quest_id: Symbol,
+    tier: BadgeTier,
+    admin: &Address,
+) -> Result<(), Error> {
+    // Verify admin authorization
+    admin::require_admin(env, admin)?;
+
+    // Get user stats
+    let mut stats = storage::get_user_stats(env, address).ok_or(Error::UserStatsNotFound)?;
+
+    // Platform badges can only be issued up to their supply
+    let holds_badge = stats.badges.iter().any(|b| b.symbol == symbol);
+    let mut platform_badges = storage::get_platform_badges(env);
+    if let Some(mut platform_badge) = platform_badges.get(symbol.clone()) {
+        if !holds_badge {
+            if platform_badge.issued >= platform_badge.max_supply {
+                return Err(Error::BadgeSupplyExhausted);
+            }
+            platform_badge.issued += 1;
+            platform_badges.set(symbol.clone(), platform_badge);
+            storage::set_platform_badges(env, &platform_badges);
+        }
+    }
+
+    add_badge(env, &mut stats, symbol, quest_id, tier);
+
+    Ok(())
+}
+
+/// Create or update a curated badge limited to `max_supply` holders (admin only).
+/// Updating keeps the issued count, so the supply cannot drop below it.
+pub fn create_platform_badge(
+    env: &Env,
+    admin: &Address,
+    symbol: Symbol,
+    description_hash: BytesN<32>,
+    max_supply: u32,
+) -> Result<(), Error> {
+    admin::require_admin(env, admin)?;
+
+    let mut platform_badges = storage::get_platform_badges(env);
+    let issued = platform_badges
+        .get(symbol.clone())
+        .map(|b| b.issued)
+        .unwrap_or(0);
+    if max_supply < issued {
+        return Err(Error::BadgeSupplyExhausted);
+    }
+
+    let platform_badge = PlatformBadge {
+        symbol: symbol.clone(),
+        description_hash,
+        max_supply,
+        issued,
+    };
+    platform_badges.set(symbol.clone(), platform_badge);
+    storage::set_platform_badges(env, &platform_badges);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "platform_badge"), symbol), max_supply);
+
+    Ok(())
+}
+
+/// Get a curated platform badge
+pub fn get_platform_badge(env: &Env, symbol: &Symbol) -> Option<PlatformBadge> {
+    storage::get_platform_badges(env).get(symbol.clone())
+}
+
+/// List the symbols of all curated platform badges
+pub fn list_platform_badges(env: &Env) -> Vec<Symbol> {
+    storage::get_platform_badges(env).keys()
+}
+
+/// Award the `sponsored_by_<sponsor_name>` badge of a sponsored quest to a paid submitter
+pub fn award_sponsor_badge(env: &Env, address: &Address, quest: &Quest) {
+    let symbol = match &quest.sponsor_name {
+        Some(name) => match quest::sponsor_badge_symbol(env, name) {
+            Ok(symbol) => symbol,
+            Err(_) => return,
+        },
+        None => return,
+    };
+
+    let mut stats = reputation::get_or_init_stats(env, address);
+    add_badge(env, &mut stats, symbol, quest.id.clone(), BadgeTier::Bronze);
+}
+
+/// Add a badge to a user's stats unless they already hold it
+fn add_badge(env: &Env, stats: &mut UserStats, symbol: Symbol, quest_id: Symbol, tier: BadgeTier) {
+    if !stats.badges.iter().any(|b| b.symbol == symbol) {
+        let badge = Badge {
+            symbol: symbol.clone(),
+            quest_id,
+            awarded_at: env.ledger().timestamp(),
+            tier,
+        };
+        stats.badges.push_back(badge);
+        storage::set_user_stats(env, stats);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "badge_grant"), stats.address.clone()),
+            symbol,
+        );
+    }
+}
+
+/// Get a user's badge by symbol
+pub fn get_badge(env: &Env, user: &Address, symbol: &Symbol) -> Option<Badge> {
+    storage::get_user_stats(env, user)?
+        .badges
+        .iter()
+        .find(|b| b.symbol == *symbol)
+}
+
+/// Get a user's badges of a given tier
+pub fn get_badges_by_tier(env: &Env, user: &Address, tier: BadgeTier) -> Vec<Badge> {
+    let mut badges = Vec::new(env);
+    if let Some(stats) = storage::get_user_stats(env, user) {
+        for badge in stats.badges.iter() {
+            if badge.tier == tier {
+                badges.push_back(badge);
+            }
+        }
+    }
+    badges
+}
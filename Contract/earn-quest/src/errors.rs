@@ -16,13 +16,43 @@ pub enum Error {
     SubmissionAlreadyExists = 10,
     InvalidSubmissionStatus = 11,
     UserStatsNotFound = 12,
-    InvalidQuestStatus = 16,
-    BadgeAlreadyGranted = 17,
-    UserNotFound = 18,
-    DuplicateSubmission = 19,
-    InvalidProofHash = 20,
     UnauthorizedVerifier = 13,
     InvalidStatusTransition = 14,
     SubmissionAlreadyProcessed = 15,
+    // Codes 16-19 were never returned and are retired; `contracterror` allows at most 50 cases
+    InvalidProofHash = 20,
     InvalidDeadline = 21,
+    AlreadyInitialized = 22,
+    DailyLimitExceeded = 23,
+    InsufficientEscrow = 24,
+    UserBanned = 25,
+    StreamNotFound = 27,
+    BatchTooLarge = 28,
+    SnapshotCooldown = 29,
+    ContractNotLive = 30,
+    InvalidConfig = 31,
+    InvalidReferrer = 32,
+    ReferrerAlreadySet = 33,
+    InvalidProofUri = 35,
+    NotWhitelisted = 36,
+    PermitExpired = 37,
+    PermitAlreadyUsed = 38,
+    MinCompletionsNotReached = 40,
+    UnsettledSubmissions = 41,
+    SymbolTooLong = 42,
+    QuestArchived = 44,
+    AlreadyApproved = 47,
+    UserFrozen = 48,
+    StatusMismatch = 49,
+    InvalidScore = 50,
+    QuestCreationLimitReached = 51,
+    TemplateNotFound = 52,
+    BadgeSupplyExhausted = 53,
+    WhitelistSealed = 55,
+    RotationTimelockActive = 56,
+    ReviewLockActive = 57,
+    FeatureDisabled = 58,
+    VerifierInsufficientHistory = 59,
+    SeriesNotFound = 60,
+    QuestDecodeFailed = 61,
 }
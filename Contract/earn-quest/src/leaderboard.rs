@@ -0,0 +1,194 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::analytics;
+use crate::reputation;
+use crate::storage;
+
+/// Maximum number of users tracked on the leaderboard
+pub const LEADERBOARD_SIZE: u32 = 100;
+
+/// Maximum number of days covered by a rank history query, and how far back a rank is looked up
+pub const MAX_RANK_HISTORY_DAYS: u32 = 90;
+
+/// Number of top users archived when a monthly season ends
+pub const MONTHLY_ARCHIVE_SIZE: u32 = 10;
+
+/// Move a user to their sorted position on a leaderboard, dropping them at zero XP
+fn place_on_board(board: &mut Vec<(Address, u32)>, user: &Address, xp: u32) {
+    // Remove the user's previous entry
+    if let Some(index) = board.iter().position(|(addr, _)| addr == *user) {
+        board.remove(index as u32);
+    }
+
+    // Insert at the sorted position (ties keep earlier entries first)
+    if xp > 0 {
+        let index = board
+            .iter()
+            .position(|(_, entry_xp)| entry_xp < xp)
+            .map(|i| i as u32)
+            .unwrap_or(board.len());
+        if index < LEADERBOARD_SIZE {
+            board.insert(index, (user.clone(), xp));
+        }
+    }
+
+    while board.len() > LEADERBOARD_SIZE {
+        board.pop_back();
+    }
+}
+
+/// Update a user's position on the XP leaderboard
+pub fn update_leaderboard(env: &Env, user: &Address, total_xp: u32) {
+    let mut board = storage::get_leaderboard(env);
+    place_on_board(&mut board, user, total_xp);
+    storage::set_leaderboard(env, &board);
+
+    // Snapshot the user's rank for today's history
+    let rank = board
+        .iter()
+        .position(|(addr, _)| addr == *user)
+        .map(|i| i as u32 + 1);
+    storage::set_user_rank_snapshot(env, user, analytics::current_day(env), rank);
+}
+
+/// Get the top `n` users by XP
+pub fn get_leaderboard(env: &Env, n: u32) -> Vec<(Address, u32)> {
+    let board = storage::get_leaderboard(env);
+    board.slice(0..n.min(board.len()))
+}
+
+/// Get a user's 1-based leaderboard rank, if they are on the leaderboard
+pub fn get_user_rank(env: &Env, user: &Address) -> Option<u32> {
+    storage::get_leaderboard(env)
+        .iter()
+        .position(|(addr, _)| addr == *user)
+        .map(|i| i as u32 + 1)
+}
+
+/// Get a user's rank as of the end of `day`: the latest snapshot within
+/// `MAX_RANK_HISTORY_DAYS` days, or `None` if unranked or not found
+fn get_rank_on_day(env: &Env, user: &Address, day: u64) -> Option<u32> {
+    let first_day = day.saturating_sub(MAX_RANK_HISTORY_DAYS as u64 - 1);
+    (first_day..=day)
+        .rev()
+        .find_map(|d| storage::get_user_rank_snapshot(env, user, d))
+        .flatten()
+}
+
+/// Get (day_epoch, rank) pairs for the last `days` days up to and including today, oldest
+/// first. A day's rank is the one after the user's last XP change on or before it, `None`
+/// when outside the top `LEADERBOARD_SIZE`. Capped to `MAX_RANK_HISTORY_DAYS`.
+pub fn get_user_rank_history(env: &Env, user: &Address, days: u32) -> Vec<(u64, Option<u32>)> {
+    let mut history = Vec::new(env);
+    let days = days.min(MAX_RANK_HISTORY_DAYS) as u64;
+    if days == 0 {
+        return history;
+    }
+
+    let today = analytics::current_day(env);
+    let first_day = today.saturating_sub(days - 1);
+    let mut rank = get_rank_on_day(env, user, first_day);
+    history.push_back((first_day, rank));
+    for day in first_day + 1..=today {
+        if let Some(snapshot) = storage::get_user_rank_snapshot(env, user, day) {
+            rank = snapshot;
+        }
+        history.push_back((day, rank));
+    }
+
+    history
+}
+
+/// Get the signed change in a user's rank over the last 7 days: negative when the user
+/// climbed, positive when they dropped. Being unranked counts as rank `LEADERBOARD_SIZE + 1`.
+pub fn get_rank_change_7d(env: &Env, user: &Address) -> i32 {
+    let unranked = LEADERBOARD_SIZE + 1;
+    let week_ago = analytics::current_day(env).saturating_sub(7);
+    let previous = get_rank_on_day(env, user, week_ago).unwrap_or(unranked);
+    let current = get_user_rank(env, user).unwrap_or(unranked);
+    current as i32 - previous as i32
+}
+
+/// Percentiles reported by `get_xp_distribution`
+pub const DISTRIBUTION_PERCENTILES: [u32; 7] = [10, 25, 50, 75, 90, 95, 99];
+
+/// Get a user's XP percentile in basis points, as `(rank - 1) * 10000 / total_users`,
+/// so the top user is at 0. Approximate: users off the leaderboard also get 0.
+pub fn get_xp_percentile(env: &Env, user: &Address) -> u32 {
+    let total_users = reputation::get_total_user_count(env);
+    match get_user_rank(env, user) {
+        Some(rank) if total_users > 0 => ((rank - 1) as u64 * 10_000 / total_users as u64) as u32,
+        _ => 0,
+    }
+}
+
+/// Get (xp_threshold, user_count) for each of `DISTRIBUTION_PERCENTILES`, where `user_count`
+/// users rank at or above the percentile and the threshold is the XP of the lowest of them.
+/// The threshold is 0 when that user is not on the leaderboard.
+pub fn get_xp_distribution(env: &Env) -> Vec<(u32, u32)> {
+    let board = storage::get_leaderboard(env);
+    let total_users = reputation::get_total_user_count(env);
+
+    let mut distribution = Vec::new(env);
+    for percentile in DISTRIBUTION_PERCENTILES {
+        let user_count = (total_users as u64 * (100 - percentile) as u64 / 100) as u32;
+        let threshold = match user_count {
+            0 => 0,
+            n => board.get(n - 1).map(|(_, xp)| xp).unwrap_or(0),
+        };
+        distribution.push_back((threshold, user_count));
+    }
+    distribution
+}
+
+/// Set a user's XP for the current season and update the monthly leaderboard
+fn set_monthly_xp(env: &Env, user: &Address, xp: u32) {
+    storage::set_monthly_xp(env, storage::get_season_id(env), user, xp);
+
+    let mut board = storage::get_monthly_leaderboard(env);
+    place_on_board(&mut board, user, xp);
+    storage::set_monthly_leaderboard(env, &board);
+}
+
+/// Add XP earned during the current season
+pub fn record_monthly_xp(env: &Env, user: &Address, xp: u32) {
+    let current = storage::get_monthly_xp(env, storage::get_season_id(env), user);
+    set_monthly_xp(env, user, current.saturating_add(xp));
+}
+
+/// Remove XP from the current season, flooring at zero
+pub fn remove_monthly_xp(env: &Env, user: &Address, xp: u32) {
+    let current = storage::get_monthly_xp(env, storage::get_season_id(env), user);
+    if current > 0 {
+        set_monthly_xp(env, user, current.saturating_sub(xp));
+    }
+}
+
+/// Get the top `n` users by XP earned this season
+pub fn get_monthly_leaderboard(env: &Env, n: u32) -> Vec<(Address, u32)> {
+    let board = storage::get_monthly_leaderboard(env);
+    board.slice(0..n.min(board.len()))
+}
+
+/// End the current season: archive its top users and start a new season, so
+/// monthly XP counts from zero again
+pub fn reset_monthly_leaderboard(env: &Env) {
+    let top = get_monthly_leaderboard(env, MONTHLY_ARCHIVE_SIZE);
+    let timestamp = env.ledger().timestamp();
+
+    let mut archive = storage::get_monthly_archive(env);
+    archive.push_back((timestamp, top.clone()));
+    storage::set_monthly_archive(env, &archive);
+
+    storage::set_season_id(env, storage::get_season_id(env) + 1);
+    storage::set_monthly_leaderboard(env, &Vec::new(env));
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "season_ended"), timestamp), top);
+}
+
+/// Get the archived top users of every past season as (ended_at, top_users)
+pub fn get_monthly_archive(env: &Env) -> Vec<(u64, Vec<(Address, u32)>)> {
+    storage::get_monthly_archive(env)
+}
@@ -1,28 +1,160 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Symbol, Vec};
 
+mod admin;
+mod analytics;
+mod badges;
 mod errors;
+mod leaderboard;
 mod payout;
 mod quest;
+mod quest_stream;
+mod ratelimit;
+mod referral;
 mod reputation;
+mod rewards;
 mod storage;
 mod submission;
 pub mod test;
 pub mod types;
+mod validation;
+mod verification;
 
-use errors::Error;
-use types::{Quest, Submission, UserStats};
+pub use errors::Error;
+use types::{
+    ApprovalPreview, Badge, CompletionCertificate, DailyQuestSeries, PlatformBadge,
+    PlatformSnapshot, PlatformStats, Quest, QuestStream, QuestVelocity, StorageMetrics, Submission,
+    SubmissionBackup, TtlHealthReport, UserStats, VerificationTemplate, VerifierStats,
+};
 
 // Re-export types for use in tests
-pub use types::{QuestStatus, SubmissionStatus};
+pub use types::{BadgeTier, QuestDifficulty, QuestStatus, RewardMode, SubmissionStatus};
+
+/// Base XP awarded for an approved submission, before the difficulty multiplier
+const BASE_QUEST_XP: u32 = 100;
 
 #[contract]
 pub struct EarnQuestContract;
 
 #[contractimpl]
 impl EarnQuestContract {
-    /// Register a new quest with participant limit
+    /// Initialize the contract with an admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        admin::initialize(&env, &admin)
+    }
+
+    /// Propose a new admin, who can accept after a 48 hour timelock (admin only)
+    pub fn propose_admin_rotation(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<u64, Error> {
+        admin::propose_admin_rotation(&env, &current_admin, &new_admin)
+    }
+
+    /// Accept a proposed admin rotation once its timelock has passed (proposed admin only)
+    pub fn confirm_admin_rotation(env: Env, new_admin: Address) -> Result<(), Error> {
+        admin::confirm_admin_rotation(&env, &new_admin)
+    }
+
+    /// Withdraw a pending admin rotation (admin only)
+    pub fn cancel_admin_rotation(env: Env, current_admin: Address) -> Result<(), Error> {
+        admin::cancel_admin_rotation(&env, &current_admin)
+    }
+
+    /// Get the pending admin rotation as (new_admin, effective_at)
+    pub fn get_pending_rotation(env: Env) -> Option<(Address, u64)> {
+        admin::get_pending_rotation(&env)
+    }
+
+    /// Enable or disable a runtime feature toggle (admin only)
+    pub fn set_feature_flag(
+        env: Env,
+        admin: Address,
+        flag: Symbol,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        admin::set_feature_flag(&env, &admin, flag, enabled)
+    }
+
+    /// Check whether a runtime feature toggle is on
+    pub fn is_feature_enabled(env: Env, flag: Symbol) -> bool {
+        admin::is_feature_enabled(&env, &flag)
+    }
+
+    /// Set a contract metadata entry (admin only)
+    pub fn set_contract_metadata(
+        env: Env,
+        admin: Address,
+        key: Symbol,
+        value: Bytes,
+    ) -> Result<(), Error> {
+        admin::set_contract_metadata(&env, &admin, key, value)
+    }
+
+    /// Get a contract metadata entry
+    pub fn get_contract_metadata(env: Env, key: Symbol) -> Option<Bytes> {
+        admin::get_contract_metadata(&env, &key)
+    }
+
+    /// Get the keys of all contract metadata entries
+    pub fn get_all_metadata_keys(env: Env) -> Vec<Symbol> {
+        admin::get_all_metadata_keys(&env)
+    }
+
+    /// Set the ledger from which quests may be created (admin only)
+    pub fn set_go_live_ledger(env: Env, admin: Address, ledger: u32) -> Result<(), Error> {
+        admin::set_go_live_ledger(&env, &admin, ledger)
+    }
+
+    /// Check whether the contract accepts new quests yet
+    pub fn is_live(env: Env) -> bool {
+        admin::is_live(&env)
+    }
+
+    /// Get the ledger sequence at which the contract was initialized
+    pub fn get_deployment_ledger(env: Env) -> Option<u32> {
+        admin::get_deployment_ledger(&env)
+    }
+
+    /// Set the fee creators pay to register a quest (admin only)
+    pub fn set_quest_creation_fee(
+        env: Env,
+        admin: Address,
+        fee: i128,
+        asset: Address,
+    ) -> Result<(), Error> {
+        admin::set_quest_creation_fee(&env, &admin, fee, &asset)
+    }
+
+    /// Get the quest creation fee and its asset, if configured
+    pub fn get_creation_fee(env: Env) -> Option<(i128, Address)> {
+        admin::get_creation_fee(&env)
+    }
+
+    /// Set the address receiving platform fees (admin only)
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), Error> {
+        admin::set_treasury(&env, &admin, &treasury)
+    }
+
+    /// Get the treasury address (defaults to the admin)
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        admin::get_treasury(&env)
+    }
+
+    /// Transfer tokens sent to the contract outside of quest escrows to the treasury (admin only)
+    pub fn drain_orphaned_escrow(env: Env, admin: Address, asset: Address) -> Result<i128, Error> {
+        admin::drain_orphaned_escrow(&env, &admin, &asset)
+    }
+
+    /// Get the contract's balance of an asset not accounted for by quest escrows
+    pub fn get_orphaned_balance(env: Env, asset: Address) -> i128 {
+        admin::get_orphaned_balance(&env, &asset)
+    }
+
+    /// Register a new quest with participant limit, escrowing the full reward pool
+    /// (`reward_amount * max_participants`) from the creator
     #[allow(clippy::too_many_arguments)]
     pub fn register_quest(
         env: Env,
@@ -33,6 +165,7 @@ impl EarnQuestContract {
         verifier: Address,
         deadline: u64,
         max_participants: u32,
+        difficulty: QuestDifficulty,
     ) -> Result<(), Error> {
         quest::create_quest(
             &env,
@@ -43,12 +176,24 @@ impl EarnQuestContract {
             verifier,
             deadline,
             max_participants,
+            difficulty,
         )
     }
 
+    /// Check quest creation parameters without creating the quest (read-only)
+    pub fn validate_quest_params(
+        env: Env,
+        id: Symbol,
+        reward_amount: i128,
+        deadline: u64,
+        max_participants: u32,
+    ) -> Result<(), Error> {
+        quest::validate_quest_params(&env, &id, reward_amount, deadline, max_participants)
+    }
+
     /// Get quest details
     pub fn get_quest(env: Env, id: Symbol) -> Result<Quest, Error> {
-        storage::get_quest(&env, &id).ok_or(Error::QuestNotFound)
+        storage::get_quest_versioned(&env, &id)
     }
 
     /// Update quest status (creator only)
@@ -67,6 +212,199 @@ impl EarnQuestContract {
         Ok(quest::is_quest_full(&quest))
     }
 
+    /// Get the share of a quest's participant slots claimed, in basis points
+    pub fn compute_fill_percentage(env: Env, quest_id: Symbol) -> Result<u32, Error> {
+        quest::compute_fill_percentage(&env, &quest_id)
+    }
+
+    /// Get the amount paid to each approved participant of a quest
+    pub fn get_reward_per_slot(env: Env, quest_id: Symbol) -> Result<i128, Error> {
+        quest::get_reward_per_slot(&env, &quest_id)
+    }
+
+    /// Get the most a quest can pay out across all participant slots
+    pub fn get_total_potential_payout(env: Env, quest_id: Symbol) -> Result<i128, Error> {
+        quest::get_total_potential_payout(&env, &quest_id)
+    }
+
+    /// Get quests paying at least `min_amount` of an asset, highest reward first (max 50)
+    pub fn search_quests_by_min_reward(env: Env, asset: Address, min_amount: i128) -> Vec<Symbol> {
+        quest::search_quests_by_min_reward(&env, &asset, min_amount)
+    }
+
+    /// Get the quests a user can still submit to (max 50)
+    pub fn get_active_quests_for_user(env: Env, user: Address) -> Vec<Symbol> {
+        quest::get_active_quests_for_user(&env, &user)
+    }
+
+    /// Get a page of the quests a user can still submit to (max 50 per page)
+    pub fn get_active_quests_for_user_paged(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Symbol> {
+        quest::get_active_quests_for_user_paged(&env, &user, offset, limit)
+    }
+
+    /// Get a page of active quests paying in an asset as (quest_id, reward_amount),
+    /// highest reward first (max 50 per page)
+    pub fn get_quests_sorted_by_reward(
+        env: Env,
+        asset: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(Symbol, i128)> {
+        quest::get_quests_sorted_by_reward(&env, &asset, offset, limit)
+    }
+
+    /// Recommend up to `n` open quests for a user based on their favorite difficulty (max 20)
+    pub fn get_recommended_quests(env: Env, user: Address, n: u32) -> Vec<Symbol> {
+        quest::get_recommended_quests(&env, &user, n)
+    }
+
+    /// Get a page of quest IDs, newest first (max 50 per page)
+    pub fn get_quests_sorted_by_recent(env: Env, offset: u32, limit: u32) -> Vec<Symbol> {
+        quest::get_quests_sorted_by_recent(&env, offset, limit)
+    }
+
+    /// Get recent engagement metrics of a quest
+    pub fn get_quest_velocity(env: Env, quest_id: Symbol) -> Result<QuestVelocity, Error> {
+        quest::get_quest_velocity(&env, &quest_id)
+    }
+
+    /// Get the cumulative submissions of a quest per day since its creation (max 90 days)
+    pub fn get_submission_timeline(env: Env, quest_id: Symbol) -> Vec<(u64, u32)> {
+        quest::get_submission_timeline(&env, &quest_id)
+    }
+
+    /// Get the cumulative approvals of a quest per day since its creation (max 90 days)
+    pub fn get_approval_timeline(env: Env, quest_id: Symbol) -> Vec<(u64, u32)> {
+        quest::get_approval_timeline(&env, &quest_id)
+    }
+
+    /// Get up to `n` active quests with the most submissions today (max 50)
+    pub fn get_trending_quests(env: Env, n: u32) -> Vec<Symbol> {
+        quest::get_trending_quests(&env, n)
+    }
+
+    /// Compute a quest's 0-100 health score from fill level, review time,
+    /// submitter completion rates and rejections
+    pub fn compute_quest_health_score(env: Env, quest_id: Symbol) -> Result<u32, Error> {
+        quest::compute_quest_health_score(&env, &quest_id)
+    }
+
+    /// Get up to `n` quests with a cached health score of at least `min_score`, best first (max 50)
+    pub fn get_healthy_quests(env: Env, min_score: u32, n: u32) -> Vec<(Symbol, u32)> {
+        quest::get_healthy_quests(&env, min_score, n)
+    }
+
+    /// Get the IDs of quests filled to at least `min_fill_bps` basis points
+    pub fn get_quests_above_fill_pct(env: Env, min_fill_bps: u32) -> Vec<Symbol> {
+        quest::get_quests_above_fill_pct(&env, min_fill_bps)
+    }
+
+    /// Set the approved claims required before a quest can be completed (creator only)
+    pub fn set_min_completions(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        min_completions: u32,
+    ) -> Result<(), Error> {
+        quest::set_min_completions(&env, &quest_id, &creator, min_completions)
+    }
+
+    /// Require approvals from both the verifier and the creator (creator only)
+    pub fn set_dual_approval_required(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        required: bool,
+    ) -> Result<(), Error> {
+        quest::set_dual_approval_required(&env, &quest_id, &creator, required)
+    }
+
+    /// Link a verification template to a quest (creator only)
+    pub fn attach_verification_template(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        template_id: u32,
+    ) -> Result<(), Error> {
+        quest::attach_verification_template(&env, &quest_id, &creator, template_id)
+    }
+
+    /// Set how a quest's reward is paid out (creator only)
+    pub fn set_reward_mode(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        mode: RewardMode,
+    ) -> Result<(), Error> {
+        quest::set_reward_mode(&env, &quest_id, &creator, mode)
+    }
+
+    /// Pay rewards from escrow as soon as submissions are approved (creator only, before any approval)
+    pub fn set_auto_payout(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        quest::set_auto_payout(&env, &quest_id, &creator, enabled)
+    }
+
+    /// Check whether a quest pays rewards out as soon as submissions are approved
+    pub fn auto_payout_on_approval(env: Env, quest_id: Symbol) -> bool {
+        rewards::auto_payout_on_approval(&env, &quest_id)
+    }
+
+    /// Get the Bayesian average score of a quest's submissions
+    pub fn get_average_submission_score(env: Env, quest_id: Symbol) -> Option<u32> {
+        quest::get_average_submission_score(&env, &quest_id)
+    }
+
+    /// Mark a quest completed once all its submissions are settled (creator only)
+    pub fn mark_completed(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        quest::mark_completed(&env, &quest_id, &creator)
+    }
+
+    /// Create the next quest of a chain as a copy of an existing quest (creator only)
+    pub fn duplicate_to_chain(
+        env: Env,
+        source_quest_id: Symbol,
+        caller: Address,
+        chain_index: u32,
+    ) -> Result<Symbol, Error> {
+        quest::duplicate_to_chain(&env, &source_quest_id, &caller, chain_index)
+    }
+
+    /// Get every quest of the chain a quest belongs to, starting with the original
+    pub fn get_chain(env: Env, quest_id: Symbol) -> Vec<Symbol> {
+        quest::get_chain(&env, &quest_id)
+    }
+
+    /// Get the most recently created quest of a chain
+    pub fn get_latest_in_chain(env: Env, quest_id: Symbol) -> Result<Symbol, Error> {
+        quest::get_latest_in_chain(&env, &quest_id)
+    }
+
+    /// Cancel a quest and refund its escrow (creator only)
+    pub fn cancel_quest(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        quest::cancel_quest(&env, &quest_id, &creator)
+    }
+
+    /// Release a quest's held creation fee to the treasury after the refund window (anyone).
+    /// Returns the amount swept.
+    pub fn sweep_creation_fee(env: Env, quest_id: Symbol) -> i128 {
+        quest::sweep_creation_fee(&env, &quest_id)
+    }
+
+    /// Archive a closed quest, keeping it readable but blocking all mutations (creator only)
+    pub fn archive_quest(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        quest::archive_quest(&env, &quest_id, &creator)
+    }
+
     /// Submit proof for a quest
     pub fn submit_proof(
         env: Env,
@@ -74,84 +412,1022 @@ impl EarnQuestContract {
         submitter: Address,
         proof_hash: BytesN<32>,
     ) -> Result<(), Error> {
-        submission::submit_proof(&env, quest_id, submitter, proof_hash)
+        submission::submit_proof(&env, quest_id, submitter, proof_hash, None, None)
     }
 
-    /// Get submission details
-    pub fn get_submission(
+    /// Submit proof for a quest with the proof document inline (max 1KB); `proof_hash`
+    /// must be the document's sha256, and only the hash is stored
+    pub fn submit_proof_with_document(
         env: Env,
         quest_id: Symbol,
         submitter: Address,
-    ) -> Result<Submission, Error> {
-        storage::get_submission(&env, &quest_id, &submitter).ok_or(Error::SubmissionNotFound)
+        proof_hash: BytesN<32>,
+        inline_document: Bytes,
+    ) -> Result<(), Error> {
+        submission::submit_proof(
+            &env,
+            quest_id,
+            submitter,
+            proof_hash,
+            None,
+            Some(inline_document),
+        )
     }
 
-    /// Approve submission and trigger payout (verifier only)
-    pub fn approve_submission(
+    /// Submit proof for a whitelisted quest with a Merkle proof of membership
+    pub fn submit_whitelisted_proof(
         env: Env,
         quest_id: Symbol,
         submitter: Address,
-        verifier: Address,
+        proof_hash: BytesN<32>,
+        merkle_proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        submission::submit_proof(
+            &env,
+            quest_id,
+            submitter,
+            proof_hash,
+            Some(merkle_proof),
+            None,
+        )
+    }
+
+    /// Submit proof on behalf of a user holding a signed permit
+    pub fn submit_proof_with_permit(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        proof_hash: BytesN<32>,
+        permit_signature: BytesN<64>,
+        permit_expiry: u64,
     ) -> Result<(), Error> {
-        // Approve submission and increment claim counter
-        submission::approve_submission(&env, &quest_id, &submitter, &verifier)?;
+        submission::submit_proof_with_permit(
+            &env,
+            quest_id,
+            submitter,
+            proof_hash,
+            permit_signature,
+            permit_expiry,
+        )
+    }
 
-        // Get submission
-        let mut sub = storage::get_submission(&env, &quest_id, &submitter)
-            .ok_or(Error::SubmissionNotFound)?;
+    /// Register the ed25519 key a user signs submission permits with
+    pub fn register_permit_key(env: Env, user: Address, public_key: BytesN<32>) {
+        submission::register_permit_key(&env, &user, public_key)
+    }
 
-        // Transfer reward using Stellar token contract
-        // Note: In production, this would transfer tokens. In tests, we skip this.
-        // let token_client = token::Client::new(&env, &quest.reward_asset);
-        // token_client.transfer(
-        //     &quest.creator,
-        //     &submitter,
-        //     &quest.reward_amount,
-        // );
+    /// Get the message a submission permit must sign
+    pub fn get_permit_message(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        proof_hash: BytesN<32>,
+        permit_expiry: u64,
+    ) -> BytesN<32> {
+        submission::permit_message(&env, &quest_id, &submitter, &proof_hash, permit_expiry)
+    }
 
-        // Award XP to user
-        reputation::award_xp(&env, &submitter, 100)?;
+    /// Restrict submissions to the addresses under a Merkle root (creator only)
+    pub fn set_whitelist_merkle_root(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        root: BytesN<32>,
+    ) -> Result<(), Error> {
+        quest::set_whitelist_merkle_root(&env, &quest_id, &creator, root)
+    }
 
-        // Update submission to paid
-        sub.status = SubmissionStatus::Paid;
-        storage::set_submission(&env, &sub);
+    /// Permanently lock a quest's whitelist root (creator only, before any claims).
+    /// The `whitelist_sealed` event publishes the sealed root, since only the root is stored.
+    pub fn seal_whitelist(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        quest::seal_whitelist(&env, &quest_id, &creator)
+    }
 
-        Ok(())
+    /// Check whether a quest's whitelist root is sealed
+    pub fn is_whitelist_sealed(env: Env, quest_id: Symbol) -> bool {
+        quest::is_whitelist_sealed(&env, &quest_id)
     }
 
-    /// Reject submission (verifier only)
-    pub fn reject_submission(
+    /// Get the whitelist Merkle root of a quest
+    pub fn get_whitelist_root(env: Env, quest_id: Symbol) -> Option<BytesN<32>> {
+        storage::get_whitelist_root(&env, &quest_id)
+    }
+
+    /// Compute the whitelist Merkle leaf of an address
+    pub fn compute_whitelist_leaf(env: Env, address: Address) -> BytesN<32> {
+        validation::compute_leaf(&env, &address)
+    }
+
+    /// Submit proof for a quest with a URI pointing at the proof
+    pub fn submit_proof_with_uri(
         env: Env,
         quest_id: Symbol,
         submitter: Address,
-        verifier: Address,
+        proof_hash: BytesN<32>,
+        proof_uri: Bytes,
     ) -> Result<(), Error> {
-        submission::reject_submission(&env, &quest_id, &submitter, &verifier)
+        submission::submit_proof_with_uri(&env, quest_id, submitter, proof_hash, proof_uri)
     }
 
-    /// Get user statistics
-    pub fn get_user_stats(env: Env, address: Address) -> Result<UserStats, Error> {
-        storage::get_user_stats(&env, &address).ok_or(Error::UserStatsNotFound)
+    /// Get the proof URI of a submission
+    pub fn get_proof_uri(env: Env, quest_id: Symbol, submitter: Address) -> Option<Bytes> {
+        storage::get_proof_uri(&env, &quest_id, &submitter)
     }
 
-    /// Grant badge to user (admin only)
-    pub fn grant_badge(
+    /// Check the structural form of a proof URI before submitting
+    pub fn validate_proof_uri(env: Env, uri: Bytes) -> Result<(), Error> {
+        validation::validate_proof_uri_reachability_hint(&env, &uri)
+    }
+
+    /// Get the creator's active quests with a deadline on the same day as `new_deadline`
+    pub fn check_deadline_conflicts(env: Env, creator: Address, new_deadline: u64) -> Vec<Symbol> {
+        validation::check_deadline_conflicts(&env, &creator, new_deadline)
+    }
+
+    /// Accept an additional URI scheme for proofs (admin only)
+    pub fn add_allowed_uri_scheme(env: Env, admin: Address, scheme: Bytes) -> Result<(), Error> {
+        admin::add_allowed_uri_scheme(&env, &admin, scheme)
+    }
+
+    /// List the URI schemes accepted for proofs
+    pub fn list_allowed_uri_schemes(env: Env) -> Vec<Bytes> {
+        admin::list_allowed_uri_schemes(&env)
+    }
+
+    /// Get submission details, falling back to the latest rejected submission
+    pub fn get_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Result<Submission, Error> {
+        submission::get_latest_submission(&env, &quest_id, &submitter)
+            .ok_or(Error::SubmissionNotFound)
+    }
+
+    /// Get the submission of a quest's `index`-th submitter, in arrival order (0-based)
+    pub fn get_submission_by_index(
+        env: Env,
+        quest_id: Symbol,
+        index: u32,
+    ) -> Result<Submission, Error> {
+        storage::get_submission_by_index(&env, &quest_id, index)
+    }
+
+    /// Get the number of users who have submitted to a quest
+    pub fn get_quest_submission_count(env: Env, quest_id: Symbol) -> u32 {
+        storage::get_quest_submission_count(&env, &quest_id)
+    }
+
+    /// Get the first address that submitted a proof hash to a quest
+    pub fn get_submitter_for_proof_hash(
+        env: Env,
+        quest_id: Symbol,
+        proof_hash: BytesN<32>,
+    ) -> Option<Address> {
+        submission::get_submitter_for_proof_hash(&env, &quest_id, &proof_hash)
+    }
+
+    /// Get the seconds since a proof hash was submitted to a quest, if it was
+    pub fn get_proof_hash_age(env: Env, quest_id: Symbol, proof_hash: BytesN<32>) -> Option<u64> {
+        submission::get_proof_hash_age(&env, &quest_id, &proof_hash)
+    }
+
+    /// Check whether a submission is older than `max_age_secs`
+    pub fn flag_old_proof(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        max_age_secs: u64,
+    ) -> bool {
+        validation::flag_old_proof(&env, &quest_id, &submitter, max_age_secs)
+    }
+
+    /// Get a page of (proof_hash, first_submitter) mappings for a quest (max 50)
+    pub fn get_all_proof_hashes(
+        env: Env,
+        quest_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(BytesN<32>, Address)> {
+        submission::get_all_proof_hashes(&env, &quest_id, offset, limit)
+    }
+
+    /// Submit a new proof after a rejected submission to the same quest
+    pub fn resubmit_after_rejection(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        proof_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        submission::resubmit_after_rejection(&env, quest_id, submitter, proof_hash)
+    }
+
+    /// Get a user's rejected submissions to a quest, oldest first
+    pub fn get_rejection_history(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Vec<Submission> {
+        submission::get_rejection_history(&env, &quest_id, &submitter)
+    }
+
+    /// Back up an approved or paid submission to instance storage (admin only)
+    pub fn backup_submission_to_instance(
         env: Env,
-        address: Address,
-        badge: Symbol,
         admin: Address,
+        quest_id: Symbol,
+        submitter: Address,
     ) -> Result<(), Error> {
-        reputation::grant_badge(&env, &address, badge, &admin)
+        admin::backup_submission_to_instance(&env, &admin, &quest_id, &submitter)
     }
 
-    /// Check if a quest has expired based on its deadline
-    pub fn check_expired(env: Env, quest_id: Symbol) -> Result<bool, Error> {
-        let quest = storage::get_quest(&env, &quest_id).ok_or(Error::QuestNotFound)?;
-        Ok(quest::check_expired(&env, &quest))
+    /// Get the instance-storage backup of a submission, if any
+    pub fn get_submission_backup(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Option<SubmissionBackup> {
+        storage::get_submission_backup(&env, &quest_id, &submitter)
     }
 
-    /// Manually expire a quest (creator only)
-    pub fn expire_quest(env: Env, quest_id: Symbol, caller: Address) -> Result<(), Error> {
-        quest::expire_quest(&env, &quest_id, &caller)
+    /// Approve submission (verifier, or verifier and creator on dual-approval quests).
+    /// The reward stays in escrow until claimed, unless the quest pays out on approval.
+    pub fn approve_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        // Approve submission and increment claim counter; a first dual-approval vote stops here
+        if !submission::approve_submission(&env, &quest_id, &submitter, &verifier)? {
+            return Ok(());
+        }
+
+        let quest = storage::get_quest(&env, &quest_id).ok_or(Error::QuestNotFound)?;
+
+        // Award XP to user, scaled by quest difficulty
+        let xp = BASE_QUEST_XP * quest.difficulty.xp_multiplier();
+        reputation::award_xp(&env, &submitter, xp)?;
+        reputation::record_difficulty_xp(&env, &submitter, quest.difficulty, xp);
+        quest::refresh_health_score(&env, &quest_id);
+
+        // Pay from escrow right away; a failed payout reverts the approval with it.
+        // Frozen accounts keep the submission approved, claimable once unfrozen
+        if quest.auto_payout && !storage::is_frozen(&env, &submitter) {
+            let reward = rewards::settle_submission(&env, &quest_id, &submitter)?;
+
+            // Emit event
+            env.events().publish(
+                (
+                    Symbol::new(&env, "submission_approved_and_paid"),
+                    quest_id.clone(),
+                ),
+                (submitter, reward),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Claim a pending submission so only this verifier can review it for 24 hours
+    pub fn claim_for_review(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        verification::claim_for_review(&env, &quest_id, &submitter, &verifier)
+    }
+
+    /// Release an expired review claim, returning the submission to pending (anyone)
+    pub fn unclaim_review(env: Env, quest_id: Symbol, submitter: Address) -> Result<(), Error> {
+        verification::unclaim_review(&env, &quest_id, &submitter)
+    }
+
+    /// Publish a reusable review rubric, returning its ID
+    pub fn create_verification_template(
+        env: Env,
+        verifier: Address,
+        name: Symbol,
+        criteria_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        verification::create_template(&env, &verifier, name, criteria_hash)
+    }
+
+    /// Get a verification template
+    pub fn get_verification_template(
+        env: Env,
+        template_id: u32,
+    ) -> Result<VerificationTemplate, Error> {
+        verification::get_template(&env, template_id)
+    }
+
+    /// Get the IDs of the templates a verifier has published
+    pub fn list_templates_by_verifier(env: Env, verifier: Address) -> Vec<u32> {
+        verification::list_templates_by_verifier(&env, &verifier)
+    }
+
+    /// Score the quality of a submission from 1 to 100 (verifier only)
+    pub fn score_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+        score: u32,
+    ) -> Result<(), Error> {
+        verification::score_submission(&env, &quest_id, &submitter, &verifier, score)
+    }
+
+    /// Get the quality score a verifier gave a submission, if any
+    pub fn get_submission_score(env: Env, quest_id: Symbol, submitter: Address) -> Option<u32> {
+        submission::get_submission_score(&env, &quest_id, &submitter)
+    }
+
+    /// Get the parties that have approved a submission to a dual-approval quest
+    pub fn get_partial_approvals(env: Env, quest_id: Symbol, submitter: Address) -> Vec<Address> {
+        submission::get_partial_approvals(&env, &quest_id, &submitter)
+    }
+
+    /// Reject submission (verifier only)
+    pub fn reject_submission(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        submission::reject_submission(&env, &quest_id, &submitter, &verifier)
+    }
+
+    /// Get user statistics, applying any XP decay accrued since the user's last activity
+    pub fn get_user_stats(env: Env, address: Address) -> Result<UserStats, Error> {
+        reputation::apply_xp_decay(&env, &address)?;
+        storage::get_user_stats(&env, &address).ok_or(Error::UserStatsNotFound)
+    }
+
+    /// Apply the XP decay accrued by a user and return the XP removed
+    pub fn apply_xp_decay(env: Env, user: Address) -> Result<u32, Error> {
+        reputation::apply_xp_decay(&env, &user)
+    }
+
+    /// Grant badge to user (admin only)
+    pub fn grant_badge(
+        env: Env,
+        address: Address,
+        badge: Symbol,
+        quest_id: Symbol,
+        tier: BadgeTier,
+        admin: Address,
+    ) -> Result<(), Error> {
+        badges::award_badge(&env, &address, badge, quest_id, tier, &admin)
+    }
+
+    /// Create or update a curated badge limited to `max_supply` holders (admin only)
+    pub fn create_platform_badge(
+        env: Env,
+        admin: Address,
+        symbol: Symbol,
+        description_hash: BytesN<32>,
+        max_supply: u32,
+    ) -> Result<(), Error> {
+        badges::create_platform_badge(&env, &admin, symbol, description_hash, max_supply)
+    }
+
+    /// Get a curated platform badge
+    pub fn get_platform_badge(env: Env, symbol: Symbol) -> Option<PlatformBadge> {
+        badges::get_platform_badge(&env, &symbol)
+    }
+
+    /// List the symbols of all curated platform badges
+    pub fn list_platform_badges(env: Env) -> Vec<Symbol> {
+        badges::list_platform_badges(&env)
+    }
+
+    /// Get a user's badge by symbol
+    pub fn get_badge(env: Env, user: Address, badge: Symbol) -> Option<Badge> {
+        badges::get_badge(&env, &user, &badge)
+    }
+
+    /// Get a user's badges of a given tier
+    pub fn get_badges_by_tier(env: Env, user: Address, tier: BadgeTier) -> Vec<Badge> {
+        badges::get_badges_by_tier(&env, &user, tier)
+    }
+
+    /// Convert users' stats from the legacy badge layout (admin only)
+    pub fn migrate_badges(env: Env, admin: Address, users: Vec<Address>) -> Result<u32, Error> {
+        admin::migrate_badges(&env, &admin, users)
+    }
+
+    /// Rewrite up to 20 quests stored in an earlier layout in the current one (admin only)
+    pub fn run_storage_migration(
+        env: Env,
+        admin: Address,
+        quest_ids: Vec<Symbol>,
+    ) -> Result<u32, Error> {
+        admin::run_storage_migration(&env, &admin, quest_ids)
+    }
+
+    /// Check if a quest has expired based on its deadline
+    pub fn check_expired(env: Env, quest_id: Symbol) -> Result<bool, Error> {
+        let quest = storage::get_quest(&env, &quest_id).ok_or(Error::QuestNotFound)?;
+        Ok(quest::check_expired(&env, &quest))
+    }
+
+    /// Manually expire a quest (creator only)
+    pub fn expire_quest(env: Env, quest_id: Symbol, caller: Address) -> Result<(), Error> {
+        quest::expire_quest(&env, &quest_id, &caller)
+    }
+
+    /// Get daily submission counts for a quest over a day range (max 90 days)
+    pub fn get_daily_submission_counts(
+        env: Env,
+        quest_id: Symbol,
+        from_day: u64,
+        to_day: u64,
+    ) -> Vec<(u64, u32)> {
+        analytics::get_daily_submission_counts(&env, &quest_id, from_day, to_day)
+    }
+
+    /// Get the day with the highest number of submissions for a quest
+    pub fn get_peak_submission_day(env: Env, quest_id: Symbol) -> Option<(u64, u32)> {
+        analytics::get_peak_submission_day(&env, &quest_id)
+    }
+
+    /// Get a user's daily submission counts for the last `days` days (max 365)
+    pub fn get_user_activity_heatmap(env: Env, user: Address, days: u32) -> Vec<(u64, u32)> {
+        analytics::get_user_activity_heatmap(&env, &user, days)
+    }
+
+    /// Estimate the persistent storage used by a quest, in bytes (approximate)
+    pub fn estimate_storage_cost(env: Env, quest_id: Symbol) -> u64 {
+        storage::estimate_storage_cost(&env, &quest_id)
+    }
+
+    /// Estimate the persistent storage used by all quests, in bytes (approximate)
+    pub fn get_contract_storage_estimate(env: Env) -> u64 {
+        admin::get_contract_storage_estimate(&env)
+    }
+
+    /// Report how close a quest's storage entries are to expiring (admin only)
+    pub fn ttl_health_check(
+        env: Env,
+        admin: Address,
+        quest_id: Symbol,
+    ) -> Result<TtlHealthReport, Error> {
+        admin::ttl_health_check(&env, &admin, &quest_id)
+    }
+
+    /// Refresh the TTL of a quest and all of its submissions
+    pub fn extend_quest_ttl(env: Env, quest_id: Symbol) -> Result<(), Error> {
+        quest::extend_quest_ttl(&env, &quest_id)
+    }
+
+    /// Refresh the TTL of an approved or paid submission (callable by keepers).
+    /// Returns `false` if the submission is missing or not approved or paid.
+    pub fn refresh_approved_submission_ttl(env: Env, quest_id: Symbol, submitter: Address) -> bool {
+        storage::refresh_approved_submission_ttl(&env, &quest_id, &submitter)
+    }
+
+    /// Get the completion certificate of a paid submission, issuing it on the first call
+    pub fn get_completion_certificate(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Result<CompletionCertificate, Error> {
+        quest::get_completion_certificate(&env, &quest_id, &submitter)
+    }
+
+    /// Get storage usage metrics for the contract (admin only)
+    pub fn get_storage_metrics(env: Env, admin: Address) -> Result<StorageMetrics, Error> {
+        admin::get_storage_metrics(&env, &admin)
+    }
+
+    /// Set the sponsor of a quest, whose badge paid submitters receive (creator only)
+    pub fn set_sponsor(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        sponsor: Address,
+        sponsor_name: Symbol,
+    ) -> Result<(), Error> {
+        quest::set_sponsor(&env, &quest_id, &creator, &sponsor, &sponsor_name)
+    }
+
+    /// Get the sponsor of a quest, if any
+    pub fn get_sponsor(env: Env, quest_id: Symbol) -> Option<Address> {
+        quest::get_sponsor(&env, &quest_id)
+    }
+
+    /// Get the IDs of quests sponsored by an address
+    pub fn get_sponsored_quests(env: Env, sponsor: Address) -> Vec<Symbol> {
+        quest::get_sponsored_quests(&env, &sponsor)
+    }
+
+    /// Add funds to a quest's reward escrow (creator or sponsor only)
+    pub fn contribute_to_reward(
+        env: Env,
+        quest_id: Symbol,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        quest::contribute_to_reward(&env, &quest_id, &contributor, amount)
+    }
+
+    /// Add participant slots to a quest and escrow their rewards (creator only)
+    pub fn fund_additional_slots(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        additional_slots: u32,
+    ) -> Result<(), Error> {
+        quest::fund_additional_slots(&env, &quest_id, &creator, additional_slots)
+    }
+
+    /// Get the reward balance held in escrow for a quest
+    pub fn get_quest_escrow(env: Env, quest_id: Symbol) -> i128 {
+        storage::get_quest_escrow(&env, &quest_id)
+    }
+
+    /// Get all quest IDs of a given difficulty
+    pub fn get_quests_by_difficulty(env: Env, difficulty: QuestDifficulty) -> Vec<Symbol> {
+        quest::get_quests_by_difficulty(&env, difficulty)
+    }
+
+    /// Get a breakdown of a user's XP by quest difficulty
+    pub fn get_xp_by_difficulty(env: Env, user: Address) -> Vec<(QuestDifficulty, u32)> {
+        reputation::get_xp_by_difficulty(&env, &user)
+    }
+
+    /// Set the maximum submissions per user per day (admin only)
+    pub fn set_max_daily_submissions(env: Env, admin: Address, limit: u32) -> Result<(), Error> {
+        admin::set_max_daily_submissions(&env, &admin, limit)
+    }
+
+    /// Set the number of quests each creator may create (admin only)
+    pub fn set_max_quests_per_creator(env: Env, admin: Address, limit: u32) -> Result<(), Error> {
+        admin::set_max_quests_per_creator(&env, &admin, limit)
+    }
+
+    /// Get the number of quests each creator may create (default 100)
+    pub fn get_max_quests_per_creator(env: Env) -> u32 {
+        admin::get_max_quests_per_creator(&env)
+    }
+
+    /// Give a trusted creator their own quest creation limit (admin only)
+    pub fn override_creator_limit(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        limit: u32,
+    ) -> Result<(), Error> {
+        admin::override_creator_limit(&env, &admin, &creator, limit)
+    }
+
+    /// Set the review count and on-time review percentage a verifier needs
+    /// to be assigned a new quest (admin only). `min_on_time_pct` (0-100) is the share of
+    /// the verifier's reviews that were completed before timing out.
+    pub fn set_verifier_quality_thresholds(
+        env: Env,
+        admin: Address,
+        min_reviewed: u32,
+        min_on_time_pct: u32,
+    ) -> Result<(), Error> {
+        admin::set_verifier_quality_thresholds(&env, &admin, min_reviewed, min_on_time_pct)
+    }
+
+    /// Get the verifier quality thresholds as (min_reviewed, min_on_time_pct)
+    pub fn get_verifier_quality_thresholds(env: Env) -> (u32, u32) {
+        admin::get_verifier_quality_thresholds(&env)
+    }
+
+    /// Let a trusted verifier bypass the quality thresholds (admin only)
+    pub fn exempt_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), Error> {
+        admin::exempt_verifier(&env, &admin, verifier)
+    }
+
+    /// Configure XP decay for inactive users (admin only, a rate of 0 disables decay)
+    pub fn configure_xp_decay(
+        env: Env,
+        admin: Address,
+        decay_rate_bps: u32,
+        decay_interval_days: u32,
+    ) -> Result<(), Error> {
+        admin::configure_xp_decay(&env, &admin, decay_rate_bps, decay_interval_days)
+    }
+
+    /// Get the XP decay settings as (decay_rate_bps, decay_interval_days)
+    pub fn get_xp_decay_config(env: Env) -> (u32, u32) {
+        storage::get_xp_decay_config(&env)
+    }
+
+    /// Get the number of submissions a user has made today
+    pub fn get_daily_count(env: Env, user: Address) -> u32 {
+        ratelimit::get_daily_count(&env, &user)
+    }
+
+    /// Set the auto-reject window for pending submissions (creator only, 0 disables)
+    pub fn set_auto_reject_after(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        seconds: u64,
+    ) -> Result<(), Error> {
+        verification::set_auto_reject_after(&env, &quest_id, &creator, seconds)
+    }
+
+    /// Auto-reject a stale pending submission; returns true if it was rejected
+    pub fn try_auto_reject(env: Env, quest_id: Symbol, submitter: Address) -> Result<bool, Error> {
+        submission::try_auto_reject(&env, &quest_id, &submitter)
+    }
+
+    /// Set the auto-approve window in seconds for a quest (creator only, 0 disables)
+    pub fn set_auto_approve_after(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        seconds: u64,
+    ) -> Result<(), Error> {
+        verification::set_auto_approve_after(&env, &quest_id, &creator, seconds)
+    }
+
+    /// Auto-approve a stale pending submission; returns true if it was approved
+    pub fn try_auto_approve(env: Env, quest_id: Symbol, submitter: Address) -> Result<bool, Error> {
+        submission::try_auto_approve(&env, &quest_id, &submitter)
+    }
+
+    /// Get the share of a quest's approvals that were automatic, in basis points
+    pub fn get_auto_approve_rate(env: Env, quest_id: Symbol) -> u32 {
+        quest::get_auto_approve_rate(&env, &quest_id)
+    }
+
+    /// Simulate the effects of approving a submission (read-only)
+    pub fn preview_approval_effects(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Result<ApprovalPreview, Error> {
+        verification::preview_approval_effects(&env, &quest_id, &submitter)
+    }
+
+    /// Reassign a quest to a new verifier (creator only)
+    pub fn set_verifier(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        new_verifier: Address,
+    ) -> Result<(), Error> {
+        quest::set_verifier(&env, &quest_id, &creator, &new_verifier)
+    }
+
+    /// Move a quest's pending submissions to a new verifier (admin only)
+    pub fn transfer_pending_queue(
+        env: Env,
+        admin: Address,
+        old_verifier: Address,
+        new_verifier: Address,
+        quest_id: Symbol,
+    ) -> Result<u32, Error> {
+        admin::transfer_pending_queue(&env, &admin, &old_verifier, &new_verifier, &quest_id)
+    }
+
+    /// Get every quest a verifier is assigned to
+    pub fn get_assigned_quest_ids(env: Env, verifier: Address) -> Vec<Symbol> {
+        verification::get_assigned_quest_ids(&env, &verifier)
+    }
+
+    /// Get the number of quests a verifier is assigned to
+    pub fn get_assigned_quest_count(env: Env, verifier: Address) -> u32 {
+        verification::get_assigned_quest_count(&env, &verifier)
+    }
+
+    /// Get a verifier's stale pending submissions as (quest_id, submitter, age_secs), oldest first
+    pub fn get_verifier_action_queue(
+        env: Env,
+        verifier: Address,
+        stale_secs: u64,
+    ) -> Vec<(Symbol, Address, u64)> {
+        quest::get_quests_needing_verifier_action(&env, &verifier, stale_secs)
+    }
+
+    /// Count a verifier's pending submissions past the SLA threshold
+    pub fn get_sla_breach_count(env: Env, verifier: Address, threshold_secs: u64) -> u32 {
+        verification::get_sla_breach_count(&env, &verifier, threshold_secs)
+    }
+
+    /// Get review statistics for a verifier
+    pub fn get_verifier_stats(env: Env, verifier: Address) -> VerifierStats {
+        verification::get_verifier_stats(&env, &verifier)
+    }
+
+    /// Get the quest IDs a user has submitted to
+    pub fn get_user_submissions(env: Env, user: Address) -> Vec<Symbol> {
+        storage::get_user_submissions(&env, &user)
+    }
+
+    /// Pay out all of a user's approved submissions (capped per call); returns the total paid
+    pub fn claim_all_paid(env: Env, user: Address) -> Result<i128, Error> {
+        rewards::claim_all_paid(&env, &user)
+    }
+
+    /// Get the total reward of a user's approved but unpaid submissions
+    pub fn get_claimable_balance(env: Env, user: Address) -> i128 {
+        rewards::get_claimable_balance(&env, &user)
+    }
+
+    /// Get (quest_id, claimable_amount, is_vesting) for a user's approved but unpaid
+    /// submissions, largest first (max 50)
+    pub fn get_all_my_approved_quests(env: Env, user: Address) -> Vec<(Symbol, i128, bool)> {
+        submission::get_all_my_approved_quests(&env, &user)
+    }
+
+    /// Mark a submission as fraudulent (verifier, co-signed by the admin)
+    pub fn mark_fraudulent(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        verifier: Address,
+        admin: Address,
+    ) -> Result<(), Error> {
+        submission::mark_fraudulent(&env, &quest_id, &submitter, &verifier, &admin)
+    }
+
+    /// Check if a user is in the fraud registry
+    pub fn is_fraudulent(env: Env, user: Address) -> bool {
+        storage::is_fraudulent(&env, &user)
+    }
+
+    /// Add a verifier to the pool offered for quest assignment (admin only)
+    pub fn register_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), Error> {
+        admin::register_verifier(&env, &admin, &verifier)
+    }
+
+    /// Remove a verifier from the assignment pool (admin only)
+    pub fn deregister_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), Error> {
+        admin::deregister_verifier(&env, &admin, &verifier)
+    }
+
+    /// Get the verifiers available for quest assignment
+    pub fn list_registered_verifiers(env: Env) -> Vec<Address> {
+        admin::list_registered_verifiers(&env)
+    }
+
+    /// Get registered verifiers with their pending review counts, least loaded first
+    pub fn get_load_balancing_hint(env: Env) -> Vec<(Address, u32)> {
+        verification::get_verification_load_balancing_hint(&env)
+    }
+
+    /// Freeze a user's account pending an investigation, holding submissions and payouts (admin only)
+    pub fn freeze_user(env: Env, admin: Address, user: Address) -> Result<(), Error> {
+        admin::freeze_user(&env, &admin, &user)
+    }
+
+    /// Lift the freeze on a user's account (admin only)
+    pub fn unfreeze_user(env: Env, admin: Address, user: Address) -> Result<(), Error> {
+        admin::unfreeze_user(&env, &admin, &user)
+    }
+
+    /// Get the users whose accounts are frozen
+    pub fn get_frozen_users(env: Env) -> Vec<Address> {
+        admin::get_frozen_users(&env)
+    }
+
+    /// Check if a user's account is frozen
+    pub fn is_user_frozen(env: Env, user: Address) -> bool {
+        admin::is_user_frozen(&env, &user)
+    }
+
+    /// Rebuild a verifier's pending queue for a quest, returning the entries corrected (admin only)
+    pub fn reindex_verifier_queue(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+        quest_id: Symbol,
+    ) -> Result<u32, Error> {
+        admin::reindex_verifier_queue(&env, &admin, &verifier, &quest_id)
+    }
+
+    /// Rebuild a user's submission index, returning the entries corrected (admin only)
+    pub fn reindex_user_submission_list(
+        env: Env,
+        admin: Address,
+        user: Address,
+    ) -> Result<u32, Error> {
+        admin::reindex_user_submission_list(&env, &admin, &user)
+    }
+
+    /// Create a stream of quests with a completion bonus (creator only)
+    pub fn create_stream(
+        env: Env,
+        creator: Address,
+        quest_ids: Vec<Symbol>,
+        stream_name: Symbol,
+        bonus_xp_on_full_completion: u32,
+    ) -> Result<u32, Error> {
+        quest_stream::create_stream(
+            &env,
+            &creator,
+            quest_ids,
+            stream_name,
+            bonus_xp_on_full_completion,
+        )
+    }
+
+    /// Get a quest stream
+    pub fn get_stream(env: Env, stream_id: u32) -> Result<QuestStream, Error> {
+        quest_stream::get_stream(&env, stream_id)
+    }
+
+    /// Create one quest per day for `cycles` days from a verification template, each with
+    /// `participants_per_cycle` slots, escrowing every cycle's rewards upfront (max 30 cycles).
+    /// The creation fee and creator quest limit apply once per series.
+    pub fn create_recurring_daily_quest(
+        env: Env,
+        creator: Address,
+        template_id: u32,
+        cycles: u32,
+        reward_per_cycle: i128,
+        participants_per_cycle: u32,
+        reward_asset: Address,
+    ) -> Result<Vec<Symbol>, Error> {
+        quest::create_recurring_daily_quest(
+            &env,
+            &creator,
+            template_id,
+            cycles,
+            reward_per_cycle,
+            participants_per_cycle,
+            &reward_asset,
+        )
+    }
+
+    /// Get a daily quest series
+    pub fn get_daily_series(env: Env, series_id: u32) -> Result<DailyQuestSeries, Error> {
+        quest::get_daily_series(&env, series_id)
+    }
+
+    /// Move a daily series to the quest open for the day (anyone)
+    pub fn advance_daily_series(env: Env, series_id: u32) -> Result<Symbol, Error> {
+        quest::advance_daily_series(&env, series_id)
+    }
+
+    /// Get (cycles_completed, total_cycles) for a user in a daily series
+    pub fn get_series_progress(env: Env, series_id: u32, user: Address) -> (u32, u32) {
+        quest::get_series_progress(&env, series_id, &user)
+    }
+
+    /// Check stream completion for a user, awarding the bonus XP once
+    pub fn check_stream_completion(env: Env, stream_id: u32, user: Address) -> Result<bool, Error> {
+        quest_stream::check_completion(&env, stream_id, &user)
+    }
+
+    /// Get platform-wide activity counters
+    pub fn get_platform_stats(env: Env) -> PlatformStats {
+        storage::get_platform_stats(&env)
+    }
+
+    /// Get the top `n` users by XP
+    pub fn get_leaderboard(env: Env, n: u32) -> Vec<(Address, u32)> {
+        leaderboard::get_leaderboard(&env, n)
+    }
+
+    /// Export stats for up to 100 users (admin only)
+    pub fn export_user_stats_batch(
+        env: Env,
+        admin: Address,
+        users: Vec<Address>,
+    ) -> Result<Vec<UserStats>, Error> {
+        admin::export_user_stats_batch(&env, &admin, users)
+    }
+
+    /// Capture a snapshot of the platform state (admin only, rate limited)
+    pub fn export_platform_snapshot(env: Env, admin: Address) -> Result<PlatformSnapshot, Error> {
+        admin::export_platform_snapshot(&env, &admin)
+    }
+
+    /// Get the top `n` users by XP earned this season
+    pub fn get_monthly_leaderboard(env: Env, n: u32) -> Vec<(Address, u32)> {
+        leaderboard::get_monthly_leaderboard(&env, n)
+    }
+
+    /// End the current leaderboard season (admin only)
+    pub fn reset_monthly_leaderboard(env: Env, admin: Address) -> Result<(), Error> {
+        admin::reset_monthly_leaderboard(&env, &admin)
+    }
+
+    /// Get the top users of every past season as (ended_at, top_users)
+    pub fn get_monthly_archive(env: Env) -> Vec<(u64, Vec<(Address, u32)>)> {
+        leaderboard::get_monthly_archive(&env)
+    }
+
+    /// Get a user's 1-based leaderboard rank, if ranked
+    pub fn get_user_rank(env: Env, user: Address) -> Option<u32> {
+        leaderboard::get_user_rank(&env, &user)
+    }
+
+    /// Get a user's daily leaderboard rank over the last `days` days, oldest first (max 90)
+    pub fn get_user_rank_history(env: Env, user: Address, days: u32) -> Vec<(u64, Option<u32>)> {
+        leaderboard::get_user_rank_history(&env, &user, days)
+    }
+
+    /// Get a user's rank change over 7 days (negative = improved)
+    pub fn get_rank_change_7d(env: Env, user: Address) -> i32 {
+        leaderboard::get_rank_change_7d(&env, &user)
+    }
+
+    /// Get a user's XP percentile in basis points (0 = top, approximate beyond the top 100)
+    pub fn get_xp_percentile(env: Env, user: Address) -> u32 {
+        leaderboard::get_xp_percentile(&env, &user)
+    }
+
+    /// Get (xp_threshold, user_count) at the 10/25/50/75/90/95/99th XP percentiles
+    pub fn get_xp_distribution(env: Env) -> Vec<(u32, u32)> {
+        leaderboard::get_xp_distribution(&env)
+    }
+
+    /// Get the number of users with stats
+    pub fn get_total_user_count(env: Env) -> u32 {
+        reputation::get_total_user_count(&env)
+    }
+
+    /// Estimate the CPU instructions needed to approve `count` submissions (approximate)
+    pub fn estimate_gas_for_batch_approve(_env: Env, count: u32) -> u64 {
+        submission::estimate_gas_for_batch_approve(count)
+    }
+
+    /// Get the submissions created at a ledger sequence
+    pub fn get_submissions_at_ledger(env: Env, sequence: u32) -> Vec<Submission> {
+        submission::get_submissions_at_ledger(&env, sequence)
+    }
+
+    /// Get the number of pending submissions across all quests
+    pub fn get_global_pending_count(env: Env) -> u32 {
+        submission::get_global_pending_count(&env)
+    }
+
+    /// Get the number of pending submissions for a quest
+    pub fn get_quest_pending_count(env: Env, quest_id: Symbol) -> u32 {
+        submission::get_quest_pending_count(&env, &quest_id)
+    }
+
+    /// Estimate the seconds a new submission to a quest would wait for review
+    pub fn predict_approval_time(env: Env, quest_id: Symbol) -> Option<u64> {
+        submission::predict_approval_time(&env, &quest_id)
+    }
+
+    /// Get a pending submission's 1-based position in its verifier's review queue
+    pub fn get_queue_position(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+    ) -> Result<u32, Error> {
+        submission::get_queue_position(&env, &quest_id, &submitter)
+    }
+
+    /// Register the address that referred the user (one-time)
+    pub fn register_referrer(env: Env, user: Address, referrer: Address) -> Result<(), Error> {
+        referral::register_referrer(&env, &user, &referrer)
+    }
+
+    /// Get the address that referred a user, if any
+    pub fn get_referrer(env: Env, user: Address) -> Option<Address> {
+        referral::get_referrer(&env, &user)
+    }
+
+    /// Get the top referrers as (address, total_earnings, referral_count)
+    pub fn get_referral_leaderboard(env: Env, n: u32) -> Vec<(Address, i128, u32)> {
+        referral::get_referral_leaderboard(&env, n)
+    }
+
+    /// Set the XP granted at referral count milestones (admin only)
+    pub fn set_referral_milestone_xp(
+        env: Env,
+        admin: Address,
+        milestones: Vec<(u32, u32)>,
+    ) -> Result<(), Error> {
+        admin::set_referral_milestone_xp(&env, &admin, milestones)
+    }
+
+    /// Get the XP granted at each referral count milestone
+    pub fn get_referral_milestone_xp(env: Env) -> Vec<(u32, u32)> {
+        referral::get_referral_milestone_xp(&env)
+    }
+
+    /// Get the number of referred submissions paid out for a referrer
+    pub fn get_referral_count(env: Env, referrer: Address) -> u32 {
+        referral::get_referral_count(&env, &referrer)
+    }
+
+    /// Add tokens to the referral bonus pool of an asset (anyone)
+    pub fn fund_referral_pool(
+        env: Env,
+        funder: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        referral::fund_referral_pool(&env, &funder, &asset, amount)
+    }
+
+    /// Get the referral bonus pool balance of an asset
+    pub fn get_referral_pool(env: Env, asset: Address) -> i128 {
+        referral::get_referral_pool(&env, &asset)
     }
 }
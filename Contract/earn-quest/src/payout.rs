@@ -1 +1,19 @@
+use soroban_sdk::{token, Address, Env};
 
+/// Transfer reward tokens from a funder into the contract's escrow
+pub fn deposit_to_escrow(env: &Env, asset: &Address, from: &Address, amount: i128) {
+    let token_client = token::Client::new(env, asset);
+    token_client.transfer(from, &env.current_contract_address(), &amount);
+}
+
+/// Get the contract's own balance of an asset
+pub fn contract_balance(env: &Env, asset: &Address) -> i128 {
+    let token_client = token::Client::new(env, asset);
+    token_client.balance(&env.current_contract_address())
+}
+
+/// Transfer reward tokens from the contract's escrow to a recipient
+pub fn release_from_escrow(env: &Env, asset: &Address, to: &Address, amount: i128) {
+    let token_client = token::Client::new(env, asset);
+    token_client.transfer(&env.current_contract_address(), to, &amount);
+}
@@ -1,10 +1,21 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
 
+use crate::admin;
+use crate::analytics;
 use crate::errors::Error;
+use crate::payout;
+use crate::reputation;
 use crate::storage;
-use crate::types::{Quest, QuestStatus};
+use crate::types::{
+    CompletionCertificate, CreationFeePayment, DailyQuestSeries, Quest, QuestDifficulty,
+    QuestStatus, QuestV1, QuestVelocity, RewardMode, SubmissionStatus,
+};
 
-/// Create and register a new quest
+/// Layout version written to new quest records
+pub const QUEST_METADATA_VERSION: u32 = 2;
+
+/// Create and register a new quest, escrowing `reward_amount * max_participants` from the
+/// creator after any creation fee
 #[allow(clippy::too_many_arguments)]
 pub fn create_quest(
     env: &Env,
@@ -15,11 +26,508 @@ pub fn create_quest(
     verifier: Address,
     deadline: u64,
     max_participants: u32,
+    difficulty: QuestDifficulty,
 ) -> Result<(), Error> {
     // Verify creator authorization
     creator.require_auth();
 
+    register_authorized_quest(
+        env,
+        id,
+        creator,
+        reward_asset,
+        reward_amount,
+        verifier,
+        deadline,
+        max_participants,
+        difficulty,
+    )
+}
+
+/// Register a quest once the caller has checked the creator's authorization
+#[allow(clippy::too_many_arguments)]
+fn register_authorized_quest(
+    env: &Env,
+    id: Symbol,
+    creator: Address,
+    reward_asset: Address,
+    reward_amount: i128,
+    verifier: Address,
+    deadline: u64,
+    max_participants: u32,
+    difficulty: QuestDifficulty,
+) -> Result<(), Error> {
     // Validate inputs
+    validate_quest_params(env, &id, reward_amount, deadline, max_participants)?;
+
+    reserve_creation_slot(env, &creator)?;
+
+    // Verifier must have enough review history
+    admin::ensure_verifier_qualified(env, &verifier)?;
+
+    collect_creation_fee(env, &creator, &id)?;
+
+    // Escrow the full reward pool in a separate transfer from the fee
+    let pool = reward_amount
+        .checked_mul(max_participants as i128)
+        .ok_or(Error::InvalidRewardAmount)?;
+    payout::deposit_to_escrow(env, &reward_asset, &creator, pool);
+    storage::set_quest_escrow(env, &id, &reward_asset, pool);
+
+    store_new_quest(
+        env,
+        new_quest(
+            env,
+            id,
+            creator,
+            reward_asset,
+            reward_amount,
+            verifier,
+            deadline,
+            max_participants,
+            difficulty,
+        ),
+    );
+
+    Ok(())
+}
+
+/// Count a quest creation against the per-creator quest creation limit
+fn reserve_creation_slot(env: &Env, creator: &Address) -> Result<(), Error> {
+    let created = storage::get_creator_quest_count(env, creator);
+    if created >= admin::get_creator_limit(env, creator) {
+        return Err(Error::QuestCreationLimitReached);
+    }
+    storage::set_creator_quest_count(env, creator, created + 1);
+    Ok(())
+}
+
+/// Collect the creation fee for a quest, if one is configured. The contract holds it until
+/// the refund window has passed, then it can be swept to the treasury
+fn collect_creation_fee(env: &Env, creator: &Address, quest_id: &Symbol) -> Result<(), Error> {
+    if let Some((fee, asset)) = admin::get_creation_fee(env) {
+        if fee > 0 {
+            let treasury = admin::get_treasury(env).ok_or(Error::Unauthorized)?;
+            payout::deposit_to_escrow(env, &asset, creator, fee);
+            let payment = CreationFeePayment {
+                amount: fee,
+                asset,
+                treasury,
+                paid_at: env.ledger().timestamp(),
+            };
+            storage::set_creation_fee_paid(env, quest_id, &payment);
+        }
+    }
+    Ok(())
+}
+
+/// Build an active quest with default settings
+#[allow(clippy::too_many_arguments)]
+fn new_quest(
+    env: &Env,
+    id: Symbol,
+    creator: Address,
+    reward_asset: Address,
+    reward_amount: i128,
+    verifier: Address,
+    deadline: u64,
+    max_participants: u32,
+    difficulty: QuestDifficulty,
+) -> Quest {
+    Quest {
+        metadata_version: QUEST_METADATA_VERSION,
+        id,
+        creator,
+        reward_asset,
+        reward_amount,
+        verifier,
+        deadline,
+        status: QuestStatus::Active,
+        max_participants,
+        total_claims: 0,
+        difficulty,
+        auto_reject_after: 0,
+        auto_approve_after: 0,
+        live_until_ledger: 0,
+        min_completions: 0,
+        sponsorship_address: None,
+        sponsor_name: None,
+        dual_approval_required: false,
+        reward_mode: RewardMode::Fixed,
+        avg_score: 0,
+        created_at: env.ledger().timestamp(),
+        verification_template_id: None,
+        auto_payout: false,
+        whitelist_sealed: false,
+    }
+}
+
+/// Store a newly created quest and add it to the quest indexes
+fn store_new_quest(env: &Env, quest: Quest) {
+    let id = quest.id.clone();
+    storage::set_quest(env, &quest);
+    storage::add_quest_id(env, &id);
+    storage::add_creator_quest(env, &quest.creator, &id);
+    storage::add_difficulty_quest(env, quest.difficulty, &id);
+    storage::add_verifier_quest(env, &quest.verifier, &id);
+    storage::add_fill_bucket_quest(env, 0, &id);
+    storage::add_reward_index_quest(env, &quest.reward_asset, quest.reward_amount, &id);
+
+    let mut platform = storage::get_platform_stats(env);
+    platform.total_quests += 1;
+    storage::set_platform_stats(env, &platform);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "quest_reg"), id), quest);
+}
+
+/// Convert a version 1 quest record to the current layout. Fields added since version 1
+/// take their defaults, as for a newly registered quest.
+pub fn migrate_v1_to_v2(legacy: QuestV1) -> Quest {
+    Quest {
+        metadata_version: 2,
+        id: legacy.id,
+        creator: legacy.creator,
+        reward_asset: legacy.reward_asset,
+        reward_amount: legacy.reward_amount,
+        verifier: legacy.verifier,
+        deadline: legacy.deadline,
+        status: legacy.status,
+        max_participants: legacy.max_participants,
+        total_claims: legacy.total_claims,
+        difficulty: QuestDifficulty::Beginner,
+        auto_reject_after: 0,
+        auto_approve_after: 0,
+        live_until_ledger: 0,
+        min_completions: 0,
+        sponsorship_address: None,
+        sponsor_name: None,
+        dual_approval_required: false,
+        reward_mode: RewardMode::Fixed,
+        avg_score: 0,
+        created_at: 0,
+        verification_template_id: None,
+        auto_payout: false,
+        whitelist_sealed: false,
+    }
+}
+
+/// Maximum length of a symbol, and so of a chained quest ID
+const MAX_SYMBOL_LEN: usize = 32;
+
+/// Create the next quest of a chain as a copy of `source_quest_id` (creator only).
+/// The copy is named `<original>_v<chain_index>` after the chain's original quest, keeps the
+/// source's reward, verifier, deadline, participant limit and difficulty, and starts empty.
+pub fn duplicate_to_chain(
+    env: &Env,
+    source_quest_id: &Symbol,
+    caller: &Address,
+    chain_index: u32,
+) -> Result<Symbol, Error> {
+    let source = storage::get_quest(env, source_quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if source.creator != *caller {
+        return Err(Error::Unauthorized);
+    }
+
+    let root = storage::get_chain_root(env, source_quest_id).unwrap_or(source_quest_id.clone());
+    let id = chained_quest_id(env, &root, chain_index)?;
+
+    create_quest(
+        env,
+        id.clone(),
+        caller.clone(),
+        source.reward_asset,
+        source.reward_amount,
+        source.verifier,
+        source.deadline,
+        source.max_participants,
+        source.difficulty,
+    )?;
+
+    // Track the new quest in the chain
+    let mut chain = get_chain(env, &root);
+    chain.push_back(id.clone());
+    storage::set_quest_chain(env, &root, &chain);
+    storage::set_chain_root(env, &id, &root);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "quest_chained"), root), id.clone());
+
+    Ok(id)
+}
+
+/// Get every quest of the chain a quest belongs to, starting with the original.
+/// A quest that was never duplicated forms a chain of its own.
+pub fn get_chain(env: &Env, quest_id: &Symbol) -> Vec<Symbol> {
+    let root = storage::get_chain_root(env, quest_id).unwrap_or(quest_id.clone());
+    let chain = storage::get_quest_chain(env, &root);
+    if !chain.is_empty() {
+        return chain;
+    }
+
+    let mut chain = Vec::new(env);
+    if storage::has_quest(env, &root) {
+        chain.push_back(root);
+    }
+    chain
+}
+
+/// Get the most recently created quest of the chain a quest belongs to
+pub fn get_latest_in_chain(env: &Env, quest_id: &Symbol) -> Result<Symbol, Error> {
+    get_chain(env, quest_id).last().ok_or(Error::QuestNotFound)
+}
+
+/// Copy a symbol's characters into `buf` without allocating, reading them from its XDR.
+/// Returns the number of characters copied.
+fn symbol_chars(env: &Env, symbol: &Symbol, buf: &mut [u8]) -> usize {
+    // ScVal::Symbol XDR: 4-byte type tag, 4-byte big-endian length, then the padded characters
+    let xdr = symbol.clone().to_xdr(env);
+    let mut len_bytes = [0u8; 4];
+    xdr.slice(4..8).copy_into_slice(&mut len_bytes);
+    let len = u32::from_be_bytes(len_bytes) as usize;
+
+    xdr.slice(8..8 + len as u32)
+        .copy_into_slice(&mut buf[..len]);
+    len
+}
+
+/// Write the decimal digits of `n` into `buf` at `len`.
+/// Returns the new length, or `None` if the digits do not fit.
+fn push_digits(buf: &mut [u8], len: usize, n: u32) -> Option<usize> {
+    let mut digits = [0u8; 10];
+    let mut digit_count = 0;
+    let mut n = n;
+    loop {
+        digits[digit_count] = b'0' + (n % 10) as u8;
+        digit_count += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+
+    if len + digit_count > buf.len() {
+        return None;
+    }
+    let mut len = len;
+    for i in (0..digit_count).rev() {
+        buf[len] = digits[i];
+        len += 1;
+    }
+    Some(len)
+}
+
+/// Build `<root>_v<index>` from the chain's original quest ID
+fn chained_quest_id(env: &Env, root: &Symbol, index: u32) -> Result<Symbol, Error> {
+    let mut buf = [0u8; MAX_SYMBOL_LEN];
+    let mut len = symbol_chars(env, root, &mut buf);
+
+    if len + 2 >= MAX_SYMBOL_LEN {
+        return Err(Error::SymbolTooLong);
+    }
+    buf[len] = b'_';
+    buf[len + 1] = b'v';
+    len += 2;
+    let len = push_digits(&mut buf, len, index).ok_or(Error::SymbolTooLong)?;
+
+    let name = core::str::from_utf8(&buf[..len]).map_err(|_| Error::SymbolTooLong)?;
+    Ok(Symbol::new(env, name))
+}
+
+/// Maximum number of cycles in a daily quest series
+pub const MAX_DAILY_SERIES_CYCLES: u32 = 30;
+
+/// Seconds between the deadlines of consecutive quests in a daily series
+const DAILY_SERIES_INTERVAL: u64 = 86400;
+
+/// Build `daily<series_id>_<cycle>` for a quest of a daily series
+fn daily_quest_id(env: &Env, series_id: u32, cycle: u32) -> Result<Symbol, Error> {
+    let mut buf = [0u8; MAX_SYMBOL_LEN];
+    buf[..5].copy_from_slice(b"daily");
+    let len = push_digits(&mut buf, 5, series_id).ok_or(Error::SymbolTooLong)?;
+    buf[len] = b'_';
+    let len = push_digits(&mut buf, len + 1, cycle).ok_or(Error::SymbolTooLong)?;
+
+    let name = core::str::from_utf8(&buf[..len]).map_err(|_| Error::SymbolTooLong)?;
+    Ok(Symbol::new(env, name))
+}
+
+/// Create `cycles` quests of `participants_per_cycle` slots from a verification template, the
+/// i-th expiring i days from now, and escrow `cycles * participants_per_cycle * reward_per_cycle`
+/// from the creator upfront. The series counts once against the creator's quest limit, and the
+/// creation fee is charged once, held against the first cycle's quest.
+/// Returns the IDs of the created quests.
+pub fn create_recurring_daily_quest(
+    env: &Env,
+    creator: &Address,
+    template_id: u32,
+    cycles: u32,
+    reward_per_cycle: i128,
+    participants_per_cycle: u32,
+    reward_asset: &Address,
+) -> Result<Vec<Symbol>, Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    if cycles == 0 || cycles > MAX_DAILY_SERIES_CYCLES {
+        return Err(Error::InvalidConfig);
+    }
+    let template = storage::get_template(env, template_id).ok_or(Error::TemplateNotFound)?;
+    let escrow_per_cycle = reward_per_cycle
+        .checked_mul(participants_per_cycle as i128)
+        .ok_or(Error::InvalidRewardAmount)?;
+    let total = escrow_per_cycle
+        .checked_mul(cycles as i128)
+        .ok_or(Error::InvalidRewardAmount)?;
+
+    reserve_creation_slot(env, creator)?;
+
+    // Verifier must have enough review history
+    admin::ensure_verifier_qualified(env, &template.verifier)?;
+
+    let series_id = storage::get_daily_series_count(env) + 1;
+    let now = env.ledger().timestamp();
+    let mut quest_ids = Vec::new(env);
+    for cycle in 1..=cycles {
+        let id = daily_quest_id(env, series_id, cycle)?;
+        let deadline = now + cycle as u64 * DAILY_SERIES_INTERVAL;
+        validate_quest_params(env, &id, reward_per_cycle, deadline, participants_per_cycle)?;
+        if cycle == 1 {
+            collect_creation_fee(env, creator, &id)?;
+        }
+
+        let mut quest = new_quest(
+            env,
+            id.clone(),
+            creator.clone(),
+            reward_asset.clone(),
+            reward_per_cycle,
+            template.verifier.clone(),
+            deadline,
+            participants_per_cycle,
+            QuestDifficulty::Beginner,
+        );
+        quest.verification_template_id = Some(template_id);
+        store_new_quest(env, quest);
+        storage::set_quest_escrow(env, &id, reward_asset, escrow_per_cycle);
+        quest_ids.push_back(id);
+    }
+
+    // Escrow every cycle's reward upfront
+    payout::deposit_to_escrow(env, reward_asset, creator, total);
+
+    let series = DailyQuestSeries {
+        series_id,
+        quest_ids: quest_ids.clone(),
+        active_index: 0,
+    };
+    storage::set_daily_series(env, &series);
+    storage::set_daily_series_count(env, series_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "daily_series_created"), series_id),
+        (creator.clone(), cycles, total),
+    );
+
+    Ok(quest_ids)
+}
+
+/// Get a daily quest series
+pub fn get_daily_series(env: &Env, series_id: u32) -> Result<DailyQuestSeries, Error> {
+    storage::get_daily_series(env, series_id).ok_or(Error::SeriesNotFound)
+}
+
+/// Move a daily series past every quest whose deadline has passed, stopping at the last one.
+/// Callable by anyone (e.g. keeper bots). Returns the quest open for the day.
+pub fn advance_daily_series(env: &Env, series_id: u32) -> Result<Symbol, Error> {
+    let mut series = get_daily_series(env, series_id)?;
+    let now = env.ledger().timestamp();
+
+    let start = series.active_index;
+    while series.active_index + 1 < series.quest_ids.len() {
+        let active = series.quest_ids.get(series.active_index).unwrap();
+        let expired = storage::get_quest(env, &active)
+            .map(|q| now >= q.deadline)
+            .unwrap_or(true);
+        if !expired {
+            break;
+        }
+        series.active_index += 1;
+    }
+
+    let active = series.quest_ids.get(series.active_index).unwrap();
+    if series.active_index != start {
+        storage::set_daily_series(env, &series);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "daily_series_advanced"), series_id),
+            (series.active_index, active.clone()),
+        );
+    }
+
+    Ok(active)
+}
+
+/// Get (cycles_completed, total_cycles) for a user in a daily series, counting approved
+/// and paid submissions
+pub fn get_series_progress(env: &Env, series_id: u32, user: &Address) -> (u32, u32) {
+    let Some(series) = storage::get_daily_series(env, series_id) else {
+        return (0, 0);
+    };
+
+    let mut completed = 0;
+    for quest_id in series.quest_ids.iter() {
+        let done = storage::get_submission(env, &quest_id, user)
+            .map(|s| s.status.is_approved() || s.status == SubmissionStatus::Paid)
+            .unwrap_or(false);
+        if done {
+            completed += 1;
+        }
+    }
+
+    (completed, series.quest_ids.len())
+}
+
+/// Prefix of the badge awarded to paid submitters of a sponsored quest
+const SPONSOR_BADGE_PREFIX: &[u8] = b"sponsored_by_";
+
+/// Build the `sponsored_by_<sponsor_name>` badge symbol
+pub fn sponsor_badge_symbol(env: &Env, sponsor_name: &Symbol) -> Result<Symbol, Error> {
+    let mut name = [0u8; MAX_SYMBOL_LEN];
+    let name_len = symbol_chars(env, sponsor_name, &mut name);
+
+    let prefix_len = SPONSOR_BADGE_PREFIX.len();
+    if prefix_len + name_len > MAX_SYMBOL_LEN {
+        return Err(Error::SymbolTooLong);
+    }
+    let mut buf = [0u8; MAX_SYMBOL_LEN];
+    buf[..prefix_len].copy_from_slice(SPONSOR_BADGE_PREFIX);
+    buf[prefix_len..prefix_len + name_len].copy_from_slice(&name[..name_len]);
+
+    let badge =
+        core::str::from_utf8(&buf[..prefix_len + name_len]).map_err(|_| Error::SymbolTooLong)?;
+    Ok(Symbol::new(env, badge))
+}
+
+/// Validate quest creation parameters without changing any state
+pub fn validate_quest_params(
+    env: &Env,
+    id: &Symbol,
+    reward_amount: i128,
+    deadline: u64,
+    max_participants: u32,
+) -> Result<(), Error> {
+    // Verify the contract is live
+    if !admin::is_live(env) {
+        return Err(Error::ContractNotLive);
+    }
+
     if reward_amount <= 0 {
         return Err(Error::InvalidRewardAmount);
     }
@@ -34,37 +542,877 @@ pub fn create_quest(
         return Err(Error::InvalidDeadline);
     }
 
-    // Check quest doesn't already exist
-    if storage::has_quest(env, &id) {
-        return Err(Error::QuestAlreadyExists);
+    // Check quest doesn't already exist
+    if storage::has_quest(env, id) {
+        return Err(Error::QuestAlreadyExists);
+    }
+
+    Ok(())
+}
+
+/// Share of a quest's approvals that were automatic, in basis points
+pub fn get_auto_approve_rate(env: &Env, quest_id: &Symbol) -> u32 {
+    let counts = storage::submission_status_counts(env, quest_id);
+    let total = counts.approved + counts.auto_approved;
+    if total == 0 {
+        return 0;
+    }
+    counts.auto_approved * 10_000 / total
+}
+
+/// Get all quest IDs of a given difficulty
+pub fn get_quests_by_difficulty(env: &Env, difficulty: QuestDifficulty) -> Vec<Symbol> {
+    storage::get_difficulty_quests(env, difficulty)
+}
+
+/// Add participant slots to an existing quest, escrowing the reward for each new slot.
+/// `reward_amount` is paid per participant, so each slot costs exactly one `reward_amount`.
+pub fn fund_additional_slots(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    additional_slots: u32,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    // Only active quests can be topped up
+    if quest.status != QuestStatus::Active {
+        return Err(Error::QuestNotActive);
+    }
+
+    if additional_slots == 0 {
+        return Err(Error::InvalidParticipantLimit);
+    }
+
+    let max_participants = quest
+        .max_participants
+        .checked_add(additional_slots)
+        .ok_or(Error::InvalidParticipantLimit)?;
+    let amount = quest
+        .reward_amount
+        .checked_mul(additional_slots as i128)
+        .ok_or(Error::InvalidRewardAmount)?;
+
+    // Escrow the additional reward pool
+    payout::deposit_to_escrow(env, &quest.reward_asset, creator, amount);
+    let escrow = storage::get_quest_escrow(env, quest_id) + amount;
+    storage::set_quest_escrow(env, quest_id, &quest.reward_asset, escrow);
+
+    // Increase participant limit
+    let old_bucket = fill_bucket(&quest);
+    quest.max_participants = max_participants;
+    storage::set_quest(env, &quest);
+    move_fill_bucket(env, quest_id, old_bucket, fill_bucket(&quest));
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "slots_added"), quest_id.clone()),
+        (additional_slots, amount),
+    );
+
+    Ok(())
+}
+
+/// Set the sponsor of a quest (creator only). Paid submitters of the quest are awarded a
+/// `sponsored_by_<sponsor_name>` badge, so the name must leave room for the prefix.
+pub fn set_sponsor(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    sponsor: &Address,
+    sponsor_name: &Symbol,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    // Validate the badge name fits in a symbol
+    sponsor_badge_symbol(env, sponsor_name)?;
+
+    // Move the quest between sponsor indexes
+    if let Some(previous) = &quest.sponsorship_address {
+        storage::remove_sponsored_quest(env, previous, quest_id);
+    }
+    storage::add_sponsored_quest(env, sponsor, quest_id);
+
+    quest.sponsorship_address = Some(sponsor.clone());
+    quest.sponsor_name = Some(sponsor_name.clone());
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "sponsor_set"), quest_id.clone()),
+        (sponsor.clone(), sponsor_name.clone()),
+    );
+
+    Ok(())
+}
+
+/// Get the sponsor of a quest, if any
+pub fn get_sponsor(env: &Env, quest_id: &Symbol) -> Option<Address> {
+    storage::get_quest(env, quest_id)?.sponsorship_address
+}
+
+/// Get the IDs of quests sponsored by an address
+pub fn get_sponsored_quests(env: &Env, sponsor: &Address) -> Vec<Symbol> {
+    storage::get_sponsored_quests(env, sponsor)
+}
+
+/// Add funds to a quest's reward escrow without adding participant slots
+/// (creator or sponsor only). Unspent escrow is returned to the creator on cancellation.
+pub fn contribute_to_reward(
+    env: &Env,
+    quest_id: &Symbol,
+    contributor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    // Verify contributor authorization
+    contributor.require_auth();
+
+    // Get quest
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator or sponsor
+    if quest.creator != *contributor && quest.sponsorship_address.as_ref() != Some(contributor) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    // Escrow the contribution
+    payout::deposit_to_escrow(env, &quest.reward_asset, contributor, amount);
+    let escrow = storage::get_quest_escrow(env, quest_id) + amount;
+    storage::set_quest_escrow(env, quest_id, &quest.reward_asset, escrow);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "reward_contrib"), quest_id.clone()),
+        (contributor.clone(), amount),
+    );
+
+    Ok(())
+}
+
+/// Check if a quest has reached its participant limit
+pub fn is_quest_full(quest: &Quest) -> bool {
+    quest.total_claims >= quest.max_participants
+}
+
+/// Width of one fill-level index bucket, in basis points
+pub const FILL_BUCKET_BPS: u32 = 1000;
+
+/// Share of a quest's participant slots that have been claimed, in basis points.
+/// A quest without a participant limit is reported as full.
+pub fn fill_bps(quest: &Quest) -> u32 {
+    if quest.max_participants == 0 {
+        return 10_000;
+    }
+    (quest.total_claims as u64 * 10_000 / quest.max_participants as u64) as u32
+}
+
+/// Fill-level index bucket of a quest
+fn fill_bucket(quest: &Quest) -> u32 {
+    fill_bps(quest) / FILL_BUCKET_BPS
+}
+
+/// Move a quest between fill-level index buckets
+fn move_fill_bucket(env: &Env, quest_id: &Symbol, old_bucket: u32, new_bucket: u32) {
+    if old_bucket != new_bucket {
+        storage::remove_fill_bucket_quest(env, old_bucket, quest_id);
+        storage::add_fill_bucket_quest(env, new_bucket, quest_id);
+    }
+}
+
+/// Count an approved claim against a quest and keep its fill-level index current
+pub fn record_claim(env: &Env, quest: &mut Quest) {
+    let old_bucket = fill_bucket(quest);
+    quest.total_claims += 1;
+    storage::set_quest(env, quest);
+    move_fill_bucket(env, &quest.id, old_bucket, fill_bucket(quest));
+}
+
+/// Get the fill level of a quest in basis points
+pub fn compute_fill_percentage(env: &Env, quest_id: &Symbol) -> Result<u32, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    Ok(fill_bps(&quest))
+}
+
+/// Get the amount paid to each approved participant.
+/// Quests use fixed rewards: every approval pays the full `reward_amount` rather than
+/// a share of a pool, so this is `reward_amount` whatever the participant limit.
+pub fn get_reward_per_slot(env: &Env, quest_id: &Symbol) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    Ok(quest.reward_amount)
+}
+
+/// Get the most a quest can pay out in total: one `reward_amount` per participant slot.
+/// Saturates at `i128::MAX` rather than overflowing.
+pub fn get_total_potential_payout(env: &Env, quest_id: &Symbol) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    Ok(quest
+        .reward_amount
+        .saturating_mul(quest.max_participants as i128))
+}
+
+/// Maximum number of quest IDs returned by a reward search
+pub const MAX_REWARD_SEARCH_RESULTS: u32 = 50;
+
+/// Get quests paying at least `min_amount` of `asset` per participant, highest reward first.
+/// Binary-searches the asset's sorted reward index; cancelled and completed quests are not indexed.
+pub fn search_quests_by_min_reward(env: &Env, asset: &Address, min_amount: i128) -> Vec<Symbol> {
+    let index = storage::get_reward_index(env, asset);
+
+    // Find the first entry with reward >= min_amount
+    let (mut low, mut high) = (0, index.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if index.get_unchecked(mid).0 < min_amount {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    let mut ids = Vec::new(env);
+    let mut position = index.len();
+    while position > low && ids.len() < MAX_REWARD_SEARCH_RESULTS {
+        position -= 1;
+        ids.push_back(index.get_unchecked(position).1);
+    }
+    ids
+}
+
+/// Maximum number of quest IDs returned per page of open quests for a user
+pub const MAX_OPEN_QUEST_RESULTS: u32 = 50;
+
+/// Get the first `MAX_OPEN_QUEST_RESULTS` quests a user can still submit to
+pub fn get_active_quests_for_user(env: &Env, user: &Address) -> Vec<Symbol> {
+    get_active_quests_for_user_paged(env, user, 0, MAX_OPEN_QUEST_RESULTS)
+}
+
+/// Get a page of quests a user can still submit to: active, before the deadline, not full,
+/// and without a live submission from the user. Whitelisted quests are left out since
+/// membership can only be shown with a Merkle proof. `limit` is capped to `MAX_OPEN_QUEST_RESULTS`,
+/// and only the last `MAX_QUEST_SCAN` registered quests are considered.
+pub fn get_active_quests_for_user_paged(
+    env: &Env,
+    user: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<Symbol> {
+    let mut ids = Vec::new(env);
+
+    // Banned users cannot submit anywhere
+    if storage::is_fraudulent(env, user) {
+        return ids;
+    }
+
+    let limit = limit.min(MAX_OPEN_QUEST_RESULTS);
+    let mut skipped = 0;
+    for quest_id in most_recent(storage::get_quest_ids(env)).iter() {
+        if ids.len() >= limit {
+            break;
+        }
+
+        if !is_open_to_user(env, &quest_id, user) {
+            continue;
+        }
+
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        ids.push_back(quest_id);
+    }
+
+    ids
+}
+
+/// Check if a user can still submit to a quest: active, before the deadline, not full,
+/// without a live submission from the user, and not whitelisted
+fn is_open_to_user(env: &Env, quest_id: &Symbol, user: &Address) -> bool {
+    let open = storage::get_quest(env, quest_id)
+        .map(|q| validate_quest_active(env, &q).is_ok())
+        .unwrap_or(false);
+    open && !storage::has_submission(env, quest_id, user)
+        && storage::get_whitelist_root(env, quest_id).is_none()
+}
+
+/// Maximum number of quests recommended to a user
+pub const MAX_RECOMMENDATIONS: u32 = 20;
+
+/// Recommend up to `n` quests a user can still submit to, highest reward first. Quests of the
+/// user's favorite difficulty (where they earned the most XP) are recommended; without a
+/// favorite, or when none are open, all open quests are. `n` is capped to `MAX_RECOMMENDATIONS`,
+/// and only the last `MAX_QUEST_SCAN` quests of each candidate list are considered.
+pub fn get_recommended_quests(env: &Env, user: &Address, n: u32) -> Vec<Symbol> {
+    // Banned users cannot submit anywhere
+    if storage::is_fraudulent(env, user) {
+        return Vec::new(env);
+    }
+
+    let n = n.min(MAX_RECOMMENDATIONS);
+    if let Some(difficulty) = reputation::compute_favorite_difficulty(env, user) {
+        let preferred = top_open_quests_by_reward(
+            env,
+            user,
+            most_recent(storage::get_difficulty_quests(env, difficulty)),
+            n,
+        );
+        if !preferred.is_empty() {
+            return preferred;
+        }
+    }
+
+    top_open_quests_by_reward(env, user, most_recent(storage::get_quest_ids(env)), n)
+}
+
+/// Maximum number of quests, the most recently registered, scanned by discovery queries
+/// that have no index to read from
+pub const MAX_QUEST_SCAN: u32 = 200;
+
+/// Keep the last `MAX_QUEST_SCAN` of `quest_ids`, in their original order
+fn most_recent(quest_ids: Vec<Symbol>) -> Vec<Symbol> {
+    quest_ids.slice(quest_ids.len().saturating_sub(MAX_QUEST_SCAN)..quest_ids.len())
+}
+
+/// Get up to `n` of `quest_ids` open to a user, highest reward first (ties keep input order)
+fn top_open_quests_by_reward(
+    env: &Env,
+    user: &Address,
+    quest_ids: Vec<Symbol>,
+    n: u32,
+) -> Vec<Symbol> {
+    let mut ranked: Vec<(Symbol, i128)> = Vec::new(env);
+
+    for quest_id in quest_ids.iter() {
+        if !is_open_to_user(env, &quest_id, user) {
+            continue;
+        }
+        let Some(quest) = storage::get_quest(env, &quest_id) else {
+            continue;
+        };
+
+        // Insert at the sorted position
+        let index = ranked
+            .iter()
+            .position(|(_, reward)| reward < quest.reward_amount)
+            .map(|i| i as u32)
+            .unwrap_or(ranked.len());
+        ranked.insert(index, (quest_id, quest.reward_amount));
+    }
+
+    let mut ids = Vec::new(env);
+    for (quest_id, _) in ranked.iter().take(n as usize) {
+        ids.push_back(quest_id);
+    }
+    ids
+}
+
+/// Maximum number of quests returned per page of a sorted quest listing
+pub const MAX_SORTED_QUEST_RESULTS: u32 = 50;
+
+/// Get a page of active quests paying in `asset` as (quest_id, reward_amount), highest
+/// reward first, read from the asset's reward index. `limit` is capped to
+/// `MAX_SORTED_QUEST_RESULTS`.
+pub fn get_quests_sorted_by_reward(
+    env: &Env,
+    asset: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<(Symbol, i128)> {
+    let mut quests = Vec::new(env);
+    let limit = limit.min(MAX_SORTED_QUEST_RESULTS);
+    let mut skipped = 0;
+    for (reward_amount, quest_id) in storage::get_reward_index(env, asset).iter().rev() {
+        if quests.len() >= limit {
+            break;
+        }
+
+        let active = storage::get_quest(env, &quest_id)
+            .map(|q| q.status == QuestStatus::Active)
+            .unwrap_or(false);
+        if !active {
+            continue;
+        }
+
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        quests.push_back((quest_id, reward_amount));
+    }
+
+    quests
+}
+
+/// Get a page of quest IDs, newest first. `limit` is capped to `MAX_SORTED_QUEST_RESULTS`.
+pub fn get_quests_sorted_by_recent(env: &Env, offset: u32, limit: u32) -> Vec<Symbol> {
+    let mut ids = Vec::new(env);
+    let all = storage::get_quest_ids(env);
+    let end = all.len().saturating_sub(offset);
+    let start = end.saturating_sub(limit.min(MAX_SORTED_QUEST_RESULTS));
+    for quest_id in all.slice(start..end).iter().rev() {
+        ids.push_back(quest_id);
+    }
+    ids
+}
+
+/// Get (day_epoch, cumulative_submissions) pairs for a quest, one per day from its
+/// creation day to today, capped to `MAX_QUERY_RANGE_DAYS` points
+pub fn get_submission_timeline(env: &Env, quest_id: &Symbol) -> Vec<(u64, u32)> {
+    cumulative_timeline(env, quest_id, storage::get_daily_submissions)
+}
+
+/// Get (day_epoch, cumulative_approvals) pairs for a quest, one per day from its
+/// creation day to today, capped to `MAX_QUERY_RANGE_DAYS` points
+pub fn get_approval_timeline(env: &Env, quest_id: &Symbol) -> Vec<(u64, u32)> {
+    cumulative_timeline(env, quest_id, storage::get_daily_approvals)
+}
+
+/// Accumulate a per-day quest counter from the quest's creation day
+fn cumulative_timeline(
+    env: &Env,
+    quest_id: &Symbol,
+    daily_count: fn(&Env, &Symbol, u64) -> u32,
+) -> Vec<(u64, u32)> {
+    let mut timeline = Vec::new(env);
+    let Some(quest) = storage::get_quest(env, quest_id) else {
+        return timeline;
+    };
+
+    let first_day = quest.created_at / analytics::SECONDS_PER_DAY;
+    let last_day = analytics::current_day(env)
+        .min(first_day.saturating_add(analytics::MAX_QUERY_RANGE_DAYS - 1));
+
+    let mut total: u32 = 0;
+    for day in first_day..=last_day {
+        total += daily_count(env, quest_id, day);
+        timeline.push_back((day, total));
+    }
+
+    timeline
+}
+
+/// Get recent submission and approval counts of a quest and the days until it fills
+/// at the average daily submission pace of the last 7 days
+pub fn get_quest_velocity(env: &Env, quest_id: &Symbol) -> Result<QuestVelocity, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    let submissions_last_24h = analytics::get_recent_submissions(env, quest_id, 1);
+    let submissions_last_7d = analytics::get_recent_submissions(env, quest_id, 7);
+    let approvals_last_24h =
+        storage::get_daily_approvals(env, quest_id, analytics::current_day(env));
+
+    // remaining / (submissions_last_7d / 7), without losing the fractional average
+    let estimated_fill_days = if quest.max_participants == 0 || submissions_last_7d == 0 {
+        u32::MAX
+    } else {
+        let remaining = quest.max_participants.saturating_sub(quest.total_claims) as u64;
+        (remaining * 7 / submissions_last_7d as u64).min(u32::MAX as u64) as u32
+    };
+
+    Ok(QuestVelocity {
+        submissions_last_24h,
+        submissions_last_7d,
+        approvals_last_24h,
+        estimated_fill_days,
+    })
+}
+
+/// Maximum number of quest IDs returned by `get_trending_quests`
+pub const MAX_TRENDING_RESULTS: u32 = 50;
+
+/// Get up to `n` active quests with submissions today, most submissions first
+/// (ties keep registration order). `n` is capped to `MAX_TRENDING_RESULTS`, and only the last
+/// `MAX_QUEST_SCAN` registered quests are considered.
+pub fn get_trending_quests(env: &Env, n: u32) -> Vec<Symbol> {
+    let mut trending: Vec<(Symbol, u32)> = Vec::new(env);
+
+    for quest_id in most_recent(storage::get_quest_ids(env)).iter() {
+        let active = storage::get_quest(env, &quest_id)
+            .map(|q| q.status == QuestStatus::Active)
+            .unwrap_or(false);
+        if !active {
+            continue;
+        }
+        let count = analytics::get_recent_submissions(env, &quest_id, 1);
+        if count == 0 {
+            continue;
+        }
+
+        // Insert at the sorted position
+        let index = trending
+            .iter()
+            .position(|(_, c)| c < count)
+            .map(|i| i as u32)
+            .unwrap_or(trending.len());
+        trending.insert(index, (quest_id, count));
+    }
+
+    let mut ids = Vec::new(env);
+    for (quest_id, _) in trending.iter().take(n.min(MAX_TRENDING_RESULTS) as usize) {
+        ids.push_back(quest_id);
+    }
+    ids
+}
+
+/// Average review time at or above which a quest earns no review points (3 days)
+pub const HEALTH_REVIEW_SLA_SECS: u64 = 3 * 24 * 3600;
+
+/// Add the time a submission waited for a verifier decision to the quest's and the
+/// verifier's review totals
+pub fn record_review_time(env: &Env, quest_id: &Symbol, verifier: &Address, submitted_at: u64) {
+    let waited = env.ledger().timestamp().saturating_sub(submitted_at);
+
+    let (sum, count) = storage::get_quest_review_times(env, quest_id);
+    storage::set_quest_review_times(env, quest_id, sum.saturating_add(waited), count + 1);
+
+    let (sum, count) = storage::get_verifier_review_times(env, verifier);
+    storage::set_verifier_review_times(env, verifier, sum.saturating_add(waited), count + 1);
+}
+
+/// Compute a 0-100 health score for a quest from:
+/// - fill level (0-40 points)
+/// - average review time against `HEALTH_REVIEW_SLA_SECS` (0-20 points)
+/// - average completion rate of the quest's submitters (0-20 points)
+/// - share of reviewed submissions that were not rejected (0-20 points)
+///
+/// Quests without reviews keep full review time and rejection points.
+pub fn compute_quest_health_score(env: &Env, quest_id: &Symbol) -> Result<u32, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    let fill_score = fill_bps(&quest) * 40 / 10_000;
+
+    let (review_sum, review_count) = storage::get_quest_review_times(env, quest_id);
+    let review_score = if review_count == 0 {
+        20
+    } else {
+        let avg = (review_sum / review_count as u64).min(HEALTH_REVIEW_SLA_SECS);
+        ((HEALTH_REVIEW_SLA_SECS - avg) * 20 / HEALTH_REVIEW_SLA_SECS) as u32
+    };
+
+    // Completion rate of a submitter: quests completed over quests submitted to
+    let mut rate_bps_sum: u64 = 0;
+    let submitters = storage::get_quest_submitters(env, quest_id);
+    for submitter in submitters.iter() {
+        let submitted = storage::get_user_submissions(env, &submitter).len();
+        let completed = storage::get_user_stats(env, &submitter)
+            .map(|stats| stats.quests_completed)
+            .unwrap_or(0);
+        if submitted > 0 {
+            rate_bps_sum += (completed.min(submitted) as u64) * 10_000 / submitted as u64;
+        }
+    }
+    let completion_score = if submitters.is_empty() {
+        0
+    } else {
+        (rate_bps_sum * 20 / (submitters.len() as u64 * 10_000)) as u32
+    };
+
+    let counts = storage::submission_status_counts(env, quest_id);
+    let rejected = counts.rejected + counts.auto_rejected;
+    let reviewed = counts.approved + counts.auto_approved + rejected;
+    let dispute_score = ((reviewed - rejected) * 20)
+        .checked_div(reviewed)
+        .unwrap_or(20);
+
+    Ok(fill_score + review_score + completion_score + dispute_score)
+}
+
+/// Recompute and cache the health score of a quest
+pub fn refresh_health_score(env: &Env, quest_id: &Symbol) {
+    if let Ok(score) = compute_quest_health_score(env, quest_id) {
+        storage::set_quest_health_score(env, quest_id, score);
+    }
+}
+
+/// Get up to `n` (quest_id, health_score) pairs with a cached score of at least `min_score`,
+/// best first (ties keep registration order). `n` is capped to `MAX_SORTED_QUEST_RESULTS`, and
+/// only the last `MAX_QUEST_SCAN` registered quests are considered.
+pub fn get_healthy_quests(env: &Env, min_score: u32, n: u32) -> Vec<(Symbol, u32)> {
+    let mut healthy: Vec<(Symbol, u32)> = Vec::new(env);
+
+    for quest_id in most_recent(storage::get_quest_ids(env)).iter() {
+        let score = match storage::get_quest_health_score(env, &quest_id) {
+            Some(score) if score >= min_score => score,
+            _ => continue,
+        };
+
+        // Insert at the sorted position
+        let index = healthy
+            .iter()
+            .position(|(_, s)| s < score)
+            .map(|i| i as u32)
+            .unwrap_or(healthy.len());
+        healthy.insert(index, (quest_id, score));
+    }
+
+    let mut top = Vec::new(env);
+    for entry in healthy
+        .iter()
+        .take(n.min(MAX_SORTED_QUEST_RESULTS) as usize)
+    {
+        top.push_back(entry);
+    }
+    top
+}
+
+/// Get the IDs of quests filled to at least `min_fill_bps` basis points
+pub fn get_quests_above_fill_pct(env: &Env, min_fill_bps: u32) -> Vec<Symbol> {
+    let mut ids = Vec::new(env);
+    let first_bucket = min_fill_bps / FILL_BUCKET_BPS;
+
+    for bucket in first_bucket..=10_000 / FILL_BUCKET_BPS {
+        for quest_id in storage::get_fill_bucket_quests(env, bucket).iter() {
+            // Quests in the lowest bucket may sit just below the threshold
+            if bucket == first_bucket {
+                let above = storage::get_quest(env, &quest_id)
+                    .map(|q| fill_bps(&q) >= min_fill_bps)
+                    .unwrap_or(false);
+                if !above {
+                    continue;
+                }
+            }
+            ids.push_back(quest_id);
+        }
+    }
+
+    ids
+}
+
+/// Refresh the TTL of a quest to `ENTRY_TTL_LEDGERS` and of each submission to the TTL of its status.
+/// Callable by anyone willing to pay the rent.
+pub fn extend_quest_ttl(env: &Env, quest_id: &Symbol) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    storage::set_quest(env, &quest);
+
+    for submitter in storage::get_quest_submitters(env, quest_id).iter() {
+        if let Some(submission) = storage::get_submission(env, quest_id, &submitter) {
+            storage::set_submission(env, &submission);
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the completion certificate of a paid submission, issuing it on the first call.
+/// Certificates are immutable: later calls return the stored certificate unchanged.
+pub fn get_completion_certificate(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Result<CompletionCertificate, Error> {
+    if let Some(certificate) = storage::get_certificate(env, quest_id, submitter) {
+        return Ok(certificate);
+    }
+
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    let submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    // Only paid submissions are certified
+    if submission.status != SubmissionStatus::Paid {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+
+    let completed_at = env.ledger().timestamp();
+    let certificate = CompletionCertificate {
+        quest_id: quest_id.clone(),
+        submitter: submitter.clone(),
+        reward_amount: quest.reward_amount,
+        reward_asset: quest.reward_asset,
+        completed_at,
+        verifier: quest.verifier,
+        certificate_hash: certificate_hash(env, quest_id, submitter, completed_at),
+    };
+    storage::set_certificate(env, &certificate);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "certificate_issued"), quest_id.clone()),
+        (submitter.clone(), certificate.certificate_hash.clone()),
+    );
+
+    Ok(certificate)
+}
+
+/// Certificate hash: sha256(quest_id || submitter || completed_at),
+/// with the symbol and address XDR-encoded and the timestamp big-endian
+fn certificate_hash(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    completed_at: u64,
+) -> BytesN<32> {
+    let mut data = quest_id.clone().to_xdr(env);
+    data.append(&submitter.clone().to_xdr(env));
+    data.append(&Bytes::from_array(env, &completed_at.to_be_bytes()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Maximum number of entries returned by `get_quests_needing_verifier_action`
+pub const MAX_ACTION_QUEUE_RESULTS: u32 = 50;
+
+/// Get a verifier's pending submissions older than `stale_secs` as
+/// (quest_id, submitter, age_secs), oldest first, capped at `MAX_ACTION_QUEUE_RESULTS`
+pub fn get_quests_needing_verifier_action(
+    env: &Env,
+    verifier: &Address,
+    stale_secs: u64,
+) -> Vec<(Symbol, Address, u64)> {
+    let now = env.ledger().timestamp();
+    let mut stale: Vec<(Symbol, Address, u64)> = Vec::new(env);
+
+    for (quest_id, submitter) in storage::get_verifier_pending(env, verifier).iter() {
+        let age = match storage::get_submission(env, &quest_id, &submitter) {
+            Some(submission) => now.saturating_sub(submission.timestamp),
+            None => continue,
+        };
+        if age <= stale_secs {
+            continue;
+        }
+
+        // Insert at the sorted position (ties keep earlier entries first)
+        let index = stale
+            .iter()
+            .position(|(_, _, a)| a < age)
+            .map(|i| i as u32)
+            .unwrap_or(stale.len());
+        stale.insert(index, (quest_id, submitter, age));
+    }
+
+    stale.slice(0..stale.len().min(MAX_ACTION_QUEUE_RESULTS))
+}
+
+/// Reassign a quest to a new verifier (creator only)
+pub fn set_verifier(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    new_verifier: &Address,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    if quest.verifier == *new_verifier {
+        return Ok(());
+    }
+
+    reassign_verifier(env, &mut quest, new_verifier);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "verifier_set"), quest_id.clone()),
+        new_verifier.clone(),
+    );
+
+    Ok(())
+}
+
+/// Restrict submissions to the addresses under a Merkle root (creator only)
+pub fn set_whitelist_merkle_root(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    root: BytesN<32>,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
     }
 
-    // Create quest
-    let quest = Quest {
-        id: id.clone(),
-        creator,
-        reward_asset,
-        reward_amount,
-        verifier,
-        deadline,
-        status: QuestStatus::Active,
-        max_participants,
-        total_claims: 0,
-    };
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
 
-    // Store quest
-    storage::set_quest(env, &quest);
+    // Sealed whitelists cannot change
+    if quest.whitelist_sealed {
+        return Err(Error::WhitelistSealed);
+    }
+
+    storage::set_whitelist_root(env, quest_id, &root);
 
     // Emit event
     env.events()
-        .publish((Symbol::new(env, "quest_reg"), id), quest);
+        .publish((Symbol::new(env, "whitelist_set"), quest_id.clone()), root);
 
     Ok(())
 }
 
-/// Check if a quest has reached its participant limit
-pub fn is_quest_full(quest: &Quest) -> bool {
-    quest.total_claims >= quest.max_participants
+/// Hand a quest and its pending submissions over to a new verifier.
+/// Returns the number of pending submissions moved.
+pub fn reassign_verifier(env: &Env, quest: &mut Quest, new_verifier: &Address) -> u32 {
+    // Move the quest between verifier assignment indexes
+    storage::remove_verifier_quest(env, &quest.verifier, &quest.id);
+    storage::add_verifier_quest(env, new_verifier, &quest.id);
+
+    // Move the quest's pending queue entries and point their submissions at the new verifier
+    let mut old_queue = Vec::new(env);
+    let mut new_queue = storage::get_verifier_pending(env, new_verifier);
+    let mut moved = 0;
+    for (id, submitter) in storage::get_verifier_pending(env, &quest.verifier).iter() {
+        if id != quest.id {
+            old_queue.push_back((id, submitter));
+            continue;
+        }
+        if let Some(mut submission) = storage::get_submission(env, &id, &submitter) {
+            submission.verifier_address = new_verifier.clone();
+            storage::set_submission(env, &submission);
+        }
+        new_queue.push_back((id, submitter));
+        moved += 1;
+    }
+    storage::set_verifier_pending(env, &quest.verifier, &old_queue);
+    storage::set_verifier_pending(env, new_verifier, &new_queue);
+
+    quest.verifier = new_verifier.clone();
+    storage::set_quest(env, quest);
+
+    moved
 }
 
 /// Update quest status (admin only)
@@ -85,8 +1433,19 @@ pub fn update_quest_status(
         return Err(Error::Unauthorized);
     }
 
-    // Validate status transition - can't change from Completed or Expired
-    if quest.status == QuestStatus::Completed || quest.status == QuestStatus::Expired {
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    // Validate status transition - can't change from Completed, Expired or Cancelled
+    if quest.status == QuestStatus::Completed
+        || quest.status == QuestStatus::Expired
+        || quest.status == QuestStatus::Cancelled
+    {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    // Quests are only archived through `archive_quest`
+    if new_status == QuestStatus::Archived {
         return Err(Error::InvalidStatusTransition);
     }
 
@@ -117,6 +1476,8 @@ pub fn auto_complete_quest_if_full(env: &Env, quest: &mut Quest) {
 
 /// Validate that a quest is active and accepting submissions
 pub fn validate_quest_active(env: &Env, quest: &Quest) -> Result<(), Error> {
+    ensure_not_archived(quest)?;
+
     // Check if quest is active
     if quest.status != QuestStatus::Active {
         return Err(Error::QuestNotActive);
@@ -142,6 +1503,429 @@ pub fn check_expired(env: &Env, quest: &Quest) -> bool {
     current_time > quest.deadline
 }
 
+/// Set the approved claims required before the quest can be marked completed (creator only)
+pub fn set_min_completions(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    min_completions: u32,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    if min_completions > quest.max_participants {
+        return Err(Error::InvalidParticipantLimit);
+    }
+
+    quest.min_completions = min_completions;
+    storage::set_quest(env, &quest);
+    Ok(())
+}
+
+/// Link a verification template to a quest (creator only)
+pub fn attach_verification_template(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    template_id: u32,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    // Template must exist
+    if storage::get_template(env, template_id).is_none() {
+        return Err(Error::TemplateNotFound);
+    }
+
+    quest.verification_template_id = Some(template_id);
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "template_attached"), quest_id.clone()),
+        template_id,
+    );
+
+    Ok(())
+}
+
+/// Set how a quest's reward is paid out (creator only)
+pub fn set_reward_mode(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    mode: RewardMode,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    quest.reward_mode = mode;
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "reward_mode_set"), quest_id.clone()),
+        mode,
+    );
+
+    Ok(())
+}
+
+/// Permanently lock a quest's whitelist root (creator only, before any claims).
+///
+/// The `whitelist_sealed` event carries the sealed Merkle root rather than a whitelist size:
+/// the contract only stores the root, so it never learns how many addresses the tree commits
+/// to. Anyone holding the published leaf list can check its size against the sealed root.
+pub fn seal_whitelist(env: &Env, quest_id: &Symbol, creator: &Address) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    if quest.whitelist_sealed {
+        return Err(Error::WhitelistSealed);
+    }
+    if quest.total_claims > 0 {
+        return Err(Error::AlreadyApproved);
+    }
+
+    quest.whitelist_sealed = true;
+    storage::set_quest(env, &quest);
+
+    // Emit event with the sealed root; the size of a root-based whitelist is not known on-chain
+    env.events().publish(
+        (Symbol::new(env, "whitelist_sealed"), quest_id.clone()),
+        storage::get_whitelist_root(env, quest_id),
+    );
+
+    Ok(())
+}
+
+/// Check whether a quest's whitelist root is sealed
+pub fn is_whitelist_sealed(env: &Env, quest_id: &Symbol) -> bool {
+    storage::get_quest(env, quest_id)
+        .map(|quest| quest.whitelist_sealed)
+        .unwrap_or(false)
+}
+
+/// Pay rewards from escrow as soon as submissions are approved (creator only).
+/// Can only be changed before the quest's first approval.
+pub fn set_auto_payout(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    enabled: bool,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    // Payout mode is fixed once a submission has been approved
+    let counts = storage::submission_status_counts(env, quest_id);
+    if counts.approved + counts.auto_approved > 0 {
+        return Err(Error::AlreadyApproved);
+    }
+
+    quest.auto_payout = enabled;
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "auto_payout_set"), quest_id.clone()),
+        enabled,
+    );
+
+    Ok(())
+}
+
+/// Get the Bayesian average score of a quest's submissions, or `None` if none are scored
+pub fn get_average_submission_score(env: &Env, quest_id: &Symbol) -> Option<u32> {
+    let quest = storage::get_quest(env, quest_id)?;
+    let (_, count) = storage::get_quest_score_totals(env, quest_id);
+    if count == 0 {
+        return None;
+    }
+    Some(quest.avg_score)
+}
+
+/// Require approvals to be signed off by both the verifier and the creator (creator only)
+pub fn set_dual_approval_required(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    required: bool,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    quest.dual_approval_required = required;
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "dual_appr_set"), quest_id.clone()),
+        required,
+    );
+
+    Ok(())
+}
+
+/// Close an active or paused quest once every submission is settled (creator only).
+/// Requires at least `min_completions` claims, no pending submissions and no approved
+/// submissions still waiting to be paid.
+pub fn mark_completed(env: &Env, quest_id: &Symbol, creator: &Address) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    // Validate status transition
+    if quest.status != QuestStatus::Active && quest.status != QuestStatus::Paused {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    if quest.total_claims < quest.min_completions {
+        return Err(Error::MinCompletionsNotReached);
+    }
+
+    // Every submission must be rejected or paid
+    ensure_settled(env, quest_id)?;
+
+    quest.status = QuestStatus::Completed;
+    storage::set_quest(env, &quest);
+    storage::remove_reward_index_quest(env, &quest.reward_asset, quest.reward_amount, quest_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "quest_completed"), quest_id.clone()),
+        quest.total_claims,
+    );
+
+    Ok(())
+}
+
+/// Fail with `UnsettledSubmissions` while any submission of a quest is pending or approved but unpaid
+fn ensure_settled(env: &Env, quest_id: &Symbol) -> Result<(), Error> {
+    if storage::get_quest_pending(env, quest_id) > 0 {
+        return Err(Error::UnsettledSubmissions);
+    }
+    for submitter in storage::get_quest_submitters(env, quest_id).iter() {
+        if let Some(submission) = storage::get_submission(env, quest_id, &submitter) {
+            if submission.status.is_approved() {
+                return Err(Error::UnsettledSubmissions);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fail with `QuestArchived` if the quest has been archived
+pub fn ensure_not_archived(quest: &Quest) -> Result<(), Error> {
+    if quest.status == QuestStatus::Archived {
+        return Err(Error::QuestArchived);
+    }
+    Ok(())
+}
+
+/// Archive a completed, expired or cancelled quest once every submission is settled
+/// (creator only). The quest stays readable but rejects every further mutation.
+pub fn archive_quest(env: &Env, quest_id: &Symbol, creator: &Address) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    ensure_not_archived(&quest)?;
+
+    // Only closed quests can be archived
+    if quest.status != QuestStatus::Completed
+        && quest.status != QuestStatus::Expired
+        && quest.status != QuestStatus::Cancelled
+    {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    // Rewards of approved submissions could no longer be paid once archived
+    ensure_settled(env, quest_id)?;
+
+    quest.status = QuestStatus::Archived;
+    storage::set_quest(env, &quest);
+    storage::remove_reward_index_quest(env, &quest.reward_asset, quest.reward_amount, quest_id);
+
+    let mut platform = storage::get_platform_stats(env);
+    platform.archived_quests += 1;
+    storage::set_platform_stats(env, &platform);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "quest_archived"), quest_id.clone()), ());
+
+    Ok(())
+}
+
+/// Cancel an active or paused quest (creator only).
+/// Refunds the remaining escrow, and the creation fee when cancelled within
+/// `CREATION_FEE_REFUND_WINDOW`; a later cancellation releases the fee to the treasury.
+pub fn cancel_quest(env: &Env, quest_id: &Symbol, creator: &Address) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
+    if quest.status != QuestStatus::Active && quest.status != QuestStatus::Paused {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    // Return unclaimed escrow to the creator
+    let escrow = storage::get_quest_escrow(env, quest_id);
+    if escrow > 0 {
+        payout::release_from_escrow(env, &quest.reward_asset, creator, escrow);
+        storage::set_quest_escrow(env, quest_id, &quest.reward_asset, 0);
+    }
+
+    // Refund the held creation fee inside the refund window
+    let mut fee_refund = 0;
+    if let Some(payment) = storage::get_creation_fee_paid(env, quest_id) {
+        if env.ledger().timestamp() <= fee_refund_window_end(&payment) {
+            payout::release_from_escrow(env, &payment.asset, creator, payment.amount);
+            storage::remove_creation_fee_paid(env, quest_id);
+            fee_refund = payment.amount;
+        } else {
+            sweep_creation_fee(env, quest_id);
+        }
+    }
+
+    quest.status = QuestStatus::Cancelled;
+    storage::set_quest(env, &quest);
+    storage::remove_reward_index_quest(env, &quest.reward_asset, quest.reward_amount, quest_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "quest_cancelled"), quest_id.clone()),
+        (escrow, fee_refund),
+    );
+
+    Ok(())
+}
+
+/// Release a quest's held creation fee to the treasury once its refund window has
+/// passed (anyone). Returns the amount swept, 0 while the window is open or when no
+/// fee is held.
+pub fn sweep_creation_fee(env: &Env, quest_id: &Symbol) -> i128 {
+    let payment = match storage::get_creation_fee_paid(env, quest_id) {
+        Some(payment) => payment,
+        None => return 0,
+    };
+    if env.ledger().timestamp() <= fee_refund_window_end(&payment) {
+        return 0;
+    }
+
+    payout::release_from_escrow(env, &payment.asset, &payment.treasury, payment.amount);
+    storage::remove_creation_fee_paid(env, quest_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "creation_fee_swept"), quest_id.clone()),
+        (payment.treasury, payment.amount),
+    );
+
+    payment.amount
+}
+
+/// Last timestamp at which cancelling a quest refunds its creation fee
+fn fee_refund_window_end(payment: &CreationFeePayment) -> u64 {
+    payment
+        .paid_at
+        .saturating_add(admin::CREATION_FEE_REFUND_WINDOW)
+}
+
 /// Manually expire a quest (admin/creator only)
 pub fn expire_quest(env: &Env, quest_id: &Symbol, caller: &Address) -> Result<(), Error> {
     // Verify caller authorization
@@ -155,12 +1939,15 @@ pub fn expire_quest(env: &Env, quest_id: &Symbol, caller: &Address) -> Result<()
         return Err(Error::Unauthorized);
     }
 
+    // Archived quests are read-only
+    ensure_not_archived(&quest)?;
+
     // Check if quest is already expired or completed
     if quest.status == QuestStatus::Expired {
         return Err(Error::InvalidStatusTransition);
     }
 
-    if quest.status == QuestStatus::Completed {
+    if quest.status == QuestStatus::Completed || quest.status == QuestStatus::Cancelled {
         return Err(Error::InvalidStatusTransition);
     }
 
@@ -0,0 +1,117 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::reputation;
+use crate::storage;
+use crate::types::{QuestStream, SubmissionStatus};
+use crate::BASE_QUEST_XP;
+
+/// Create a stream of quests owned by the creator. Returns the new stream ID.
+/// A quest belongs to at most one stream, and the bonus is capped at
+/// `BASE_QUEST_XP` per quest so streams cannot mint XP beyond the quests themselves.
+pub fn create_stream(
+    env: &Env,
+    creator: &Address,
+    quest_ids: Vec<Symbol>,
+    name: Symbol,
+    bonus_xp: u32,
+) -> Result<u32, Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    if quest_ids.is_empty() || bonus_xp > BASE_QUEST_XP.saturating_mul(quest_ids.len()) {
+        return Err(Error::InvalidConfig);
+    }
+
+    // Every quest must exist, belong to the creator and not be part of another stream
+    for quest_id in quest_ids.iter() {
+        let quest = storage::get_quest(env, &quest_id).ok_or(Error::QuestNotFound)?;
+        if quest.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if storage::get_quest_stream_id(env, &quest_id).is_some() {
+            return Err(Error::InvalidConfig);
+        }
+    }
+
+    let id = storage::get_stream_count(env) + 1;
+    let stream = QuestStream {
+        id,
+        quest_ids,
+        name,
+        bonus_xp,
+    };
+
+    for quest_id in stream.quest_ids.iter() {
+        storage::set_quest_stream_id(env, &quest_id, id);
+    }
+    storage::set_stream(env, &stream);
+    storage::set_stream_count(env, id);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "stream_created"), id), creator.clone());
+
+    Ok(id)
+}
+
+/// Get a quest stream
+pub fn get_stream(env: &Env, stream_id: u32) -> Result<QuestStream, Error> {
+    storage::get_stream(env, stream_id).ok_or(Error::StreamNotFound)
+}
+
+/// Get the bonus XP a user would earn from the stream of `quest_id` once it is approved
+pub fn pending_bonus_xp(env: &Env, quest_id: &Symbol, user: &Address) -> u32 {
+    let Some(stream_id) = storage::get_quest_stream_id(env, quest_id) else {
+        return 0;
+    };
+    let Some(stream) = storage::get_stream(env, stream_id) else {
+        return 0;
+    };
+    if storage::is_stream_completed(env, stream_id, user) {
+        return 0;
+    }
+
+    // Every other quest in the stream must already be completed
+    let others_completed = stream.quest_ids.iter().all(|id| {
+        id == *quest_id
+            || storage::get_submission(env, &id, user)
+                .map(|s| s.status.is_approved() || s.status == SubmissionStatus::Paid)
+                .unwrap_or(false)
+    });
+    if others_completed {
+        stream.bonus_xp
+    } else {
+        0
+    }
+}
+
+/// Check whether a user completed every quest in a stream, awarding the bonus XP once
+pub fn check_completion(env: &Env, stream_id: u32, user: &Address) -> Result<bool, Error> {
+    let stream = get_stream(env, stream_id)?;
+
+    // Bonus already awarded
+    if storage::is_stream_completed(env, stream_id, user) {
+        return Ok(true);
+    }
+
+    for quest_id in stream.quest_ids.iter() {
+        let completed = storage::get_submission(env, &quest_id, user)
+            .map(|s| s.status.is_approved() || s.status == SubmissionStatus::Paid)
+            .unwrap_or(false);
+        if !completed {
+            return Ok(false);
+        }
+    }
+
+    storage::set_stream_completed(env, stream_id, user);
+    reputation::add_xp(env, user, stream.bonus_xp);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "stream_completed"), stream_id),
+        (user.clone(), stream.bonus_xp),
+    );
+
+    Ok(true)
+}
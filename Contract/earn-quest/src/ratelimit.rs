@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env};
+
+use crate::analytics;
+use crate::errors::Error;
+use crate::storage;
+
+/// Default maximum number of submissions per user per day across all quests
+pub const MAX_DAILY_SUBMISSIONS: u32 = 50;
+
+/// Get the active daily submission limit
+pub fn get_max_daily_submissions(env: &Env) -> u32 {
+    storage::get_max_daily_submissions(env).unwrap_or(MAX_DAILY_SUBMISSIONS)
+}
+
+/// Check that the user is below today's submission limit and count the new submission
+pub fn check_and_increment_daily_submissions(env: &Env, user: &Address) -> Result<(), Error> {
+    let day = analytics::current_day(env);
+    let count = storage::get_daily_submission_count(env, user, day);
+
+    if count >= get_max_daily_submissions(env) {
+        return Err(Error::DailyLimitExceeded);
+    }
+
+    storage::set_daily_submission_count(env, user, day, count + 1);
+    Ok(())
+}
+
+/// Get the number of submissions a user has made today
+pub fn get_daily_count(env: &Env, user: &Address) -> u32 {
+    storage::get_daily_submission_count(env, user, analytics::current_day(env))
+}
@@ -0,0 +1,184 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::payout;
+use crate::reputation;
+use crate::storage;
+use crate::types::Quest;
+
+/// Referral bonus paid to the referrer, in basis points of the reward
+pub const REFERRAL_BONUS_BPS: i128 = 500;
+
+/// Maximum number of referrers tracked on the referral leaderboard
+pub const REFERRAL_LEADERBOARD_SIZE: u32 = 50;
+
+/// Referral counts that trigger a milestone event
+pub const REFERRAL_MILESTONES: [u32; 3] = [10, 50, 100];
+
+/// Default XP granted to a referrer on reaching each referral count, as (referral_count, xp)
+pub const DEFAULT_REFERRAL_MILESTONE_XP: [(u32, u32); 5] =
+    [(1, 10), (5, 50), (10, 100), (25, 250), (50, 500)];
+
+/// Register the address that referred a user (one-time)
+pub fn register_referrer(env: &Env, user: &Address, referrer: &Address) -> Result<(), Error> {
+    // Verify user authorization
+    user.require_auth();
+
+    if user == referrer {
+        return Err(Error::InvalidReferrer);
+    }
+
+    if storage::get_referrer(env, user).is_some() {
+        return Err(Error::ReferrerAlreadySet);
+    }
+
+    storage::set_referrer(env, user, referrer);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "referrer_set"), user.clone()),
+        referrer.clone(),
+    );
+
+    Ok(())
+}
+
+/// Get the address that referred a user, if any
+pub fn get_referrer(env: &Env, user: &Address) -> Option<Address> {
+    storage::get_referrer(env, user)
+}
+
+/// Add tokens to the pool referral bonuses in an asset are paid from (anyone)
+pub fn fund_referral_pool(
+    env: &Env,
+    funder: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    // Verify funder authorization
+    funder.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    payout::deposit_to_escrow(env, asset, funder, amount);
+    let pool = storage::get_referral_pool(env, asset) + amount;
+    storage::set_referral_pool(env, asset, pool);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "referral_pool_funded"), asset.clone()),
+        (funder.clone(), amount),
+    );
+
+    Ok(())
+}
+
+/// Get the referral bonus pool balance of an asset
+pub fn get_referral_pool(env: &Env, asset: &Address) -> i128 {
+    storage::get_referral_pool(env, asset)
+}
+
+/// Count a paid submission towards its submitter's referrer and pay the referral bonus.
+/// The bonus comes out of the referral pool of the reward asset, never the quest escrow,
+/// and is skipped while the pool cannot cover it. Returns the referrer, if any.
+pub fn pay_referral_bonus(env: &Env, quest: &Quest, submitter: &Address) -> Option<Address> {
+    let referrer = storage::get_referrer(env, submitter)?;
+
+    let count = storage::get_referral_count(env, &referrer) + 1;
+    storage::set_referral_count(env, &referrer, count);
+
+    let bonus = quest.reward_amount * REFERRAL_BONUS_BPS / 10_000;
+    let pool = storage::get_referral_pool(env, &quest.reward_asset);
+    if bonus > 0 && pool >= bonus {
+        payout::release_from_escrow(env, &quest.reward_asset, &referrer, bonus);
+        storage::set_referral_pool(env, &quest.reward_asset, pool - bonus);
+
+        let earnings = storage::get_referral_earnings(env, &referrer) + bonus;
+        storage::set_referral_earnings(env, &referrer, earnings);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "referral_paid"), referrer.clone()),
+            (submitter.clone(), bonus),
+        );
+    }
+
+    // Emit event
+    if REFERRAL_MILESTONES.contains(&count) {
+        env.events().publish(
+            (Symbol::new(env, "referral_milestone"), referrer.clone()),
+            count,
+        );
+    }
+
+    Some(referrer)
+}
+
+/// Update a referrer's position on the referral leaderboard, sorted by earnings
+pub fn update_referral_leaderboard(env: &Env, referrer: &Address) {
+    let mut board = storage::get_referral_leaderboard(env);
+    let earnings = storage::get_referral_earnings(env, referrer);
+    let count = storage::get_referral_count(env, referrer);
+
+    // Remove the referrer's previous entry
+    if let Some(index) = board.iter().position(|(addr, _, _)| addr == *referrer) {
+        board.remove(index as u32);
+    }
+
+    // Insert at the sorted position (ties keep earlier entries first)
+    if earnings > 0 {
+        let index = board
+            .iter()
+            .position(|(_, total, _)| total < earnings)
+            .map(|i| i as u32)
+            .unwrap_or(board.len());
+        if index < REFERRAL_LEADERBOARD_SIZE {
+            board.insert(index, (referrer.clone(), earnings, count));
+        }
+    }
+
+    while board.len() > REFERRAL_LEADERBOARD_SIZE {
+        board.pop_back();
+    }
+
+    storage::set_referral_leaderboard(env, &board);
+}
+
+/// Get the top `n` referrers as (address, total_earnings, referral_count)
+pub fn get_referral_leaderboard(env: &Env, n: u32) -> Vec<(Address, i128, u32)> {
+    let board = storage::get_referral_leaderboard(env);
+    board.slice(0..n.min(board.len()))
+}
+
+/// Get the number of referred submissions paid out for a referrer
+pub fn get_referral_count(env: &Env, referrer: &Address) -> u32 {
+    storage::get_referral_count(env, referrer)
+}
+
+/// Get the XP granted at each referral count milestone, as (referral_count, xp)
+pub fn get_referral_milestone_xp(env: &Env) -> Vec<(u32, u32)> {
+    storage::get_referral_milestone_xp(env).unwrap_or_else(|| {
+        let mut milestones = Vec::new(env);
+        for milestone in DEFAULT_REFERRAL_MILESTONE_XP.iter() {
+            milestones.push_back(*milestone);
+        }
+        milestones
+    })
+}
+
+/// Award milestone XP to a referrer whose paid referral count just reached `referral_count`.
+/// Returns the XP awarded, 0 when the count is not a milestone.
+pub fn award_xp_for_referral_milestone(env: &Env, referrer: &Address, referral_count: u32) -> u32 {
+    let xp = get_referral_milestone_xp(env)
+        .iter()
+        .find(|(count, _)| *count == referral_count)
+        .map(|(_, xp)| xp)
+        .unwrap_or(0);
+
+    if xp > 0 {
+        reputation::add_xp(env, referrer, xp);
+    }
+    xp
+}
@@ -1,22 +1,34 @@
 use soroban_sdk::{Address, Env, Symbol, Vec};
 
+use crate::analytics;
 use crate::errors::Error;
+use crate::leaderboard;
 use crate::storage;
-use crate::types::UserStats;
+use crate::types::{QuestDifficulty, UserStats};
 
-/// Award XP to a user and update their stats
-pub fn award_xp(env: &Env, address: &Address, xp: u32) -> Result<(), Error> {
-    // Get or create user stats
-    let mut stats = storage::get_user_stats(env, address).unwrap_or_else(|| UserStats {
+/// Get a user's stats, or fresh level-1 stats if they have none yet
+pub fn get_or_init_stats(env: &Env, address: &Address) -> UserStats {
+    storage::get_user_stats(env, address).unwrap_or_else(|| UserStats {
         address: address.clone(),
         total_xp: 0,
         level: 1,
         quests_completed: 0,
         badges: Vec::new(env),
-    });
+    })
+}
+
+/// Get the number of users with stats
+pub fn get_total_user_count(env: &Env) -> u32 {
+    storage::get_user_count(env)
+}
+
+/// Award XP to a user and update their stats
+pub fn award_xp(env: &Env, address: &Address, xp: u32) -> Result<(), Error> {
+    // Get or create user stats
+    let mut stats = get_or_init_stats(env, address);
 
     // Add XP
-    stats.total_xp += xp;
+    stats.total_xp = stats.total_xp.saturating_add(xp);
 
     // Update level based on XP
     stats.level = calculate_level(stats.total_xp);
@@ -26,6 +38,8 @@ pub fn award_xp(env: &Env, address: &Address, xp: u32) -> Result<(), Error> {
 
     // Store updated stats
     storage::set_user_stats(env, &stats);
+    leaderboard::update_leaderboard(env, address, stats.total_xp);
+    leaderboard::record_monthly_xp(env, address, xp);
 
     // Emit event
     env.events()
@@ -34,38 +48,133 @@ pub fn award_xp(env: &Env, address: &Address, xp: u32) -> Result<(), Error> {
     Ok(())
 }
 
-/// Calculate user level based on total XP
-/// Level formula: level = floor(sqrt(total_xp / 100)) + 1
-fn calculate_level(total_xp: u32) -> u32 {
-    // Simple level calculation: 100 XP per level
-    // Level 1: 0-99 XP
-    // Level 2: 100-199 XP
-    // Level 3: 200-299 XP, etc.
-    (total_xp / 100) + 1
+/// Add bonus XP to a user without counting a completed quest
+pub fn add_xp(env: &Env, address: &Address, xp: u32) {
+    let mut stats = get_or_init_stats(env, address);
+
+    stats.total_xp = stats.total_xp.saturating_add(xp);
+    stats.level = calculate_level(stats.total_xp);
+    storage::set_user_stats(env, &stats);
+    leaderboard::update_leaderboard(env, address, stats.total_xp);
+    leaderboard::record_monthly_xp(env, address, xp);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "xp_award"), address.clone()), xp);
+}
+
+/// Record XP earned from a quest of the given difficulty
+pub fn record_difficulty_xp(env: &Env, address: &Address, difficulty: QuestDifficulty, xp: u32) {
+    let total = storage::get_difficulty_xp(env, address, difficulty).saturating_add(xp);
+    storage::set_difficulty_xp(env, address, difficulty, total);
+}
+
+/// Get a breakdown of a user's XP by quest difficulty
+pub fn get_xp_by_difficulty(env: &Env, address: &Address) -> Vec<(QuestDifficulty, u32)> {
+    let mut breakdown = Vec::new(env);
+    for difficulty in [
+        QuestDifficulty::Beginner,
+        QuestDifficulty::Intermediate,
+        QuestDifficulty::Advanced,
+        QuestDifficulty::Expert,
+    ] {
+        let xp = storage::get_difficulty_xp(env, address, difficulty);
+        breakdown.push_back((difficulty, xp));
+    }
+    breakdown
+}
+
+/// Get the difficulty a user has earned the most XP from, if any (ties favour the easier one)
+pub fn compute_favorite_difficulty(env: &Env, address: &Address) -> Option<QuestDifficulty> {
+    let mut favorite = None;
+    let mut most_xp = 0;
+    for (difficulty, xp) in get_xp_by_difficulty(env, address).iter() {
+        if xp > most_xp {
+            favorite = Some(difficulty);
+            most_xp = xp;
+        }
+    }
+    favorite
+}
+
+/// Deduct XP from a user as a penalty, flooring at zero. Returns the XP actually removed.
+pub fn apply_xp_penalty(env: &Env, address: &Address, penalty: u32) -> u32 {
+    let mut stats = get_or_init_stats(env, address);
+
+    let removed = penalty.min(stats.total_xp);
+    stats.total_xp -= removed;
+    stats.level = calculate_level(stats.total_xp);
+    storage::set_user_stats(env, &stats);
+    leaderboard::update_leaderboard(env, address, stats.total_xp);
+    leaderboard::remove_monthly_xp(env, address, removed);
+
+    removed
 }
 
-/// Grant a badge to a user (admin only)
-pub fn grant_badge(
-    env: &Env,
-    address: &Address,
-    badge: Symbol,
-    admin: &Address,
-) -> Result<(), Error> {
-    // Verify admin authorization
-    admin.require_auth();
-
-    // Get user stats
-    let mut stats = storage::get_user_stats(env, address).ok_or(Error::UserStatsNotFound)?;
-
-    // Add badge if not already present
-    if !stats.badges.contains(&badge) {
-        stats.badges.push_back(badge.clone());
+/// Maximum number of decay intervals applied in one call; the rest apply on later calls
+pub const MAX_DECAY_INTERVALS: u64 = 365;
+
+/// Apply the XP decay accrued since the user's last activity, flooring at zero.
+/// Each full `decay_interval_days` removes `decay_rate_bps` of the remaining XP.
+/// Returns the XP removed (0 if decay is disabled or the user has no activity).
+pub fn apply_xp_decay(env: &Env, user: &Address) -> Result<u32, Error> {
+    let (rate_bps, interval_days) = storage::get_xp_decay_config(env);
+    if rate_bps == 0 || interval_days == 0 {
+        return Ok(0);
+    }
+    let anchor = match storage::get_xp_decay_anchor(env, user) {
+        Some(day) => day,
+        None => return Ok(0),
+    };
+    let mut stats = match storage::get_user_stats(env, user) {
+        Some(stats) => stats,
+        None => return Ok(0),
+    };
+
+    let interval_days = interval_days as u64;
+    let today = analytics::current_day(env);
+    let intervals = (today.saturating_sub(anchor) / interval_days).min(MAX_DECAY_INTERVALS);
+    if intervals == 0 {
+        return Ok(0);
+    }
+
+    let mut xp = stats.total_xp;
+    for _ in 0..intervals {
+        if xp == 0 {
+            break;
+        }
+        xp -= (xp as u64 * rate_bps as u64 / 10_000) as u32;
+    }
+    let decayed = stats.total_xp - xp;
+    storage::set_xp_decay_anchor(env, user, anchor + intervals * interval_days);
+
+    if decayed > 0 {
+        stats.total_xp = xp;
+        stats.level = calculate_level(stats.total_xp);
         storage::set_user_stats(env, &stats);
+        leaderboard::update_leaderboard(env, user, stats.total_xp);
 
         // Emit event
         env.events()
-            .publish((Symbol::new(env, "badge_grant"), address.clone()), badge);
+            .publish((Symbol::new(env, "xp_decayed"), user.clone()), decayed);
     }
 
+    Ok(decayed)
+}
+
+/// Settle a user's accrued XP decay and restart their decay clock from today
+pub fn record_activity(env: &Env, user: &Address) -> Result<(), Error> {
+    apply_xp_decay(env, user)?;
+    storage::set_xp_decay_anchor(env, user, analytics::current_day(env));
     Ok(())
 }
+
+/// Calculate user level based on total XP
+/// Level formula: level = floor(sqrt(total_xp / 100)) + 1
+pub fn calculate_level(total_xp: u32) -> u32 {
+    // Simple level calculation: 100 XP per level
+    // Level 1: 0-99 XP
+    // Level 2: 100-199 XP
+    // Level 3: 200-299 XP, etc.
+    (total_xp / 100) + 1
+}
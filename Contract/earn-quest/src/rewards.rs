@@ -0,0 +1,153 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::badges;
+use crate::errors::Error;
+use crate::payout;
+use crate::quest;
+use crate::referral;
+use crate::storage;
+use crate::types::{Quest, RewardMode, Submission, SubmissionStatus};
+
+/// Maximum number of quests settled in a single bulk claim
+pub const MAX_BATCH_SIZE: u32 = 20;
+
+/// Reward owed for a submission. Score-based quests pay `reward_amount * score / 100`
+/// to scored submissions; unscored submissions receive the full reward.
+pub fn submission_reward(quest: &Quest, submission: &Submission) -> i128 {
+    match (quest.reward_mode, submission.score) {
+        (RewardMode::ScoreBased, Some(score)) => quest.reward_amount * score as i128 / 100,
+        _ => quest.reward_amount,
+    }
+}
+
+/// Pay the escrowed reward for an approved submission and mark it as paid.
+/// Returns the amount transferred.
+pub fn pay_submission(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<i128, Error> {
+    let reward = settle_submission(env, quest_id, submitter)?;
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "reward_paid"), quest_id.clone()),
+        (submitter.clone(), reward),
+    );
+
+    Ok(reward)
+}
+
+/// Check whether a quest pays rewards out as soon as submissions are approved
+pub fn auto_payout_on_approval(env: &Env, quest_id: &Symbol) -> bool {
+    storage::get_quest(env, quest_id)
+        .map(|quest| quest.auto_payout)
+        .unwrap_or(false)
+}
+
+/// Transfer the escrowed reward of an approved submission and mark it as paid,
+/// without emitting the payout event
+pub(crate) fn settle_submission(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    quest::ensure_not_archived(&quest)?;
+    let mut submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    // Only approved submissions can be paid
+    if !submission.status.is_approved() {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+
+    // Payouts to frozen accounts are held until the freeze is lifted
+    if storage::is_frozen(env, submitter) {
+        return Err(Error::UserFrozen);
+    }
+
+    // Reward must be covered by the quest's escrow
+    let reward = submission_reward(&quest, &submission);
+    let escrow = storage::get_quest_escrow(env, quest_id);
+    if escrow < reward {
+        return Err(Error::InsufficientEscrow);
+    }
+
+    payout::release_from_escrow(env, &quest.reward_asset, submitter, reward);
+    storage::set_quest_escrow(env, quest_id, &quest.reward_asset, escrow - reward);
+
+    submission.status = SubmissionStatus::Paid;
+    storage::set_submission(env, &submission);
+    badges::award_sponsor_badge(env, submitter, &quest);
+
+    // Credit the referrer and pay their bonus from the referral pool
+    if let Some(referrer) = referral::pay_referral_bonus(env, &quest, submitter) {
+        referral::update_referral_leaderboard(env, &referrer);
+        let count = storage::get_referral_count(env, &referrer);
+        referral::award_xp_for_referral_milestone(env, &referrer, count);
+    }
+
+    Ok(reward)
+}
+
+/// Pay out every approved submission of a user, up to `MAX_BATCH_SIZE` quests per call.
+/// Quests that are archived or whose escrow cannot cover the reward are skipped, so they
+/// don't hold up the rest. Returns the total amount transferred.
+pub fn claim_all_paid(env: &Env, user: &Address) -> Result<i128, Error> {
+    // Verify user authorization
+    user.require_auth();
+
+    let mut total: i128 = 0;
+    let mut count: u32 = 0;
+
+    for quest_id in storage::get_user_submissions(env, user).iter() {
+        if count >= MAX_BATCH_SIZE {
+            break;
+        }
+
+        let Some(submission) = storage::get_submission(env, &quest_id, user) else {
+            continue;
+        };
+        if !submission.status.is_approved() {
+            continue;
+        }
+
+        // Skip quests that cannot pay right now
+        let Some(quest) = storage::get_quest(env, &quest_id) else {
+            continue;
+        };
+        let payable = quest::ensure_not_archived(&quest).is_ok()
+            && storage::get_quest_escrow(env, &quest_id) >= submission_reward(&quest, &submission);
+        if !payable {
+            continue;
+        }
+
+        total += pay_submission(env, &quest_id, user)?;
+        count += 1;
+    }
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "bulk_claim"), user.clone()),
+        (count, total),
+    );
+
+    Ok(total)
+}
+
+/// Sum of rewards for a user's approved but unpaid submissions
+pub fn get_claimable_balance(env: &Env, user: &Address) -> i128 {
+    let mut total: i128 = 0;
+
+    for quest_id in storage::get_user_submissions(env, user).iter() {
+        let Some(submission) = storage::get_submission(env, &quest_id, user) else {
+            continue;
+        };
+        if !submission.status.is_approved() {
+            continue;
+        }
+
+        if let Some(quest) = storage::get_quest(env, &quest_id) {
+            total += submission_reward(&quest, &submission);
+        }
+    }
+
+    total
+}
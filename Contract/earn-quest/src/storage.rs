@@ -1,5 +1,14 @@
-use crate::types::{Quest, Submission, UserStats};
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use crate::errors::Error;
+use crate::quest;
+use crate::types::{
+    CompletionCertificate, CreationFeePayment, DailyQuestSeries, PlatformBadge, PlatformStats,
+    Quest, QuestDifficulty, QuestStream, QuestV1, Submission, SubmissionBackup, SubmissionStatus,
+    SubmissionStatusCounts, UserStats, VerificationTemplate, VerifierStats,
+};
+use soroban_sdk::{
+    contracttype, panic_with_error, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal,
+    Val, Vec,
+};
 
 /// Storage keys for the contract
 #[contracttype]
@@ -11,30 +20,417 @@ pub enum StorageKey {
     Submission(Symbol, Address),
     /// User stats storage key
     UserStats(Address),
+    /// Daily submission counter (quest_id, day_epoch)
+    DailySubmissions(Symbol, u64),
+    /// Day with the most submissions for a quest (quest_id)
+    PeakSubmissionDay(Symbol),
+    /// Registry of all quest IDs
+    QuestIds,
+    /// Submitters of a quest in arrival order (quest_id)
+    QuestSubmitters(Symbol),
+    /// Reward tokens held in escrow for a quest (quest_id)
+    QuestEscrow(Symbol),
+    /// Quest IDs of a given difficulty
+    DifficultyQuests(QuestDifficulty),
+    /// XP a user earned from quests of a given difficulty (user, difficulty)
+    DifficultyXp(Address, QuestDifficulty),
+    /// Contract admin address
+    Admin,
+    /// Maximum submissions per user per day across all quests
+    MaxDailySubmissions,
+    /// Submissions made by a user on a given day (user, day_epoch)
+    DailySubmissionCount(Address, u64),
+    /// Verifier review statistics
+    VerifierStats(Address),
+    /// Quest IDs a user has submitted to
+    UserSubmissions(Address),
+    /// Fraud registry entry for a user
+    Fraudulent(Address),
+    /// Number of quest streams created
+    StreamCount,
+    /// Quest stream storage key (stream_id)
+    Stream(u32),
+    /// Completion flag for a user in a stream (stream_id, user)
+    StreamCompleted(u32, Address),
+    /// Platform-wide activity counters
+    PlatformStats,
+    /// Top users sorted by XP descending
+    Leaderboard,
+    /// Timestamp of the last platform snapshot export
+    LastSnapshotTime,
+    /// Number of pending submissions across all quests
+    GlobalPendingCount,
+    /// Number of pending submissions for a quest (quest_id)
+    QuestPendingCount(Symbol),
+    /// Ledger sequence at which the contract was initialized
+    DeploymentLedger,
+    /// Ledger sequence from which quests may be created
+    GoLiveLedger,
+    /// Instance-storage backup of a submission (quest_id, submitter)
+    SubmissionBackup(Symbol, Address),
+    /// Address that referred a user
+    Referrer(Address),
+    /// Total referral bonuses earned by a referrer
+    ReferralEarnings(Address),
+    /// Number of paid referrals for a referrer
+    ReferralCount(Address),
+    /// Top referrers sorted by earnings descending
+    ReferralLeaderboard,
+    /// Review outcome counts for a quest (quest_id)
+    SubmissionStatusCounts(Symbol),
+    /// Number of persistent entries written by the contract
+    StorageEntryCount,
+    /// Timestamp of the last storage compaction (pruning of the submission ledger index)
+    LastCompactionTime,
+    /// Submissions created at a ledger sequence (sequence)
+    SubmissionLedgerIndex(u32),
+    /// Ledger sequences present in the submission ledger index, oldest first
+    IndexedLedgers,
+    /// Quest IDs a verifier is assigned to
+    VerifierQuests(Address),
+    /// Quest IDs in a fill-level bucket (bucket index)
+    FillBucket(u32),
+    /// Pending submissions awaiting a verifier, oldest first (verifier)
+    VerifierPendingQueue(Address),
+    /// Quest creation fee and its asset
+    CreationFee,
+    /// Address receiving platform fees
+    Treasury,
+    /// Creation fee paid for a quest (quest_id)
+    CreationFeePaid(Symbol),
+    /// XP a user earned in a season (season_id, user)
+    MonthlyXp(u32, Address),
+    /// Top users by XP earned in the current season
+    MonthlyLeaderboard,
+    /// Top users of past seasons as (ended_at, top_users)
+    MonthlyArchive,
+    /// URI schemes accepted for proofs
+    AllowedUriSchemes,
+    /// Proof URI of a submission (quest_id, submitter)
+    ProofUri(Symbol, Address),
+    /// Submissions made by a user on a given day, kept for activity history (user, day_epoch)
+    UserDailyActivity(Address, u64),
+    /// Merkle root of the addresses allowed to submit to a quest (quest_id)
+    WhitelistRoot(Symbol),
 }
 
+/// Storage keys added after `StorageKey` reached the 50-case `contracttype` limit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuxStorageKey {
+    /// Ed25519 public key a user signs submission permits with
+    PermitKey(Address),
+    /// Marker for a permit message hash that has been used
+    UsedPermit(BytesN<32>),
+    /// Contract metadata entries such as version and feature flags
+    ContractMetadata,
+    /// Rejected submissions of a user to a quest, oldest first (quest_id, submitter)
+    RejectedSubmission(Symbol, Address),
+    /// Quest IDs of a chain in order, starting with the original (root quest_id)
+    QuestChain(Symbol),
+    /// Original quest of the chain a duplicated quest belongs to (quest_id)
+    ChainRoot(Symbol),
+    /// XP granted at referral count milestones as (referral_count, xp)
+    ReferralMilestoneXp,
+    /// Open quests paying in an asset as (reward_amount, quest_id), ascending by reward (asset)
+    RewardIndex(Address),
+    /// First submitter of each proof hash on a quest (quest_id)
+    QuestProofHashes(Symbol),
+    /// Completion certificate issued for a paid submission (quest_id, submitter)
+    Certificate(Symbol, Address),
+    /// Number of users with stats
+    UserCount,
+    /// XP decay settings as (decay_rate_bps, decay_interval_days)
+    XpDecayConfig,
+    /// Day from which a user's XP decay is counted: their last activity,
+    /// advanced as decay is applied (user)
+    XpDecayAnchor(Address),
+    /// Quests sponsored by an address (sponsor)
+    SponsoredQuests(Address),
+    /// Number of approvals recorded for a quest on a given day (quest_id, day_epoch)
+    DailyApprovals(Symbol, u64),
+    /// Users whose payouts and submissions are frozen pending an investigation
+    FrozenUsers,
+    /// Sum and number of the scores given to a quest's submissions (quest_id)
+    QuestScoreTotals(Symbol),
+    /// Maximum number of quests a creator may create
+    MaxQuestsPerCreator,
+    /// Creator-specific quest creation limit replacing the global one (creator)
+    CreatorLimitOverride(Address),
+    /// Number of quests created by a creator (creator)
+    CreatorQuestCount(Address),
+    /// Quest IDs created by a creator, in creation order (creator)
+    CreatorQuests(Address),
+    /// Leaderboard rank of a user after their last XP change on a day, `None` if unranked
+    /// (user, day_epoch)
+    UserRankSnapshot(Address, u64),
+    /// Number of verification templates created
+    TemplateCount,
+    /// Verification template (template_id)
+    VerificationTemplate(u32),
+    /// Templates published by a verifier (verifier)
+    VerifierTemplates(Address),
+    /// Curated limited-supply badges by symbol
+    PlatformBadges,
+    /// Proposed admin and the timestamp from which they can accept the role
+    PendingAdminRotation,
+    /// Runtime feature toggles by flag symbol
+    FeatureFlags,
+    /// Sum of review times in seconds and number of reviews for a quest
+    QuestReviewTimes(Symbol),
+    /// Cached health score of a quest
+    QuestHealthScore(Symbol),
+    /// Minimum (reviews, on-time review rate) a verifier needs to be assigned a new quest
+    VerifierQualityThresholds,
+    /// Verifier exempt from the quality thresholds (verifier)
+    VerifierExempt(Address),
+    /// Sum of review times in seconds and number of reviews by a verifier
+    VerifierReviewTimes(Address),
+    /// Number of daily quest series created
+    DailySeriesCount,
+    /// Daily quest series (series_id)
+    DailySeries(u32),
+    /// Verifiers available for quest assignment
+    RegisteredVerifiers,
+    /// Stream a quest belongs to (quest_id)
+    QuestStreamId(Symbol),
+    /// Tokens set aside for referral bonuses (asset)
+    ReferralPool(Address),
+    /// Number of the current leaderboard season
+    SeasonId,
+    /// Sum of all quest escrows in an asset (asset)
+    EscrowTotal(Address),
+    /// Creation fees held during their refund window (asset)
+    HeldCreationFees(Address),
+}
+
+/// Approximate number of ledgers closed per day (5 second ledgers)
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// TTL given to quest and settled submission entries on every write (about 30 days)
+pub const ENTRY_TTL_LEDGERS: u32 = 30 * DAY_IN_LEDGERS;
+
+/// TTL given to pending submissions, which expire if never reviewed (about 14 days)
+pub const PENDING_TTL_LEDGERS: u32 = 14 * DAY_IN_LEDGERS;
+
+/// TTL given to approved and paid submissions (about 180 days, the network maximum)
+pub const PERMANENT_TTL_LEDGERS: u32 = 180 * DAY_IN_LEDGERS;
+
+/// Entries with fewer ledgers than this remaining are reported as expiring soon (about 7 days)
+pub const TTL_WARNING_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+
+/// Write a persistent entry, counting it towards the storage entry total if it is new
+fn persistent_set<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    let persistent = env.storage().persistent();
+    if !persistent.has(key) {
+        let count = get_storage_entry_count(env) + 1;
+        env.storage()
+            .instance()
+            .set(&StorageKey::StorageEntryCount, &count);
+    }
+    persistent.set(key, value);
+}
+
+/// Remove a persistent entry, discounting it from the storage entry total
+fn persistent_remove<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    let persistent = env.storage().persistent();
+    if persistent.has(key) {
+        persistent.remove(key);
+        let count = get_storage_entry_count(env).saturating_sub(1);
+        env.storage()
+            .instance()
+            .set(&StorageKey::StorageEntryCount, &count);
+    }
+}
+
+/// Get the number of persistent entries written by the contract
+pub fn get_storage_entry_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::StorageEntryCount)
+        .unwrap_or(0)
+}
+
+/// Get the timestamp at which the submission ledger index last dropped its oldest
+/// ledger (0 if never compacted)
+pub fn get_last_compaction_time(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::LastCompactionTime)
+        .unwrap_or(0)
+}
+
+/// Approximate fixed overhead of a persistent ledger entry (key, TTL and entry headers)
+pub const ENTRY_OVERHEAD_BYTES: u64 = 48;
+/// Approximate serialized size of a `Quest`: a 24-field map, mostly field names,
+/// without sponsor or template
+pub const QUEST_ENTRY_BYTES: u64 = 920;
+/// Approximate serialized size of a `Submission`: an 11-field map without partial
+/// approvals, score or reviewing verifier
+pub const SUBMISSION_ENTRY_BYTES: u64 = 470;
+/// Approximate serialized size of a `CompletionCertificate` (7 fields)
+pub const CERTIFICATE_ENTRY_BYTES: u64 = 360;
+/// Approximate serialized size of a `CreationFeePayment` (i128, 2 addresses, u64)
+pub const FEE_PAYMENT_ENTRY_BYTES: u64 = 190;
+/// Approximate serialized size of a `SubmissionStatusCounts` (4 x u32)
+pub const STATUS_COUNTS_ENTRY_BYTES: u64 = 124;
+/// Approximate serialized size of one address, alone or in an index vector
+pub const ADDRESS_BYTES: u64 = 40;
+/// Approximate serialized size of one quest ID in an index vector
+pub const QUEST_ID_BYTES: u64 = 16;
+/// Approximate serialized size of a 32-byte hash
+pub const HASH_BYTES: u64 = 40;
+/// Approximate serialized size of a `(u64, u32)` entry such as the peak day or score totals
+pub const PAIR_ENTRY_BYTES: u64 = 32;
+/// Approximate serialized size of an `i128` balance entry
+pub const BALANCE_ENTRY_BYTES: u64 = 20;
+/// Approximate serialized size of a `u32` counter entry
+pub const COUNTER_ENTRY_BYTES: u64 = 8;
+
 /// Store a quest
 pub fn set_quest(env: &Env, quest: &Quest) {
     let key = StorageKey::Quest(quest.id.clone());
-    env.storage().persistent().set(&key, quest);
+    let mut quest = quest.clone();
+    quest.live_until_ledger = env.ledger().sequence() + ENTRY_TTL_LEDGERS;
+    persistent_set(env, &key, &quest);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, ENTRY_TTL_LEDGERS, ENTRY_TTL_LEDGERS);
 }
 
-/// Get a quest
+/// Get a quest, converting records stored in an earlier layout to the current one.
+/// A record that cannot be decoded aborts with `QuestDecodeFailed` rather than reading
+/// as missing.
 pub fn get_quest(env: &Env, quest_id: &Symbol) -> Option<Quest> {
+    match get_quest_versioned(env, quest_id) {
+        Ok(quest) => Some(quest),
+        Err(Error::QuestNotFound) => None,
+        Err(error) => panic_with_error!(env, error),
+    }
+}
+
+/// Get the raw stored value of a quest, for decoding earlier layouts
+pub fn get_quest_raw(env: &Env, quest_id: &Symbol) -> Option<Val> {
     let key = StorageKey::Quest(quest_id.clone());
     env.storage().persistent().get(&key)
 }
 
+/// Read the layout version of a raw quest record. Version 1 records predate the
+/// `metadata_version` field.
+fn quest_layout_version(env: &Env, raw: &Val) -> Result<u32, Error> {
+    // Viewing the record as a map only checks its type; no field is unpacked
+    let fields =
+        Map::<Symbol, Val>::try_from_val(env, raw).map_err(|_| Error::QuestDecodeFailed)?;
+    match fields.get(Symbol::new(env, "metadata_version")) {
+        Some(version) => u32::try_from_val(env, &version).map_err(|_| Error::QuestDecodeFailed),
+        None => Ok(1),
+    }
+}
+
+/// Get a quest, converting records stored in an earlier layout to the current one.
+/// The converted record is not written back; see `admin::run_storage_migration`.
+pub fn get_quest_versioned(env: &Env, quest_id: &Symbol) -> Result<Quest, Error> {
+    let raw = get_quest_raw(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Decoding a record into a struct of another shape traps rather than failing, so the
+    // stored version picks the layout before the record is decoded
+    match quest_layout_version(env, &raw)? {
+        quest::QUEST_METADATA_VERSION => {
+            Quest::try_from_val(env, &raw).map_err(|_| Error::QuestDecodeFailed)
+        }
+        1 => QuestV1::try_from_val(env, &raw)
+            .map(quest::migrate_v1_to_v2)
+            .map_err(|_| Error::QuestDecodeFailed),
+        _ => Err(Error::QuestDecodeFailed),
+    }
+}
+
+/// Check whether a quest record is stored in the current layout
+pub fn is_quest_current(env: &Env, quest_id: &Symbol) -> bool {
+    get_quest_raw(env, quest_id).and_then(|raw| quest_layout_version(env, &raw).ok())
+        == Some(quest::QUEST_METADATA_VERSION)
+}
+
 /// Check if a quest exists
 pub fn has_quest(env: &Env, quest_id: &Symbol) -> bool {
     let key = StorageKey::Quest(quest_id.clone());
     env.storage().persistent().has(&key)
 }
 
-/// Store a submission
+/// TTL a submission entry is kept alive for in a given status
+pub fn submission_ttl_ledgers(status: &SubmissionStatus) -> u32 {
+    match status {
+        SubmissionStatus::Pending | SubmissionStatus::UnderReview => PENDING_TTL_LEDGERS,
+        SubmissionStatus::Approved | SubmissionStatus::AutoApproved | SubmissionStatus::Paid => {
+            PERMANENT_TTL_LEDGERS
+        }
+        _ => ENTRY_TTL_LEDGERS,
+    }
+}
+
+/// Store a submission, keeping it alive for the TTL of its status
 pub fn set_submission(env: &Env, submission: &Submission) {
     let key = StorageKey::Submission(submission.quest_id.clone(), submission.submitter.clone());
-    env.storage().persistent().set(&key, submission);
+    let ttl_ledgers = submission_ttl_ledgers(&submission.status);
+    let mut submission = submission.clone();
+    submission.live_until_ledger = env.ledger().sequence() + ttl_ledgers;
+    persistent_set(env, &key, &submission);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+}
+
+/// Extend the TTL of a stored submission to `ttl_ledgers` from now.
+/// Returns `false` if the submission does not exist.
+pub fn set_submission_expiry(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    ttl_ledgers: u32,
+) -> bool {
+    let Some(mut submission) = get_submission(env, quest_id, submitter) else {
+        return false;
+    };
+    let key = StorageKey::Submission(quest_id.clone(), submitter.clone());
+    submission.live_until_ledger = env.ledger().sequence() + ttl_ledgers;
+    persistent_set(env, &key, &submission);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+    true
+}
+
+/// Move a stored submission from `expected` to `new` status (compare-and-swap).
+/// Fails with `StatusMismatch` without writing if the stored status is not `expected`,
+/// so a submission settled earlier in the same call chain is never overwritten.
+pub fn atomic_swap_submission_status(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    expected: SubmissionStatus,
+    new: SubmissionStatus,
+) -> Result<(), Error> {
+    let mut submission =
+        get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+    if submission.status != expected {
+        return Err(Error::StatusMismatch);
+    }
+    submission.status = new;
+    set_submission(env, &submission);
+    Ok(())
+}
+
+/// Refresh an approved or paid submission to `PERMANENT_TTL_LEDGERS`.
+/// Returns `false` if the submission does not exist or is in any other status.
+pub fn refresh_approved_submission_ttl(env: &Env, quest_id: &Symbol, submitter: &Address) -> bool {
+    let refreshable = get_submission(env, quest_id, submitter)
+        .map(|s| s.status.is_approved() || s.status == SubmissionStatus::Paid)
+        .unwrap_or(false);
+    refreshable && set_submission_expiry(env, quest_id, submitter, PERMANENT_TTL_LEDGERS)
 }
 
 /// Get a submission
@@ -43,16 +439,81 @@ pub fn get_submission(env: &Env, quest_id: &Symbol, submitter: &Address) -> Opti
     env.storage().persistent().get(&key)
 }
 
-/// Check if a submission exists
+/// Check if a live (not rejected) submission exists
 pub fn has_submission(env: &Env, quest_id: &Symbol, submitter: &Address) -> bool {
     let key = StorageKey::Submission(quest_id.clone(), submitter.clone());
     env.storage().persistent().has(&key)
 }
 
-/// Store user stats
+/// Remove a submission from the live slot
+pub fn remove_submission(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let key = StorageKey::Submission(quest_id.clone(), submitter.clone());
+    persistent_remove(env, &key);
+}
+
+/// Get the rejected submissions of a user to a quest, oldest first
+pub fn get_rejected_submissions(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Vec<Submission> {
+    let key = AuxStorageKey::RejectedSubmission(quest_id.clone(), submitter.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a rejected submission to the user's rejection history for the quest
+pub fn add_rejected_submission(env: &Env, submission: &Submission) {
+    let mut history = get_rejected_submissions(env, &submission.quest_id, &submission.submitter);
+    history.push_back(submission.clone());
+    let key = AuxStorageKey::RejectedSubmission(
+        submission.quest_id.clone(),
+        submission.submitter.clone(),
+    );
+    persistent_set(env, &key, &history);
+}
+
+/// Store a submission backup in instance storage
+pub fn set_submission_backup(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    backup: &SubmissionBackup,
+) {
+    let key = StorageKey::SubmissionBackup(quest_id.clone(), submitter.clone());
+    env.storage().instance().set(&key, backup);
+}
+
+/// Get a submission backup from instance storage
+pub fn get_submission_backup(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Option<SubmissionBackup> {
+    let key = StorageKey::SubmissionBackup(quest_id.clone(), submitter.clone());
+    env.storage().instance().get(&key)
+}
+
+/// Store user stats, counting the user if they are new
 pub fn set_user_stats(env: &Env, stats: &UserStats) {
+    if !has_user_stats(env, &stats.address) {
+        let count = get_user_count(env) + 1;
+        env.storage()
+            .instance()
+            .set(&AuxStorageKey::UserCount, &count);
+    }
     let key = StorageKey::UserStats(stats.address.clone());
-    env.storage().persistent().set(&key, stats);
+    persistent_set(env, &key, stats);
+}
+
+/// Get the number of users with stats
+pub fn get_user_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::UserCount)
+        .unwrap_or(0)
 }
 
 /// Get user stats
@@ -61,9 +522,1365 @@ pub fn get_user_stats(env: &Env, address: &Address) -> Option<UserStats> {
     env.storage().persistent().get(&key)
 }
 
+/// Get the raw stored value of a user's stats, for decoding legacy layouts
+pub fn get_user_stats_raw(env: &Env, address: &Address) -> Option<Val> {
+    let key = StorageKey::UserStats(address.clone());
+    env.storage().persistent().get(&key)
+}
+
 /// Check if user stats exist
-#[allow(dead_code)]
 pub fn has_user_stats(env: &Env, address: &Address) -> bool {
     let key = StorageKey::UserStats(address.clone());
     env.storage().persistent().has(&key)
 }
+
+/// Get the number of submissions recorded for a quest on a given day
+pub fn get_daily_submissions(env: &Env, quest_id: &Symbol, day: u64) -> u32 {
+    let key = StorageKey::DailySubmissions(quest_id.clone(), day);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the number of submissions recorded for a quest on a given day
+pub fn set_daily_submissions(env: &Env, quest_id: &Symbol, day: u64, count: u32) {
+    let key = StorageKey::DailySubmissions(quest_id.clone(), day);
+    persistent_set(env, &key, &count);
+}
+
+/// Get the number of approvals recorded for a quest on a given day
+pub fn get_daily_approvals(env: &Env, quest_id: &Symbol, day: u64) -> u32 {
+    let key = AuxStorageKey::DailyApprovals(quest_id.clone(), day);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the number of approvals recorded for a quest on a given day
+pub fn set_daily_approvals(env: &Env, quest_id: &Symbol, day: u64, count: u32) {
+    let key = AuxStorageKey::DailyApprovals(quest_id.clone(), day);
+    persistent_set(env, &key, &count);
+}
+
+/// Get the number of submissions a user made on a given day, for activity history
+pub fn get_user_daily_activity(env: &Env, user: &Address, day: u64) -> u32 {
+    let key = StorageKey::UserDailyActivity(user.clone(), day);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the number of submissions a user made on a given day, for activity history
+pub fn set_user_daily_activity(env: &Env, user: &Address, day: u64, count: u32) {
+    let key = StorageKey::UserDailyActivity(user.clone(), day);
+    persistent_set(env, &key, &count);
+}
+
+/// Get the peak submission day for a quest
+pub fn get_peak_submission_day(env: &Env, quest_id: &Symbol) -> Option<(u64, u32)> {
+    let key = StorageKey::PeakSubmissionDay(quest_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the peak submission day for a quest
+pub fn set_peak_submission_day(env: &Env, quest_id: &Symbol, day: u64, count: u32) {
+    let key = StorageKey::PeakSubmissionDay(quest_id.clone());
+    persistent_set(env, &key, &(day, count));
+}
+
+/// Get all registered quest IDs
+pub fn get_quest_ids(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::QuestIds)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a quest ID to the registry
+pub fn add_quest_id(env: &Env, quest_id: &Symbol) {
+    let mut ids = get_quest_ids(env);
+    ids.push_back(quest_id.clone());
+    persistent_set(env, &StorageKey::QuestIds, &ids);
+}
+
+/// Get submitters of a quest in arrival order
+pub fn get_quest_submitters(env: &Env, quest_id: &Symbol) -> Vec<Address> {
+    let key = StorageKey::QuestSubmitters(quest_id.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a submitter to a quest's submitter index
+pub fn add_quest_submitter(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let key = StorageKey::QuestSubmitters(quest_id.clone());
+    let mut submitters = get_quest_submitters(env, quest_id);
+    submitters.push_back(submitter.clone());
+    persistent_set(env, &key, &submitters);
+}
+
+/// Get the number of users who have submitted to a quest
+pub fn get_quest_submission_count(env: &Env, quest_id: &Symbol) -> u32 {
+    get_quest_submitters(env, quest_id).len()
+}
+
+/// Get the submission of the quest's `index`-th submitter (0-based, in arrival order),
+/// falling back to their latest rejected submission
+pub fn get_submission_by_index(
+    env: &Env,
+    quest_id: &Symbol,
+    index: u32,
+) -> Result<Submission, Error> {
+    let submitter = get_quest_submitters(env, quest_id)
+        .get(index)
+        .ok_or(Error::SubmissionNotFound)?;
+    get_submission(env, quest_id, &submitter)
+        .or_else(|| get_rejected_submissions(env, quest_id, &submitter).last())
+        .ok_or(Error::SubmissionNotFound)
+}
+
+/// Estimate the persistent storage used by a quest, in bytes.
+/// Uses fixed per-entry sizes instead of reading every entry, so the result is approximate.
+/// A submitter's referral link is counted with the first quest they submitted to.
+pub fn estimate_storage_cost(env: &Env, quest_id: &Symbol) -> u64 {
+    let persistent = env.storage().persistent();
+    let entry = |size: u64| ENTRY_OVERHEAD_BYTES + size;
+    let mut bytes = 0;
+
+    if persistent.has(&StorageKey::Quest(quest_id.clone())) {
+        bytes += entry(QUEST_ENTRY_BYTES);
+        // Sponsor field plus the quest's slot in the sponsor's index
+        let sponsored = get_quest_versioned(env, quest_id)
+            .map(|quest| quest.sponsorship_address.is_some())
+            .unwrap_or(false);
+        if sponsored {
+            bytes += ADDRESS_BYTES + QUEST_ID_BYTES;
+        }
+    }
+
+    // Submitter index plus the entries kept for each submitter
+    if persistent.has(&StorageKey::QuestSubmitters(quest_id.clone())) {
+        let submitters = get_quest_submitters(env, quest_id);
+        bytes += entry(submitters.len() as u64 * ADDRESS_BYTES);
+        for submitter in submitters.iter() {
+            if persistent.has(&StorageKey::Submission(quest_id.clone(), submitter.clone())) {
+                bytes += entry(SUBMISSION_ENTRY_BYTES);
+            }
+            let rejected = AuxStorageKey::RejectedSubmission(quest_id.clone(), submitter.clone());
+            if persistent.has(&rejected) {
+                let count = get_rejected_submissions(env, quest_id, &submitter).len() as u64;
+                bytes += entry(count * SUBMISSION_ENTRY_BYTES);
+            }
+            if persistent.has(&AuxStorageKey::Certificate(
+                quest_id.clone(),
+                submitter.clone(),
+            )) {
+                bytes += entry(CERTIFICATE_ENTRY_BYTES);
+            }
+            if let Some(uri) = get_proof_uri(env, quest_id, &submitter) {
+                bytes += entry(uri.len() as u64);
+            }
+            if persistent.has(&StorageKey::Referrer(submitter.clone()))
+                && get_user_submissions(env, &submitter).first().as_ref() == Some(quest_id)
+            {
+                bytes += entry(ADDRESS_BYTES);
+            }
+        }
+    }
+
+    if persistent.has(&StorageKey::PeakSubmissionDay(quest_id.clone())) {
+        bytes += entry(PAIR_ENTRY_BYTES);
+    }
+    if persistent.has(&StorageKey::QuestEscrow(quest_id.clone())) {
+        bytes += entry(BALANCE_ENTRY_BYTES);
+    }
+    if persistent.has(&StorageKey::QuestPendingCount(quest_id.clone())) {
+        bytes += entry(COUNTER_ENTRY_BYTES);
+    }
+    if persistent.has(&StorageKey::SubmissionStatusCounts(quest_id.clone())) {
+        bytes += entry(STATUS_COUNTS_ENTRY_BYTES);
+    }
+    if persistent.has(&StorageKey::CreationFeePaid(quest_id.clone())) {
+        bytes += entry(FEE_PAYMENT_ENTRY_BYTES);
+    }
+    if persistent.has(&StorageKey::WhitelistRoot(quest_id.clone())) {
+        bytes += entry(HASH_BYTES);
+    }
+    if persistent.has(&AuxStorageKey::QuestProofHashes(quest_id.clone())) {
+        let count = get_quest_proof_hashes(env, quest_id).len() as u64;
+        bytes += entry(count * (HASH_BYTES + ADDRESS_BYTES));
+    }
+    if persistent.has(&AuxStorageKey::QuestScoreTotals(quest_id.clone())) {
+        bytes += entry(PAIR_ENTRY_BYTES);
+    }
+    if persistent.has(&AuxStorageKey::QuestReviewTimes(quest_id.clone())) {
+        bytes += entry(PAIR_ENTRY_BYTES);
+    }
+    if persistent.has(&AuxStorageKey::QuestHealthScore(quest_id.clone())) {
+        bytes += entry(COUNTER_ENTRY_BYTES);
+    }
+    if persistent.has(&AuxStorageKey::QuestStreamId(quest_id.clone())) {
+        bytes += entry(COUNTER_ENTRY_BYTES);
+    }
+    if persistent.has(&AuxStorageKey::ChainRoot(quest_id.clone())) {
+        bytes += entry(QUEST_ID_BYTES);
+    }
+    if persistent.has(&AuxStorageKey::QuestChain(quest_id.clone())) {
+        let count = get_quest_chain(env, quest_id).len() as u64;
+        bytes += entry(count * QUEST_ID_BYTES);
+    }
+
+    bytes
+}
+
+/// Get the escrowed reward balance of a quest
+pub fn get_quest_escrow(env: &Env, quest_id: &Symbol) -> i128 {
+    let key = StorageKey::QuestEscrow(quest_id.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the escrowed reward balance of a quest, keeping the escrow total of its asset
+pub fn set_quest_escrow(env: &Env, quest_id: &Symbol, asset: &Address, amount: i128) {
+    let total = get_escrow_total(env, asset) - get_quest_escrow(env, quest_id) + amount;
+    persistent_set(env, &AuxStorageKey::EscrowTotal(asset.clone()), &total);
+
+    let key = StorageKey::QuestEscrow(quest_id.clone());
+    persistent_set(env, &key, &amount);
+}
+
+/// Get the sum of all quest escrows in an asset
+pub fn get_escrow_total(env: &Env, asset: &Address) -> i128 {
+    let key = AuxStorageKey::EscrowTotal(asset.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Get quest IDs of a given difficulty
+pub fn get_difficulty_quests(env: &Env, difficulty: QuestDifficulty) -> Vec<Symbol> {
+    let key = StorageKey::DifficultyQuests(difficulty);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a quest ID to the index of its difficulty
+pub fn add_difficulty_quest(env: &Env, difficulty: QuestDifficulty, quest_id: &Symbol) {
+    let key = StorageKey::DifficultyQuests(difficulty);
+    let mut ids = get_difficulty_quests(env, difficulty);
+    ids.push_back(quest_id.clone());
+    persistent_set(env, &key, &ids);
+}
+
+/// Get the quest IDs a verifier is assigned to
+pub fn get_verifier_quests(env: &Env, verifier: &Address) -> Vec<Symbol> {
+    let key = StorageKey::VerifierQuests(verifier.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a quest ID to a verifier's assignment index
+pub fn add_verifier_quest(env: &Env, verifier: &Address, quest_id: &Symbol) {
+    let key = StorageKey::VerifierQuests(verifier.clone());
+    let mut ids = get_verifier_quests(env, verifier);
+    ids.push_back(quest_id.clone());
+    persistent_set(env, &key, &ids);
+}
+
+/// Remove a quest ID from a verifier's assignment index
+pub fn remove_verifier_quest(env: &Env, verifier: &Address, quest_id: &Symbol) {
+    let key = StorageKey::VerifierQuests(verifier.clone());
+    let mut ids = get_verifier_quests(env, verifier);
+    if let Some(index) = ids.first_index_of(quest_id) {
+        ids.remove(index);
+        persistent_set(env, &key, &ids);
+    }
+}
+
+/// Get the pending (quest_id, submitter) pairs assigned to a verifier
+pub fn get_verifier_pending(env: &Env, verifier: &Address) -> Vec<(Symbol, Address)> {
+    let key = StorageKey::VerifierPendingQueue(verifier.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the pending queue of a verifier
+pub fn set_verifier_pending(env: &Env, verifier: &Address, queue: &Vec<(Symbol, Address)>) {
+    let key = StorageKey::VerifierPendingQueue(verifier.clone());
+    persistent_set(env, &key, queue);
+}
+
+/// Append a pending submission to a verifier's queue
+pub fn add_verifier_pending(env: &Env, verifier: &Address, quest_id: &Symbol, submitter: &Address) {
+    let mut queue = get_verifier_pending(env, verifier);
+    queue.push_back((quest_id.clone(), submitter.clone()));
+    set_verifier_pending(env, verifier, &queue);
+}
+
+/// Remove a submission from a verifier's pending queue
+pub fn remove_verifier_pending(
+    env: &Env,
+    verifier: &Address,
+    quest_id: &Symbol,
+    submitter: &Address,
+) {
+    let mut queue = get_verifier_pending(env, verifier);
+    if let Some(index) = queue.first_index_of((quest_id.clone(), submitter.clone())) {
+        queue.remove(index);
+        set_verifier_pending(env, verifier, &queue);
+    }
+}
+
+/// Get the quest IDs in a fill-level bucket
+pub fn get_fill_bucket_quests(env: &Env, bucket: u32) -> Vec<Symbol> {
+    let key = StorageKey::FillBucket(bucket);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a quest ID to a fill-level bucket
+pub fn add_fill_bucket_quest(env: &Env, bucket: u32, quest_id: &Symbol) {
+    let key = StorageKey::FillBucket(bucket);
+    let mut ids = get_fill_bucket_quests(env, bucket);
+    ids.push_back(quest_id.clone());
+    persistent_set(env, &key, &ids);
+}
+
+/// Remove a quest ID from a fill-level bucket
+pub fn remove_fill_bucket_quest(env: &Env, bucket: u32, quest_id: &Symbol) {
+    let key = StorageKey::FillBucket(bucket);
+    let mut ids = get_fill_bucket_quests(env, bucket);
+    if let Some(index) = ids.first_index_of(quest_id) {
+        ids.remove(index);
+        persistent_set(env, &key, &ids);
+    }
+}
+
+/// Get XP a user earned from quests of a given difficulty
+pub fn get_difficulty_xp(env: &Env, address: &Address, difficulty: QuestDifficulty) -> u32 {
+    let key = StorageKey::DifficultyXp(address.clone(), difficulty);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store XP a user earned from quests of a given difficulty
+pub fn set_difficulty_xp(env: &Env, address: &Address, difficulty: QuestDifficulty, xp: u32) {
+    let key = StorageKey::DifficultyXp(address.clone(), difficulty);
+    persistent_set(env, &key, &xp);
+}
+
+/// Get the contract admin
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StorageKey::Admin)
+}
+
+/// Store the contract admin
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&StorageKey::Admin, admin);
+}
+
+/// Get the ledger sequence at which the contract was initialized
+pub fn get_deployment_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&StorageKey::DeploymentLedger)
+}
+
+/// Store the ledger sequence at which the contract was initialized
+pub fn set_deployment_ledger(env: &Env, ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::DeploymentLedger, &ledger);
+}
+
+/// Get the configured go-live ledger, if any
+pub fn get_go_live_ledger(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&StorageKey::GoLiveLedger)
+}
+
+/// Store the go-live ledger
+pub fn set_go_live_ledger(env: &Env, ledger: u32) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::GoLiveLedger, &ledger);
+}
+
+/// Get the quest creation fee and its asset, if configured
+pub fn get_creation_fee(env: &Env) -> Option<(i128, Address)> {
+    env.storage().instance().get(&StorageKey::CreationFee)
+}
+
+/// Store the quest creation fee and its asset
+pub fn set_creation_fee(env: &Env, fee: i128, asset: &Address) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::CreationFee, &(fee, asset.clone()));
+}
+
+/// Get the configured treasury address, if any
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StorageKey::Treasury)
+}
+
+/// Store the treasury address
+pub fn set_treasury(env: &Env, treasury: &Address) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::Treasury, treasury);
+}
+
+/// Get the creation fee paid for a quest
+pub fn get_creation_fee_paid(env: &Env, quest_id: &Symbol) -> Option<CreationFeePayment> {
+    let key = StorageKey::CreationFeePaid(quest_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the creation fee paid for a quest, adding it to the fees held in its asset
+pub fn set_creation_fee_paid(env: &Env, quest_id: &Symbol, payment: &CreationFeePayment) {
+    let held = get_held_creation_fees(env, &payment.asset) + payment.amount;
+    set_held_creation_fees(env, &payment.asset, held);
+
+    let key = StorageKey::CreationFeePaid(quest_id.clone());
+    persistent_set(env, &key, payment);
+}
+
+/// Remove the creation fee record of a quest, releasing it from the fees held
+pub fn remove_creation_fee_paid(env: &Env, quest_id: &Symbol) {
+    if let Some(payment) = get_creation_fee_paid(env, quest_id) {
+        let held = get_held_creation_fees(env, &payment.asset) - payment.amount;
+        set_held_creation_fees(env, &payment.asset, held);
+    }
+    persistent_remove(env, &StorageKey::CreationFeePaid(quest_id.clone()));
+}
+
+/// Get the creation fees in an asset held by the contract during refund windows
+pub fn get_held_creation_fees(env: &Env, asset: &Address) -> i128 {
+    let key = AuxStorageKey::HeldCreationFees(asset.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the creation fees in an asset held by the contract
+fn set_held_creation_fees(env: &Env, asset: &Address, amount: i128) {
+    let key = AuxStorageKey::HeldCreationFees(asset.clone());
+    persistent_set(env, &key, &amount);
+}
+
+/// Get the configured daily submission limit, if any
+pub fn get_max_daily_submissions(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::MaxDailySubmissions)
+}
+
+/// Store the daily submission limit
+pub fn set_max_daily_submissions(env: &Env, limit: u32) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::MaxDailySubmissions, &limit);
+}
+
+/// Get the number of submissions a user made on a given day
+pub fn get_daily_submission_count(env: &Env, user: &Address, day: u64) -> u32 {
+    let key = StorageKey::DailySubmissionCount(user.clone(), day);
+    env.storage().temporary().get(&key).unwrap_or(0)
+}
+
+/// Store the number of submissions a user made on a given day.
+/// Kept in temporary storage so it expires on its own after about a day.
+pub fn set_daily_submission_count(env: &Env, user: &Address, day: u64, count: u32) {
+    let key = StorageKey::DailySubmissionCount(user.clone(), day);
+    env.storage().temporary().set(&key, &count);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS, DAY_IN_LEDGERS);
+}
+
+/// Get verifier stats, defaulting to zeroed stats
+pub fn get_verifier_stats(env: &Env, verifier: &Address) -> VerifierStats {
+    let key = StorageKey::VerifierStats(verifier.clone());
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+/// Store verifier stats
+pub fn set_verifier_stats(env: &Env, verifier: &Address, stats: &VerifierStats) {
+    let key = StorageKey::VerifierStats(verifier.clone());
+    persistent_set(env, &key, stats);
+}
+
+/// Get the quest IDs a user has submitted to
+pub fn get_user_submissions(env: &Env, user: &Address) -> Vec<Symbol> {
+    let key = StorageKey::UserSubmissions(user.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Replace a user's submission index
+pub fn set_user_submissions(env: &Env, user: &Address, ids: &Vec<Symbol>) {
+    let key = StorageKey::UserSubmissions(user.clone());
+    persistent_set(env, &key, ids);
+}
+
+/// Add a quest ID to a user's submission index
+pub fn add_user_submission(env: &Env, user: &Address, quest_id: &Symbol) {
+    let key = StorageKey::UserSubmissions(user.clone());
+    let mut ids = get_user_submissions(env, user);
+    ids.push_back(quest_id.clone());
+    persistent_set(env, &key, &ids);
+}
+
+/// Maximum number of ledger sequences kept in the submission ledger index
+pub const MAX_INDEXED_LEDGERS: u32 = 1000;
+
+/// Get the submissions created at a ledger sequence
+pub fn get_submission_ledger_index(env: &Env, sequence: u32) -> Vec<(Symbol, Address)> {
+    let key = StorageKey::SubmissionLedgerIndex(sequence);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Index a submission under the ledger sequence it was created at.
+/// The oldest ledger is dropped once `MAX_INDEXED_LEDGERS` sequences are indexed, which
+/// is recorded as the last compaction time.
+pub fn add_submission_to_ledger_index(
+    env: &Env,
+    sequence: u32,
+    quest_id: &Symbol,
+    submitter: &Address,
+) {
+    let mut entries = get_submission_ledger_index(env, sequence);
+
+    if entries.is_empty() {
+        let mut ledgers: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::IndexedLedgers)
+            .unwrap_or_else(|| Vec::new(env));
+        ledgers.push_back(sequence);
+        while ledgers.len() > MAX_INDEXED_LEDGERS {
+            if let Some(oldest) = ledgers.pop_front() {
+                persistent_remove(env, &StorageKey::SubmissionLedgerIndex(oldest));
+                env.storage()
+                    .instance()
+                    .set(&StorageKey::LastCompactionTime, &env.ledger().timestamp());
+            }
+        }
+        persistent_set(env, &StorageKey::IndexedLedgers, &ledgers);
+    }
+
+    entries.push_back((quest_id.clone(), submitter.clone()));
+    persistent_set(env, &StorageKey::SubmissionLedgerIndex(sequence), &entries);
+}
+
+/// Check if a user is in the fraud registry
+pub fn is_fraudulent(env: &Env, user: &Address) -> bool {
+    let key = StorageKey::Fraudulent(user.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Add a user to the fraud registry
+pub fn add_fraudulent(env: &Env, user: &Address) {
+    let key = StorageKey::Fraudulent(user.clone());
+    persistent_set(env, &key, &true);
+}
+
+/// Get the users whose accounts are frozen
+pub fn get_frozen_users(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AuxStorageKey::FrozenUsers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the users whose accounts are frozen
+pub fn set_frozen_users(env: &Env, users: &Vec<Address>) {
+    persistent_set(env, &AuxStorageKey::FrozenUsers, users);
+}
+
+/// Check if a user's account is frozen
+pub fn is_frozen(env: &Env, user: &Address) -> bool {
+    get_frozen_users(env).contains(user)
+}
+
+/// Get the verifiers available for quest assignment
+pub fn get_registered_verifiers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AuxStorageKey::RegisteredVerifiers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the verifiers available for quest assignment
+pub fn set_registered_verifiers(env: &Env, verifiers: &Vec<Address>) {
+    persistent_set(env, &AuxStorageKey::RegisteredVerifiers, verifiers);
+}
+
+/// Get the number of quest streams created
+pub fn get_stream_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::StreamCount)
+        .unwrap_or(0)
+}
+
+/// Store the number of quest streams created
+pub fn set_stream_count(env: &Env, count: u32) {
+    persistent_set(env, &StorageKey::StreamCount, &count);
+}
+
+/// Store a quest stream
+pub fn set_stream(env: &Env, stream: &QuestStream) {
+    let key = StorageKey::Stream(stream.id);
+    persistent_set(env, &key, stream);
+}
+
+/// Get a quest stream
+pub fn get_stream(env: &Env, stream_id: u32) -> Option<QuestStream> {
+    let key = StorageKey::Stream(stream_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Get the stream a quest belongs to, if any
+pub fn get_quest_stream_id(env: &Env, quest_id: &Symbol) -> Option<u32> {
+    let key = AuxStorageKey::QuestStreamId(quest_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Record the stream a quest belongs to
+pub fn set_quest_stream_id(env: &Env, quest_id: &Symbol, stream_id: u32) {
+    let key = AuxStorageKey::QuestStreamId(quest_id.clone());
+    persistent_set(env, &key, &stream_id);
+}
+
+/// Check if a user has already completed a stream
+pub fn is_stream_completed(env: &Env, stream_id: u32, user: &Address) -> bool {
+    let key = StorageKey::StreamCompleted(stream_id, user.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Mark a stream as completed by a user
+pub fn set_stream_completed(env: &Env, stream_id: u32, user: &Address) {
+    let key = StorageKey::StreamCompleted(stream_id, user.clone());
+    persistent_set(env, &key, &true);
+}
+
+/// Get platform-wide activity counters
+pub fn get_platform_stats(env: &Env) -> PlatformStats {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::PlatformStats)
+        .unwrap_or_default()
+}
+
+/// Store platform-wide activity counters
+pub fn set_platform_stats(env: &Env, stats: &PlatformStats) {
+    persistent_set(env, &StorageKey::PlatformStats, stats);
+}
+
+/// Get the XP leaderboard
+pub fn get_leaderboard(env: &Env) -> Vec<(Address, u32)> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Leaderboard)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the XP leaderboard
+pub fn set_leaderboard(env: &Env, leaderboard: &Vec<(Address, u32)>) {
+    persistent_set(env, &StorageKey::Leaderboard, leaderboard);
+}
+
+/// Get the number of the current leaderboard season
+pub fn get_season_id(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::SeasonId)
+        .unwrap_or(0)
+}
+
+/// Store the number of the current leaderboard season
+pub fn set_season_id(env: &Env, season_id: u32) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::SeasonId, &season_id);
+}
+
+/// Get the XP a user earned in a season
+pub fn get_monthly_xp(env: &Env, season_id: u32, user: &Address) -> u32 {
+    let key = StorageKey::MonthlyXp(season_id, user.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the XP a user earned in a season
+pub fn set_monthly_xp(env: &Env, season_id: u32, user: &Address, xp: u32) {
+    let key = StorageKey::MonthlyXp(season_id, user.clone());
+    persistent_set(env, &key, &xp);
+}
+
+/// Get the monthly leaderboard
+pub fn get_monthly_leaderboard(env: &Env) -> Vec<(Address, u32)> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::MonthlyLeaderboard)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the monthly leaderboard
+pub fn set_monthly_leaderboard(env: &Env, leaderboard: &Vec<(Address, u32)>) {
+    persistent_set(env, &StorageKey::MonthlyLeaderboard, leaderboard);
+}
+
+/// Get the archive of past seasons
+pub fn get_monthly_archive(env: &Env) -> Vec<(u64, Vec<(Address, u32)>)> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::MonthlyArchive)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the archive of past seasons
+pub fn set_monthly_archive(env: &Env, archive: &Vec<(u64, Vec<(Address, u32)>)>) {
+    persistent_set(env, &StorageKey::MonthlyArchive, archive);
+}
+
+/// Get the timestamp of the last platform snapshot
+pub fn get_last_snapshot_time(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&StorageKey::LastSnapshotTime)
+}
+
+/// Store the timestamp of the last platform snapshot
+pub fn set_last_snapshot_time(env: &Env, timestamp: u64) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::LastSnapshotTime, &timestamp);
+}
+
+/// Get the review outcome counts for a quest
+pub fn submission_status_counts(env: &Env, quest_id: &Symbol) -> SubmissionStatusCounts {
+    let key = StorageKey::SubmissionStatusCounts(quest_id.clone());
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+/// Store the review outcome counts for a quest
+pub fn set_submission_status_counts(env: &Env, quest_id: &Symbol, counts: &SubmissionStatusCounts) {
+    let key = StorageKey::SubmissionStatusCounts(quest_id.clone());
+    persistent_set(env, &key, counts);
+}
+
+/// Get the number of pending submissions across all quests
+pub fn get_global_pending(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::GlobalPendingCount)
+        .unwrap_or(0)
+}
+
+/// Increment the global pending submission counter
+pub fn increment_global_pending(env: &Env) {
+    let count = get_global_pending(env) + 1;
+    persistent_set(env, &StorageKey::GlobalPendingCount, &count);
+}
+
+/// Decrement the global pending submission counter
+pub fn decrement_global_pending(env: &Env) {
+    let count = get_global_pending(env).saturating_sub(1);
+    persistent_set(env, &StorageKey::GlobalPendingCount, &count);
+}
+
+/// Get the number of pending submissions for a quest
+pub fn get_quest_pending(env: &Env, quest_id: &Symbol) -> u32 {
+    let key = StorageKey::QuestPendingCount(quest_id.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Increment the pending submission counter of a quest
+pub fn increment_quest_pending(env: &Env, quest_id: &Symbol) {
+    let key = StorageKey::QuestPendingCount(quest_id.clone());
+    let count = get_quest_pending(env, quest_id) + 1;
+    persistent_set(env, &key, &count);
+}
+
+/// Decrement the pending submission counter of a quest
+pub fn decrement_quest_pending(env: &Env, quest_id: &Symbol) {
+    let key = StorageKey::QuestPendingCount(quest_id.clone());
+    let count = get_quest_pending(env, quest_id).saturating_sub(1);
+    persistent_set(env, &key, &count);
+}
+
+/// Get the referrer of a user
+pub fn get_referrer(env: &Env, user: &Address) -> Option<Address> {
+    let key = StorageKey::Referrer(user.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the referrer of a user
+pub fn set_referrer(env: &Env, user: &Address, referrer: &Address) {
+    let key = StorageKey::Referrer(user.clone());
+    persistent_set(env, &key, referrer);
+}
+
+/// Get the total referral bonuses earned by a referrer
+pub fn get_referral_earnings(env: &Env, referrer: &Address) -> i128 {
+    let key = StorageKey::ReferralEarnings(referrer.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the total referral bonuses earned by a referrer
+pub fn set_referral_earnings(env: &Env, referrer: &Address, earnings: i128) {
+    let key = StorageKey::ReferralEarnings(referrer.clone());
+    persistent_set(env, &key, &earnings);
+}
+
+/// Get the referral bonus pool balance of an asset
+pub fn get_referral_pool(env: &Env, asset: &Address) -> i128 {
+    let key = AuxStorageKey::ReferralPool(asset.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the referral bonus pool balance of an asset
+pub fn set_referral_pool(env: &Env, asset: &Address, amount: i128) {
+    let key = AuxStorageKey::ReferralPool(asset.clone());
+    persistent_set(env, &key, &amount);
+}
+
+/// Get the number of paid referrals for a referrer
+pub fn get_referral_count(env: &Env, referrer: &Address) -> u32 {
+    let key = StorageKey::ReferralCount(referrer.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the number of paid referrals for a referrer
+pub fn set_referral_count(env: &Env, referrer: &Address, count: u32) {
+    let key = StorageKey::ReferralCount(referrer.clone());
+    persistent_set(env, &key, &count);
+}
+
+/// Get the referral leaderboard
+pub fn get_referral_leaderboard(env: &Env) -> Vec<(Address, i128, u32)> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::ReferralLeaderboard)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the referral leaderboard
+pub fn set_referral_leaderboard(env: &Env, leaderboard: &Vec<(Address, i128, u32)>) {
+    persistent_set(env, &StorageKey::ReferralLeaderboard, leaderboard);
+}
+
+/// Get the URI schemes accepted for proofs, if configured
+pub fn get_allowed_uri_schemes(env: &Env) -> Option<Vec<Bytes>> {
+    env.storage().instance().get(&StorageKey::AllowedUriSchemes)
+}
+
+/// Store the URI schemes accepted for proofs
+pub fn set_allowed_uri_schemes(env: &Env, schemes: &Vec<Bytes>) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::AllowedUriSchemes, schemes);
+}
+
+/// Get the proof URI of a submission
+pub fn get_proof_uri(env: &Env, quest_id: &Symbol, submitter: &Address) -> Option<Bytes> {
+    let key = StorageKey::ProofUri(quest_id.clone(), submitter.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the proof URI of a submission
+pub fn set_proof_uri(env: &Env, quest_id: &Symbol, submitter: &Address, uri: &Bytes) {
+    let key = StorageKey::ProofUri(quest_id.clone(), submitter.clone());
+    persistent_set(env, &key, uri);
+}
+
+/// Get the whitelist Merkle root of a quest
+pub fn get_whitelist_root(env: &Env, quest_id: &Symbol) -> Option<BytesN<32>> {
+    let key = StorageKey::WhitelistRoot(quest_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the whitelist Merkle root of a quest
+pub fn set_whitelist_root(env: &Env, quest_id: &Symbol, root: &BytesN<32>) {
+    let key = StorageKey::WhitelistRoot(quest_id.clone());
+    persistent_set(env, &key, root);
+}
+
+/// Get the permit signing key registered by a user
+pub fn get_permit_key(env: &Env, user: &Address) -> Option<BytesN<32>> {
+    let key = AuxStorageKey::PermitKey(user.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the permit signing key of a user
+pub fn set_permit_key(env: &Env, user: &Address, public_key: &BytesN<32>) {
+    let key = AuxStorageKey::PermitKey(user.clone());
+    persistent_set(env, &key, public_key);
+}
+
+/// Check if a permit message hash has been used
+pub fn is_permit_used(env: &Env, message: &BytesN<32>) -> bool {
+    let key = AuxStorageKey::UsedPermit(message.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Mark a permit message hash as used
+pub fn mark_permit_used(env: &Env, message: &BytesN<32>) {
+    let key = AuxStorageKey::UsedPermit(message.clone());
+    persistent_set(env, &key, &true);
+}
+
+/// Get the contract metadata entries
+pub fn get_contract_metadata(env: &Env) -> Map<Symbol, Bytes> {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::ContractMetadata)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Store the contract metadata entries
+pub fn set_contract_metadata(env: &Env, metadata: &Map<Symbol, Bytes>) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::ContractMetadata, metadata);
+}
+
+/// Get the quest IDs of the chain started by `root`
+pub fn get_quest_chain(env: &Env, root: &Symbol) -> Vec<Symbol> {
+    let key = AuxStorageKey::QuestChain(root.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the quest IDs of the chain started by `root`
+pub fn set_quest_chain(env: &Env, root: &Symbol, chain: &Vec<Symbol>) {
+    let key = AuxStorageKey::QuestChain(root.clone());
+    persistent_set(env, &key, chain);
+}
+
+/// Get the original quest of the chain a quest was duplicated into
+pub fn get_chain_root(env: &Env, quest_id: &Symbol) -> Option<Symbol> {
+    let key = AuxStorageKey::ChainRoot(quest_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Record the original quest of the chain a quest was duplicated into
+pub fn set_chain_root(env: &Env, quest_id: &Symbol, root: &Symbol) {
+    let key = AuxStorageKey::ChainRoot(quest_id.clone());
+    persistent_set(env, &key, root);
+}
+
+/// Get the configured referral milestone XP grants
+pub fn get_referral_milestone_xp(env: &Env) -> Option<Vec<(u32, u32)>> {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::ReferralMilestoneXp)
+}
+
+/// Store the referral milestone XP grants
+pub fn set_referral_milestone_xp(env: &Env, milestones: &Vec<(u32, u32)>) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::ReferralMilestoneXp, milestones);
+}
+
+/// Get the reward index of an asset, ascending by reward amount
+pub fn get_reward_index(env: &Env, asset: &Address) -> Vec<(i128, Symbol)> {
+    let key = AuxStorageKey::RewardIndex(asset.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the reward index of an asset
+pub fn set_reward_index(env: &Env, asset: &Address, index: &Vec<(i128, Symbol)>) {
+    let key = AuxStorageKey::RewardIndex(asset.clone());
+    persistent_set(env, &key, index);
+}
+
+/// Insert a quest into its asset's reward index, keeping it sorted by reward amount
+pub fn add_reward_index_quest(env: &Env, asset: &Address, reward_amount: i128, quest_id: &Symbol) {
+    let mut index = get_reward_index(env, asset);
+    let position = match index.binary_search((reward_amount, quest_id.clone())) {
+        Ok(position) | Err(position) => position,
+    };
+    index.insert(position, (reward_amount, quest_id.clone()));
+    set_reward_index(env, asset, &index);
+}
+
+/// Remove a quest from its asset's reward index
+pub fn remove_reward_index_quest(
+    env: &Env,
+    asset: &Address,
+    reward_amount: i128,
+    quest_id: &Symbol,
+) {
+    let mut index = get_reward_index(env, asset);
+    if let Ok(position) = index.binary_search((reward_amount, quest_id.clone())) {
+        index.remove(position);
+        set_reward_index(env, asset, &index);
+    }
+}
+
+/// Get the first submitter of each proof hash on a quest
+pub fn get_quest_proof_hashes(env: &Env, quest_id: &Symbol) -> Map<BytesN<32>, Address> {
+    let key = AuxStorageKey::QuestProofHashes(quest_id.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Record the submitter of a proof hash on a quest, keeping the first submitter if already seen
+pub fn add_quest_proof_hash(
+    env: &Env,
+    quest_id: &Symbol,
+    proof_hash: &BytesN<32>,
+    submitter: &Address,
+) {
+    let mut hashes = get_quest_proof_hashes(env, quest_id);
+    if !hashes.contains_key(proof_hash.clone()) {
+        hashes.set(proof_hash.clone(), submitter.clone());
+        let key = AuxStorageKey::QuestProofHashes(quest_id.clone());
+        persistent_set(env, &key, &hashes);
+    }
+}
+
+/// Get the completion certificate issued for a submission
+pub fn get_certificate(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Option<CompletionCertificate> {
+    let key = AuxStorageKey::Certificate(quest_id.clone(), submitter.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the completion certificate of a submission
+pub fn set_certificate(env: &Env, certificate: &CompletionCertificate) {
+    let key =
+        AuxStorageKey::Certificate(certificate.quest_id.clone(), certificate.submitter.clone());
+    persistent_set(env, &key, certificate);
+}
+
+/// Get the sum and number of the scores given to a quest's submissions
+pub fn get_quest_score_totals(env: &Env, quest_id: &Symbol) -> (u64, u32) {
+    let key = AuxStorageKey::QuestScoreTotals(quest_id.clone());
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
+/// Store the sum and number of the scores given to a quest's submissions
+pub fn set_quest_score_totals(env: &Env, quest_id: &Symbol, sum: u64, count: u32) {
+    let key = AuxStorageKey::QuestScoreTotals(quest_id.clone());
+    persistent_set(env, &key, &(sum, count));
+}
+
+/// Get the sum of review times in seconds and number of reviews for a quest
+pub fn get_quest_review_times(env: &Env, quest_id: &Symbol) -> (u64, u32) {
+    let key = AuxStorageKey::QuestReviewTimes(quest_id.clone());
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
+/// Store the sum of review times in seconds and number of reviews for a quest
+pub fn set_quest_review_times(env: &Env, quest_id: &Symbol, sum: u64, count: u32) {
+    let key = AuxStorageKey::QuestReviewTimes(quest_id.clone());
+    persistent_set(env, &key, &(sum, count));
+}
+
+/// Get the sum of review times in seconds and number of reviews by a verifier
+pub fn get_verifier_review_times(env: &Env, verifier: &Address) -> (u64, u32) {
+    let key = AuxStorageKey::VerifierReviewTimes(verifier.clone());
+    env.storage().persistent().get(&key).unwrap_or((0, 0))
+}
+
+/// Store the sum of review times in seconds and number of reviews by a verifier
+pub fn set_verifier_review_times(env: &Env, verifier: &Address, sum: u64, count: u32) {
+    let key = AuxStorageKey::VerifierReviewTimes(verifier.clone());
+    persistent_set(env, &key, &(sum, count));
+}
+
+/// Get the number of daily quest series created
+pub fn get_daily_series_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::DailySeriesCount)
+        .unwrap_or(0)
+}
+
+/// Store the number of daily quest series created
+pub fn set_daily_series_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::DailySeriesCount, &count);
+}
+
+/// Get a daily quest series
+pub fn get_daily_series(env: &Env, series_id: u32) -> Option<DailyQuestSeries> {
+    let key = AuxStorageKey::DailySeries(series_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store a daily quest series
+pub fn set_daily_series(env: &Env, series: &DailyQuestSeries) {
+    let key = AuxStorageKey::DailySeries(series.series_id);
+    persistent_set(env, &key, series);
+}
+
+/// Get the cached health score of a quest
+pub fn get_quest_health_score(env: &Env, quest_id: &Symbol) -> Option<u32> {
+    let key = AuxStorageKey::QuestHealthScore(quest_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Cache the health score of a quest
+pub fn set_quest_health_score(env: &Env, quest_id: &Symbol, score: u32) {
+    let key = AuxStorageKey::QuestHealthScore(quest_id.clone());
+    persistent_set(env, &key, &score);
+}
+
+/// Get the global per-creator quest creation limit, if set
+pub fn get_max_quests_per_creator(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::MaxQuestsPerCreator)
+}
+
+/// Store the global per-creator quest creation limit
+pub fn set_max_quests_per_creator(env: &Env, limit: u32) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::MaxQuestsPerCreator, &limit);
+}
+
+/// Get a creator's quest creation limit override, if any
+pub fn get_creator_limit_override(env: &Env, creator: &Address) -> Option<u32> {
+    let key = AuxStorageKey::CreatorLimitOverride(creator.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store a creator's quest creation limit override
+pub fn set_creator_limit_override(env: &Env, creator: &Address, limit: u32) {
+    let key = AuxStorageKey::CreatorLimitOverride(creator.clone());
+    persistent_set(env, &key, &limit);
+}
+
+/// Get the verifier quality thresholds as (min_reviewed, min_on_time_pct)
+pub fn get_verifier_quality_thresholds(env: &Env) -> (u32, u32) {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::VerifierQualityThresholds)
+        .unwrap_or((0, 0))
+}
+
+/// Store the verifier quality thresholds
+pub fn set_verifier_quality_thresholds(env: &Env, min_reviewed: u32, min_on_time_pct: u32) {
+    env.storage().instance().set(
+        &AuxStorageKey::VerifierQualityThresholds,
+        &(min_reviewed, min_on_time_pct),
+    );
+}
+
+/// Check if a verifier is exempt from the quality thresholds
+pub fn is_verifier_exempt(env: &Env, verifier: &Address) -> bool {
+    let key = AuxStorageKey::VerifierExempt(verifier.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Exempt a verifier from the quality thresholds
+pub fn set_verifier_exempt(env: &Env, verifier: &Address) {
+    let key = AuxStorageKey::VerifierExempt(verifier.clone());
+    persistent_set(env, &key, &true);
+}
+
+/// Get the number of quests a creator has created
+pub fn get_creator_quest_count(env: &Env, creator: &Address) -> u32 {
+    let key = AuxStorageKey::CreatorQuestCount(creator.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store the number of quests a creator has created
+pub fn set_creator_quest_count(env: &Env, creator: &Address, count: u32) {
+    let key = AuxStorageKey::CreatorQuestCount(creator.clone());
+    persistent_set(env, &key, &count);
+}
+
+/// Get the IDs of the quests a creator has created, in creation order
+pub fn get_creator_quests(env: &Env, creator: &Address) -> Vec<Symbol> {
+    let key = AuxStorageKey::CreatorQuests(creator.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a quest ID to a creator's quests
+pub fn add_creator_quest(env: &Env, creator: &Address, quest_id: &Symbol) {
+    let mut ids = get_creator_quests(env, creator);
+    ids.push_back(quest_id.clone());
+    let key = AuxStorageKey::CreatorQuests(creator.clone());
+    persistent_set(env, &key, &ids);
+}
+
+/// Get a user's rank snapshot for a day, if their XP changed that day
+pub fn get_user_rank_snapshot(env: &Env, user: &Address, day: u64) -> Option<Option<u32>> {
+    let key = AuxStorageKey::UserRankSnapshot(user.clone(), day);
+    env.storage().persistent().get(&key)
+}
+
+/// Store a user's rank snapshot for a day
+pub fn set_user_rank_snapshot(env: &Env, user: &Address, day: u64, rank: Option<u32>) {
+    let key = AuxStorageKey::UserRankSnapshot(user.clone(), day);
+    persistent_set(env, &key, &rank);
+}
+
+/// Get the number of verification templates created
+pub fn get_template_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::TemplateCount)
+        .unwrap_or(0)
+}
+
+/// Store the number of verification templates created
+pub fn set_template_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::TemplateCount, &count);
+}
+
+/// Store a verification template
+pub fn set_template(env: &Env, template: &VerificationTemplate) {
+    let key = AuxStorageKey::VerificationTemplate(template.id);
+    persistent_set(env, &key, template);
+}
+
+/// Get a verification template
+pub fn get_template(env: &Env, template_id: u32) -> Option<VerificationTemplate> {
+    let key = AuxStorageKey::VerificationTemplate(template_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Get the IDs of the templates a verifier has published
+pub fn get_verifier_templates(env: &Env, verifier: &Address) -> Vec<u32> {
+    let key = AuxStorageKey::VerifierTemplates(verifier.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a template to the verifier's published templates
+pub fn add_verifier_template(env: &Env, verifier: &Address, template_id: u32) {
+    let key = AuxStorageKey::VerifierTemplates(verifier.clone());
+    let mut templates = get_verifier_templates(env, verifier);
+    templates.push_back(template_id);
+    persistent_set(env, &key, &templates);
+}
+
+/// Get the curated platform badges by symbol
+pub fn get_platform_badges(env: &Env) -> Map<Symbol, PlatformBadge> {
+    env.storage()
+        .persistent()
+        .get(&AuxStorageKey::PlatformBadges)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Store the curated platform badges
+pub fn set_platform_badges(env: &Env, badges: &Map<Symbol, PlatformBadge>) {
+    persistent_set(env, &AuxStorageKey::PlatformBadges, badges);
+}
+
+/// Get the pending admin rotation as (new_admin, effective_at)
+pub fn get_pending_admin_rotation(env: &Env) -> Option<(Address, u64)> {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::PendingAdminRotation)
+}
+
+/// Store the pending admin rotation
+pub fn set_pending_admin_rotation(env: &Env, new_admin: &Address, effective_at: u64) {
+    env.storage().instance().set(
+        &AuxStorageKey::PendingAdminRotation,
+        &(new_admin.clone(), effective_at),
+    );
+}
+
+/// Clear the pending admin rotation
+pub fn remove_pending_admin_rotation(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&AuxStorageKey::PendingAdminRotation);
+}
+
+/// Get the runtime feature toggles
+pub fn get_feature_flags(env: &Env) -> Map<Symbol, bool> {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::FeatureFlags)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Store the runtime feature toggles
+pub fn set_feature_flags(env: &Env, flags: &Map<Symbol, bool>) {
+    env.storage()
+        .instance()
+        .set(&AuxStorageKey::FeatureFlags, flags);
+}
+
+/// Get the XP decay settings as (decay_rate_bps, decay_interval_days)
+pub fn get_xp_decay_config(env: &Env) -> (u32, u32) {
+    env.storage()
+        .instance()
+        .get(&AuxStorageKey::XpDecayConfig)
+        .unwrap_or((0, 0))
+}
+
+/// Store the XP decay settings
+pub fn set_xp_decay_config(env: &Env, decay_rate_bps: u32, decay_interval_days: u32) {
+    env.storage().instance().set(
+        &AuxStorageKey::XpDecayConfig,
+        &(decay_rate_bps, decay_interval_days),
+    );
+}
+
+/// Get the day from which a user's XP decay is counted
+pub fn get_xp_decay_anchor(env: &Env, user: &Address) -> Option<u64> {
+    let key = AuxStorageKey::XpDecayAnchor(user.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Store the day from which a user's XP decay is counted
+pub fn set_xp_decay_anchor(env: &Env, user: &Address, day: u64) {
+    let key = AuxStorageKey::XpDecayAnchor(user.clone());
+    persistent_set(env, &key, &day);
+}
+
+/// Get the IDs of quests sponsored by an address
+pub fn get_sponsored_quests(env: &Env, sponsor: &Address) -> Vec<Symbol> {
+    let key = AuxStorageKey::SponsoredQuests(sponsor.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a quest to a sponsor's index
+pub fn add_sponsored_quest(env: &Env, sponsor: &Address, quest_id: &Symbol) {
+    let mut ids = get_sponsored_quests(env, sponsor);
+    if !ids.contains(quest_id) {
+        ids.push_back(quest_id.clone());
+        let key = AuxStorageKey::SponsoredQuests(sponsor.clone());
+        persistent_set(env, &key, &ids);
+    }
+}
+
+/// Remove a quest from a sponsor's index
+pub fn remove_sponsored_quest(env: &Env, sponsor: &Address, quest_id: &Symbol) {
+    let mut ids = get_sponsored_quests(env, sponsor);
+    if let Some(index) = ids.first_index_of(quest_id) {
+        ids.remove(index);
+        let key = AuxStorageKey::SponsoredQuests(sponsor.clone());
+        persistent_set(env, &key, &ids);
+    }
+}
@@ -1,20 +1,132 @@
+use crate::admin;
+use crate::analytics;
 use crate::errors::Error;
 use crate::quest;
+use crate::ratelimit;
+use crate::reputation;
+use crate::rewards;
 use crate::storage;
 use crate::types::{Submission, SubmissionStatus};
-use soroban_sdk::{Address, BytesN, Env, Symbol};
+use crate::validation;
+use crate::verification;
+use crate::BASE_QUEST_XP;
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
 
 /// Submit proof of quest completion
 /// Validates that the quest exists, is active, hasn't expired, and user hasn't already submitted
+/// If the quest has a whitelist root, `merkle_proof` must prove the submitter is on it
+/// If `inline_document` is given, `proof_hash` must be its sha256; only the hash is stored
 pub fn submit_proof(
     env: &Env,
     quest_id: Symbol,
     submitter: Address,
     proof_hash: BytesN<32>,
+    merkle_proof: Option<Vec<BytesN<32>>>,
+    inline_document: Option<Bytes>,
 ) -> Result<(), Error> {
     // Verify submitter authorization
     submitter.require_auth();
 
+    // Verify the inline proof document against the claimed hash
+    if let Some(document) = inline_document {
+        admin::require_feature_enabled(env, &admin::FLAG_INLINE_DOCUMENTS)?;
+        if !hash_and_verify_proof_document(env, &document, &proof_hash) {
+            return Err(Error::InvalidProofHash);
+        }
+    }
+
+    record_submission(env, quest_id, submitter, proof_hash, merkle_proof)
+}
+
+/// Maximum size in bytes of a proof document submitted inline
+pub const MAX_INLINE_DOCUMENT_LEN: u32 = 1024;
+
+/// Check that an inline proof document of at most `MAX_INLINE_DOCUMENT_LEN` bytes
+/// hashes to `claimed_hash` with sha256
+pub fn hash_and_verify_proof_document(
+    env: &Env,
+    document: &Bytes,
+    claimed_hash: &BytesN<32>,
+) -> bool {
+    if document.len() > MAX_INLINE_DOCUMENT_LEN {
+        return false;
+    }
+    env.crypto().sha256(document).to_bytes() == *claimed_hash
+}
+
+/// Submit proof on behalf of a user who signed a permit with their registered key.
+/// Lets a relayer pay for the transaction when the user's wallet can't sign Soroban auth.
+pub fn submit_proof_with_permit(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    proof_hash: BytesN<32>,
+    permit_signature: BytesN<64>,
+    permit_expiry: u64,
+) -> Result<(), Error> {
+    // Validate permit expiry
+    if env.ledger().timestamp() >= permit_expiry {
+        return Err(Error::PermitExpired);
+    }
+
+    let public_key = storage::get_permit_key(env, &submitter).ok_or(Error::Unauthorized)?;
+
+    // Prevent replay
+    let message = permit_message(env, &quest_id, &submitter, &proof_hash, permit_expiry);
+    if storage::is_permit_used(env, &message) {
+        return Err(Error::PermitAlreadyUsed);
+    }
+
+    // Verify signature (traps if invalid)
+    env.crypto()
+        .ed25519_verify(&public_key, &message.clone().into(), &permit_signature);
+    storage::mark_permit_used(env, &message);
+
+    record_submission(env, quest_id, submitter, proof_hash, None)
+}
+
+/// Register the ed25519 key a user signs submission permits with
+pub fn register_permit_key(env: &Env, user: &Address, public_key: BytesN<32>) {
+    // Verify user authorization
+    user.require_auth();
+
+    storage::set_permit_key(env, user, &public_key);
+}
+
+/// Message a permit signs: sha256(quest_id || submitter || proof_hash || permit_expiry),
+/// with the symbol and address XDR-encoded and the expiry big-endian
+pub fn permit_message(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    proof_hash: &BytesN<32>,
+    permit_expiry: u64,
+) -> BytesN<32> {
+    let mut data = quest_id.clone().to_xdr(env);
+    data.append(&submitter.clone().to_xdr(env));
+    data.append(&Bytes::from_array(env, &proof_hash.to_array()));
+    data.append(&Bytes::from_array(env, &permit_expiry.to_be_bytes()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Record a submission once the submitter's consent has been established
+fn record_submission(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    proof_hash: BytesN<32>,
+    merkle_proof: Option<Vec<BytesN<32>>>,
+) -> Result<(), Error> {
+    // Users in the fraud registry are permanently banned
+    if storage::is_fraudulent(env, &submitter) {
+        return Err(Error::UserBanned);
+    }
+
+    // Frozen accounts cannot submit until the freeze is lifted
+    if storage::is_frozen(env, &submitter) {
+        return Err(Error::UserFrozen);
+    }
+
     // Get quest
     let mut quest = storage::get_quest(env, &quest_id).ok_or(Error::QuestNotFound)?;
 
@@ -24,11 +136,22 @@ pub fn submit_proof(
     // Validate quest is active and accepting submissions
     quest::validate_quest_active(env, &quest)?;
 
+    // Verify whitelist membership
+    if let Some(root) = storage::get_whitelist_root(env, &quest_id) {
+        let merkle_proof = merkle_proof.unwrap_or_else(|| Vec::new(env));
+        if !validation::verify_merkle_proof(env, &root, &submitter, &merkle_proof) {
+            return Err(Error::NotWhitelisted);
+        }
+    }
+
     // Check if submission already exists
     if storage::has_submission(env, &quest_id, &submitter) {
         return Err(Error::SubmissionAlreadyExists);
     }
 
+    // Enforce the global per-user daily submission limit
+    ratelimit::check_and_increment_daily_submissions(env, &submitter)?;
+
     // Create submission
     let submission = Submission {
         quest_id: quest_id.clone(),
@@ -36,10 +159,35 @@ pub fn submit_proof(
         proof_hash,
         status: SubmissionStatus::Pending,
         timestamp: env.ledger().timestamp(),
+        live_until_ledger: 0,
+        verifier_address: quest.verifier.clone(),
+        partial_approvals: Vec::new(env),
+        score: None,
+        reviewing_verifier: None,
+        review_deadline: 0,
     };
 
-    // Store submission
+    // Store submission; resubmissions are already in the submitter indexes
+    let is_resubmission = !storage::get_rejected_submissions(env, &quest_id, &submitter).is_empty();
     storage::set_submission(env, &submission);
+    if !is_resubmission {
+        storage::add_quest_submitter(env, &quest_id, &submitter);
+        storage::add_user_submission(env, &submitter, &quest_id);
+    }
+    storage::add_submission_to_ledger_index(env, env.ledger().sequence(), &quest_id, &submitter);
+    storage::add_quest_proof_hash(env, &quest_id, &submission.proof_hash, &submitter);
+    storage::increment_global_pending(env);
+    storage::increment_quest_pending(env, &quest_id);
+    storage::add_verifier_pending(env, &quest.verifier, &quest_id, &submitter);
+
+    let mut platform = storage::get_platform_stats(env);
+    platform.total_submissions += 1;
+    storage::set_platform_stats(env, &platform);
+
+    // Record submission in daily analytics
+    analytics::record_daily_submission(env, &quest_id);
+    analytics::record_user_activity(env, &submitter);
+    reputation::record_activity(env, &submitter)?;
 
     // Emit event
     env.events()
@@ -48,56 +196,192 @@ pub fn submit_proof(
     Ok(())
 }
 
-/// Approve a submission and increment claim counter
+/// Submit proof for a quest along with a URI pointing at the proof
+pub fn submit_proof_with_uri(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    proof_hash: BytesN<32>,
+    proof_uri: Bytes,
+) -> Result<(), Error> {
+    // Validate URI before recording anything
+    validation::validate_proof_uri_reachability_hint(env, &proof_uri)?;
+
+    submit_proof(
+        env,
+        quest_id.clone(),
+        submitter.clone(),
+        proof_hash,
+        None,
+        None,
+    )?;
+    storage::set_proof_uri(env, &quest_id, &submitter, &proof_uri);
+
+    Ok(())
+}
+
+/// Approve a submission and increment claim counter.
+/// On dual-approval quests the verifier and the creator must both approve; the first
+/// approval is only recorded. Returns `true` once the submission is approved.
 pub fn approve_submission(
     env: &Env,
     quest_id: &Symbol,
     submitter: &Address,
     verifier: &Address,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
     // Verify verifier authorization
     verifier.require_auth();
 
     // Get quest
     let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
 
-    // Verify caller is the designated verifier
-    if quest.verifier != *verifier {
+    // Verify caller is the designated verifier, or the creator on dual-approval quests
+    let is_creator_vote = quest.dual_approval_required && quest.creator == *verifier;
+    if quest.verifier != *verifier && !is_creator_vote {
         return Err(Error::Unauthorized);
     }
 
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
     // Get submission
     let mut submission =
         storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
 
+    // Check submission is awaiting review, and not claimed by another verifier
+    if !submission.status.is_awaiting_review() {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+    verification::ensure_review_claimed_by(&submission, verifier)?;
+
+    // Check if quest is full (race condition protection)
+    if quest::is_quest_full(&quest) {
+        return Err(Error::QuestFull);
+    }
+
+    // Wait for the second party on dual-approval quests
+    if quest.dual_approval_required
+        && !verification::require_multi_sig_approval(env, &quest, &mut submission, verifier)?
+    {
+        return Ok(false);
+    }
+
+    // Update submission status
+    storage::atomic_swap_submission_status(
+        env,
+        quest_id,
+        submitter,
+        submission.status,
+        SubmissionStatus::Approved,
+    )?;
+    clear_pending(env, &quest.verifier, quest_id, submitter);
+    verification::record_review(env, &quest.verifier, true);
+
+    let mut platform = storage::get_platform_stats(env);
+    platform.total_approved += 1;
+    storage::set_platform_stats(env, &platform);
+
+    let mut counts = storage::submission_status_counts(env, quest_id);
+    counts.approved += 1;
+    storage::set_submission_status_counts(env, quest_id, &counts);
+    analytics::record_daily_approval(env, quest_id);
+
+    // Increment total claims counter
+    quest::record_claim(env, &mut quest);
+
+    // Auto-complete quest if limit reached
+    quest::auto_complete_quest_if_full(env, &mut quest);
+
+    quest::record_review_time(env, quest_id, &quest.verifier, submission.timestamp);
+
+    // Emit event; auto-payout quests publish a combined event once paid
+    if !quest.auto_payout {
+        env.events().publish(
+            (Symbol::new(env, "approved"), quest_id.clone()),
+            submitter.clone(),
+        );
+    }
+    if quest.dual_approval_required {
+        env.events().publish(
+            (Symbol::new(env, "full_approval_granted"), quest_id.clone()),
+            (submitter.clone(), submission.partial_approvals),
+        );
+    }
+
+    Ok(true)
+}
+
+/// Auto-approve a pending submission once the quest's `auto_approve_after` window has passed.
+/// Callable by anyone (e.g. keeper bots). Returns `true` if the submission was approved.
+/// The reward stays claimable through `claim_all_paid`.
+pub fn try_auto_approve(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<bool, Error> {
+    // Get quest and submission
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    quest::ensure_not_archived(&quest)?;
+    let submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
     // Check submission is pending
     if submission.status != SubmissionStatus::Pending {
         return Err(Error::InvalidSubmissionStatus);
     }
 
+    // Auto-approve disabled for this quest, or sign-off from both parties required
+    if quest.auto_approve_after == 0 || quest.dual_approval_required {
+        return Ok(false);
+    }
+
+    let current_time = env.ledger().timestamp();
+    if current_time
+        < submission
+            .timestamp
+            .saturating_add(quest.auto_approve_after)
+    {
+        return Ok(false);
+    }
+
     // Check if quest is full (race condition protection)
     if quest::is_quest_full(&quest) {
         return Err(Error::QuestFull);
     }
 
     // Update submission status
-    submission.status = SubmissionStatus::Approved;
-    storage::set_submission(env, &submission);
+    storage::atomic_swap_submission_status(
+        env,
+        quest_id,
+        submitter,
+        SubmissionStatus::Pending,
+        SubmissionStatus::AutoApproved,
+    )?;
+    clear_pending(env, &quest.verifier, quest_id, submitter);
+
+    let mut platform = storage::get_platform_stats(env);
+    platform.total_approved += 1;
+    storage::set_platform_stats(env, &platform);
+
+    let mut counts = storage::submission_status_counts(env, quest_id);
+    counts.auto_approved += 1;
+    storage::set_submission_status_counts(env, quest_id, &counts);
+    analytics::record_daily_approval(env, quest_id);
+
+    // Award XP to user, scaled by quest difficulty
+    let xp = BASE_QUEST_XP * quest.difficulty.xp_multiplier();
+    reputation::award_xp(env, submitter, xp)?;
+    reputation::record_difficulty_xp(env, submitter, quest.difficulty, xp);
 
     // Increment total claims counter
-    quest.total_claims += 1;
-    storage::set_quest(env, &quest);
+    quest::record_claim(env, &mut quest);
 
     // Auto-complete quest if limit reached
     quest::auto_complete_quest_if_full(env, &mut quest);
 
     // Emit event
     env.events().publish(
-        (Symbol::new(env, "approved"), quest_id.clone()),
+        (Symbol::new(env, "auto_approved"), quest_id.clone()),
         submitter.clone(),
     );
 
-    Ok(())
+    Ok(true)
 }
 
 /// Reject a submission
@@ -118,24 +402,368 @@ pub fn reject_submission(
         return Err(Error::Unauthorized);
     }
 
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
     // Get submission
     let mut submission =
         storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
 
+    // Check submission is awaiting review, and not claimed by another verifier
+    if !submission.status.is_awaiting_review() {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+    verification::ensure_review_claimed_by(&submission, verifier)?;
+
+    apply_rejection(env, verifier, &mut submission, SubmissionStatus::Rejected);
+    verification::record_review(env, verifier, false);
+    quest::record_review_time(env, quest_id, verifier, submission.timestamp);
+    quest::refresh_health_score(env, quest_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "rejected"), quest_id.clone()),
+        submitter.clone(),
+    );
+
+    Ok(())
+}
+
+/// Reason code reserved for rejections caused by verifier timeout
+pub const REJECTION_REASON_TIMEOUT: u32 = 0;
+
+/// Auto-reject a pending submission once the quest's `auto_reject_after` window has passed.
+/// Callable by anyone (e.g. keeper bots). Returns `true` if the submission was rejected.
+pub fn try_auto_reject(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<bool, Error> {
+    // Get quest and submission
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    quest::ensure_not_archived(&quest)?;
+    let mut submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
     // Check submission is pending
     if submission.status != SubmissionStatus::Pending {
         return Err(Error::InvalidSubmissionStatus);
     }
 
+    // Auto-reject disabled for this quest
+    if quest.auto_reject_after == 0 {
+        return Ok(false);
+    }
+
+    let current_time = env.ledger().timestamp();
+    if current_time < submission.timestamp.saturating_add(quest.auto_reject_after) {
+        return Ok(false);
+    }
+
+    // Update submission status, then move it into the rejection history
+    storage::atomic_swap_submission_status(
+        env,
+        quest_id,
+        submitter,
+        SubmissionStatus::Pending,
+        SubmissionStatus::AutoRejected,
+    )?;
+    apply_rejection(
+        env,
+        &quest.verifier,
+        &mut submission,
+        SubmissionStatus::AutoRejected,
+    );
+    verification::record_timeout(env, &quest.verifier);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "auto_rejected"), quest_id.clone()),
+        (submitter.clone(), REJECTION_REASON_TIMEOUT),
+    );
+
+    Ok(true)
+}
+
+/// XP removed from a submitter whose submission is marked fraudulent
+pub const FRAUD_REP_PENALTY: u32 = 500;
+
+/// Mark a submission as fraudulent (verifier with admin co-signature).
+/// Penalizes the submitter's XP and permanently bans them via the fraud registry.
+pub fn mark_fraudulent(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    verifier: &Address,
+    admin: &Address,
+) -> Result<(), Error> {
+    // Verify verifier and admin authorization
+    verifier.require_auth();
+    admin::require_admin(env, admin)?;
+
+    // Get quest
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the designated verifier
+    if quest.verifier != *verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
+    // Get submission
+    let mut submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    if submission.status == SubmissionStatus::Fraudulent {
+        return Err(Error::SubmissionAlreadyProcessed);
+    }
+
     // Update submission status
-    submission.status = SubmissionStatus::Rejected;
+    if submission.status.is_awaiting_review() {
+        clear_pending(env, verifier, quest_id, submitter);
+    }
+    submission.status = SubmissionStatus::Fraudulent;
     storage::set_submission(env, &submission);
 
+    // Penalize and ban the submitter
+    let penalty = reputation::apply_xp_penalty(env, submitter, FRAUD_REP_PENALTY);
+    storage::add_fraudulent(env, submitter);
+
     // Emit event
     env.events().publish(
-        (Symbol::new(env, "rejected"), quest_id.clone()),
-        submitter.clone(),
+        (Symbol::new(env, "fraud_detected"), quest_id.clone()),
+        (submitter.clone(), verifier.clone(), penalty),
     );
 
     Ok(())
 }
+
+/// Remove a submission leaving `Pending` from the pending counters and its verifier's queue
+fn clear_pending(env: &Env, verifier: &Address, quest_id: &Symbol, submitter: &Address) {
+    storage::decrement_global_pending(env);
+    storage::decrement_quest_pending(env, quest_id);
+    storage::remove_verifier_pending(env, verifier, quest_id, submitter);
+}
+
+/// Submit a new proof after an earlier submission to the quest was rejected.
+/// The rejected record stays in the rejection history.
+pub fn resubmit_after_rejection(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    proof_hash: BytesN<32>,
+) -> Result<(), Error> {
+    // Verify submitter authorization
+    submitter.require_auth();
+
+    // Only users with a rejected submission can resubmit
+    if storage::get_rejected_submissions(env, &quest_id, &submitter).is_empty() {
+        return Err(Error::SubmissionNotFound);
+    }
+
+    record_submission(env, quest_id, submitter, proof_hash, None)
+}
+
+/// Get a user's rejected submissions to a quest, oldest first
+pub fn get_rejection_history(env: &Env, quest_id: &Symbol, submitter: &Address) -> Vec<Submission> {
+    storage::get_rejected_submissions(env, quest_id, submitter)
+}
+
+/// Get the live submission, or the most recently rejected one if there is none
+pub fn get_latest_submission(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Option<Submission> {
+    storage::get_submission(env, quest_id, submitter)
+        .or_else(|| storage::get_rejected_submissions(env, quest_id, submitter).last())
+}
+
+/// Maximum number of entries returned by `get_all_my_approved_quests`
+pub const MAX_APPROVED_QUEST_RESULTS: u32 = 50;
+
+/// Get (quest_id, claimable_amount, is_vesting) for each of a user's approved but unpaid
+/// submissions, largest amount first, capped to `MAX_APPROVED_QUEST_RESULTS`.
+/// Rewards are paid in full on claim, so `is_vesting` is always false.
+pub fn get_all_my_approved_quests(env: &Env, user: &Address) -> Vec<(Symbol, i128, bool)> {
+    let mut approved: Vec<(Symbol, i128, bool)> = Vec::new(env);
+
+    for quest_id in storage::get_user_submissions(env, user).iter() {
+        let Some(submission) = storage::get_submission(env, &quest_id, user) else {
+            continue;
+        };
+        if !submission.status.is_approved() {
+            continue;
+        }
+        let Some(quest) = storage::get_quest(env, &quest_id) else {
+            continue;
+        };
+        let amount = rewards::submission_reward(&quest, &submission);
+
+        // Insert at the sorted position
+        let index = approved
+            .iter()
+            .position(|(_, a, _)| a < amount)
+            .map(|i| i as u32)
+            .unwrap_or(approved.len());
+        approved.insert(index, (quest_id, amount, false));
+    }
+
+    let mut top = Vec::new(env);
+    for entry in approved.iter().take(MAX_APPROVED_QUEST_RESULTS as usize) {
+        top.push_back(entry);
+    }
+    top
+}
+
+/// Maximum number of proof hash mappings returned per page
+pub const MAX_PROOF_HASH_PAGE: u32 = 50;
+
+/// Get the first address that submitted a proof hash to a quest
+pub fn get_submitter_for_proof_hash(
+    env: &Env,
+    quest_id: &Symbol,
+    proof_hash: &BytesN<32>,
+) -> Option<Address> {
+    storage::get_quest_proof_hashes(env, quest_id).get(proof_hash.clone())
+}
+
+/// Get the seconds elapsed since a proof hash was submitted to a quest by its first submitter,
+/// or `None` if the hash was never submitted
+pub fn get_proof_hash_age(env: &Env, quest_id: &Symbol, proof_hash: &BytesN<32>) -> Option<u64> {
+    let submitter = get_submitter_for_proof_hash(env, quest_id, proof_hash)?;
+    let submission = storage::get_submission(env, quest_id, &submitter)?;
+    Some(
+        env.ledger()
+            .timestamp()
+            .saturating_sub(submission.timestamp),
+    )
+}
+
+/// Get a page of (proof_hash, first_submitter) mappings for a quest, ordered by hash.
+/// `limit` is capped to `MAX_PROOF_HASH_PAGE`.
+pub fn get_all_proof_hashes(
+    env: &Env,
+    quest_id: &Symbol,
+    offset: u32,
+    limit: u32,
+) -> Vec<(BytesN<32>, Address)> {
+    let mut page = Vec::new(env);
+    let limit = limit.min(MAX_PROOF_HASH_PAGE);
+    for entry in storage::get_quest_proof_hashes(env, quest_id)
+        .iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+    {
+        page.push_back(entry);
+    }
+    page
+}
+
+/// Get the parties that have approved a submission to a dual-approval quest
+pub fn get_partial_approvals(env: &Env, quest_id: &Symbol, submitter: &Address) -> Vec<Address> {
+    storage::get_submission(env, quest_id, submitter)
+        .map(|s| s.partial_approvals)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the quality score a verifier gave a submission, if any
+pub fn get_submission_score(env: &Env, quest_id: &Symbol, submitter: &Address) -> Option<u32> {
+    storage::get_submission(env, quest_id, submitter).and_then(|s| s.score)
+}
+
+/// Transition a pending submission to `Rejected` or `AutoRejected`
+fn apply_rejection(
+    env: &Env,
+    verifier: &Address,
+    submission: &mut Submission,
+    status: SubmissionStatus,
+) {
+    let mut counts = storage::submission_status_counts(env, &submission.quest_id);
+    if status == SubmissionStatus::AutoRejected {
+        counts.auto_rejected += 1;
+    } else {
+        counts.rejected += 1;
+    }
+    storage::set_submission_status_counts(env, &submission.quest_id, &counts);
+
+    // Move the submission out of the live slot so the user can resubmit
+    submission.status = status;
+    storage::remove_submission(env, &submission.quest_id, &submission.submitter);
+    storage::add_rejected_submission(env, submission);
+    clear_pending(env, verifier, &submission.quest_id, &submission.submitter);
+
+    let mut platform = storage::get_platform_stats(env);
+    platform.total_rejected += 1;
+    storage::set_platform_stats(env, &platform);
+}
+
+/// Estimated CPU instructions consumed by approving one submission
+pub const APPROVE_INSTRUCTIONS_PER_ITEM: u64 = 2_000_000;
+
+/// Estimated fixed CPU instructions for invoking a batch of approvals
+pub const BATCH_APPROVE_OVERHEAD: u64 = 500_000;
+
+/// Estimate the CPU instructions needed to approve `count` submissions in one transaction.
+/// The budget API is only available in test builds, so this uses a static per-item cost
+/// calibrated against the test host. It is approximate: actual cost grows with the size of
+/// the indexes an approval updates (leaderboards, pending queues).
+pub fn estimate_gas_for_batch_approve(count: u32) -> u64 {
+    BATCH_APPROVE_OVERHEAD
+        .saturating_add(APPROVE_INSTRUCTIONS_PER_ITEM.saturating_mul(count as u64))
+}
+
+/// Get the current state of every submission created at a ledger sequence
+pub fn get_submissions_at_ledger(env: &Env, sequence: u32) -> Vec<Submission> {
+    let mut submissions = Vec::new(env);
+    for (quest_id, submitter) in storage::get_submission_ledger_index(env, sequence).iter() {
+        if let Some(submission) = get_latest_submission(env, &quest_id, &submitter) {
+            submissions.push_back(submission);
+        }
+    }
+    submissions
+}
+
+/// Get the number of pending submissions across all quests
+pub fn get_global_pending_count(env: &Env) -> u32 {
+    storage::get_global_pending(env)
+}
+
+/// Get the number of pending submissions for a quest
+pub fn get_quest_pending_count(env: &Env, quest_id: &Symbol) -> u32 {
+    storage::get_quest_pending(env, quest_id)
+}
+
+/// Estimate the seconds a new submission to a quest would wait for review, from its
+/// verifier's average review time and queue depth. `None` if the verifier has no reviews.
+pub fn predict_approval_time(env: &Env, quest_id: &Symbol) -> Option<u64> {
+    let quest = storage::get_quest(env, quest_id)?;
+    let (sum, count) = storage::get_verifier_review_times(env, &quest.verifier);
+    let avg_review_time = sum.checked_div(count as u64)?;
+    let pending = storage::get_verifier_pending(env, &quest.verifier).len() as u64;
+    Some(avg_review_time.saturating_mul(pending + 1))
+}
+
+/// Get a submission's 1-based position in its verifier's review queue, oldest first
+pub fn get_queue_position(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<u32, Error> {
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    let submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+    if !submission.status.is_awaiting_review() {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+
+    // Count queued submissions that arrived earlier, or at the same time but queued first
+    let mut position = 1;
+    for (id, queued) in storage::get_verifier_pending(env, &quest.verifier).iter() {
+        if id == *quest_id && queued == *submitter {
+            break;
+        }
+        let earlier = storage::get_submission(env, &id, &queued)
+            .map(|s| s.timestamp <= submission.timestamp)
+            .unwrap_or(false);
+        if earlier {
+            position += 1;
+        }
+    }
+
+    Ok(position)
+}
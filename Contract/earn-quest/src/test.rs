@@ -1,8 +1,46 @@
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Ledger},
+        token::{StellarAssetClient, TokenClient},
+        xdr::ToXdr,
+        Address, Bytes, BytesN, Env, Symbol,
+    };
 
-    use crate::{types::QuestStatus, EarnQuestContract, EarnQuestContractClient};
+    use crate::{
+        errors::Error,
+        storage,
+        types::{
+            BadgeTier, LegacyUserStats, Quest, QuestDifficulty, QuestStatus, QuestV1, RewardMode,
+            SubmissionStatus,
+        },
+        EarnQuestContract, EarnQuestContractClient,
+    };
+
+    /// Force a submission into the given status, bypassing the public flow
+    fn set_submission_status(
+        env: &Env,
+        contract_id: &Address,
+        quest_id: &Symbol,
+        submitter: &Address,
+        status: SubmissionStatus,
+    ) {
+        env.as_contract(contract_id, || {
+            let mut submission = storage::get_submission(env, quest_id, submitter).unwrap();
+            submission.status = status;
+            storage::set_submission(env, &submission);
+        });
+    }
+
+    /// Register a reward token and mint enough of it to `holder` to fund quests
+    fn reward_token(env: &Env, holder: &Address) -> Address {
+        let asset = env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address();
+        StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+        asset
+    }
 
     #[test]
     fn test_register_quest_with_participant_limit() {
@@ -14,7 +52,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
 
         // Register quest with max_participants = 5
         client.register_quest(
@@ -25,6 +63,7 @@ mod tests {
             &verifier,
             &1000000,
             &5,
+            &QuestDifficulty::Beginner,
         );
 
         // Verify quest was created
@@ -43,7 +82,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
 
         // Should fail with InvalidParticipantLimit
         let result = client.try_register_quest(
@@ -54,6 +93,7 @@ mod tests {
             &verifier,
             &1000000,
             &0,
+            &QuestDifficulty::Beginner,
         );
 
         assert!(result.is_err());
@@ -69,7 +109,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
 
         // Register quest with max_participants = 2
         client.register_quest(
@@ -80,6 +120,7 @@ mod tests {
             &verifier,
             &9999999999,
             &2,
+            &QuestDifficulty::Beginner,
         );
 
         // Submit and approve 2 submissions
@@ -109,7 +150,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
 
         // Register quest with max_participants = 1
         client.register_quest(
@@ -120,6 +161,7 @@ mod tests {
             &verifier,
             &9999999999,
             &1,
+            &QuestDifficulty::Beginner,
         );
 
         // Submit and approve 1 submission
@@ -145,7 +187,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
 
         // Register quest
         client.register_quest(
@@ -156,6 +198,7 @@ mod tests {
             &verifier,
             &9999999999,
             &5,
+            &QuestDifficulty::Beginner,
         );
 
         // Verify initial count
@@ -185,7 +228,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
         let submitter = Address::generate(&env);
 
         // Register quest
@@ -197,6 +240,7 @@ mod tests {
             &verifier,
             &9999999999,
             &10,
+            &QuestDifficulty::Beginner,
         );
 
         // Submit and approve
@@ -209,4 +253,1007 @@ mod tests {
         assert_eq!(stats.total_xp, 100);
         assert_eq!(stats.quests_completed, 1);
     }
+
+    #[test]
+    fn test_claim_all_paid() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &reward_asset).mint(&creator, &10_000);
+
+        let submitter = Address::generate(&env);
+        let quests = [
+            symbol_short!("QA"),
+            symbol_short!("QB"),
+            symbol_short!("QC"),
+        ];
+        for quest_id in quests.iter() {
+            client.register_quest(
+                quest_id,
+                &creator,
+                &reward_asset,
+                &100,
+                &verifier,
+                &9999999999,
+                &1,
+                &QuestDifficulty::Beginner,
+            );
+            let proof = BytesN::from_array(&env, &[1u8; 32]);
+            client.submit_proof(quest_id, &submitter, &proof);
+        }
+
+        // Two approved-but-unpaid submissions, one still pending
+        for quest_id in quests[..2].iter() {
+            set_submission_status(
+                &env,
+                &contract_id,
+                quest_id,
+                &submitter,
+                SubmissionStatus::Approved,
+            );
+        }
+        assert_eq!(client.get_claimable_balance(&submitter), 200);
+
+        let total = client.claim_all_paid(&submitter);
+        assert_eq!(total, 200);
+        assert_eq!(client.get_claimable_balance(&submitter), 0);
+        assert_eq!(
+            TokenClient::new(&env, &reward_asset).balance(&submitter),
+            200
+        );
+        assert_eq!(
+            client.get_submission(&quests[0], &submitter).status,
+            SubmissionStatus::Paid
+        );
+        assert_eq!(
+            client.get_submission(&quests[2], &submitter).status,
+            SubmissionStatus::Pending
+        );
+        assert_eq!(client.get_quest_escrow(&quests[0]), 0);
+    }
+
+    #[test]
+    fn test_claim_all_paid_skips_underfunded_quest() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &reward_asset).mint(&creator, &10_000);
+
+        let submitter = Address::generate(&env);
+        let other = Address::generate(&env);
+        let funded = symbol_short!("QFUND");
+        let drained = symbol_short!("QDRAIN");
+        for quest_id in [&funded, &drained] {
+            client.register_quest(
+                quest_id,
+                &creator,
+                &reward_asset,
+                &100,
+                &verifier,
+                &9999999999,
+                &1,
+                &QuestDifficulty::Beginner,
+            );
+        }
+        let proof = BytesN::from_array(&env, &[1u8; 32]);
+        for (quest_id, user) in [
+            (&drained, &other),
+            (&funded, &submitter),
+            (&drained, &submitter),
+        ] {
+            client.submit_proof(quest_id, user, &proof);
+            set_submission_status(
+                &env,
+                &contract_id,
+                quest_id,
+                user,
+                SubmissionStatus::Approved,
+            );
+        }
+
+        // Another winner empties the drained quest's escrow first
+        assert_eq!(client.claim_all_paid(&other), 100);
+        assert_eq!(client.get_quest_escrow(&drained), 0);
+
+        // The funded quest still pays; the drained one stays claimable
+        assert_eq!(client.claim_all_paid(&submitter), 100);
+        assert_eq!(
+            client.get_submission(&funded, &submitter).status,
+            SubmissionStatus::Paid
+        );
+        assert_eq!(
+            client.get_submission(&drained, &submitter).status,
+            SubmissionStatus::Approved
+        );
+        assert_eq!(client.get_claimable_balance(&submitter), 100);
+    }
+
+    #[test]
+    fn test_completion_certificate() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let reward_asset = reward_token(&env, &creator);
+        let submitter = Address::generate(&env);
+        let quest_id = symbol_short!("QCERT");
+
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &100,
+            &verifier,
+            &9999999999,
+            &5,
+            &QuestDifficulty::Beginner,
+        );
+        let proof = BytesN::from_array(&env, &[1u8; 32]);
+        client.submit_proof(&quest_id, &submitter, &proof);
+
+        // Unpaid submissions are not certified
+        assert_eq!(
+            client.try_get_completion_certificate(&quest_id, &submitter),
+            Err(Ok(Error::InvalidSubmissionStatus))
+        );
+
+        set_submission_status(
+            &env,
+            &contract_id,
+            &quest_id,
+            &submitter,
+            SubmissionStatus::Paid,
+        );
+        env.ledger().with_mut(|li| li.timestamp = 5000);
+        let certificate = client.get_completion_certificate(&quest_id, &submitter);
+        assert_eq!(certificate.reward_amount, 100);
+        assert_eq!(certificate.reward_asset, reward_asset);
+        assert_eq!(certificate.verifier, verifier);
+        assert_eq!(certificate.completed_at, 5000);
+
+        let mut data = quest_id.clone().to_xdr(&env);
+        data.append(&submitter.clone().to_xdr(&env));
+        data.append(&Bytes::from_array(&env, &5000u64.to_be_bytes()));
+        let expected: BytesN<32> = env.crypto().sha256(&data).into();
+        assert_eq!(certificate.certificate_hash, expected);
+
+        // The stored certificate never changes
+        env.ledger().with_mut(|li| li.timestamp = 9000);
+        assert_eq!(
+            client.get_completion_certificate(&quest_id, &submitter),
+            certificate
+        );
+    }
+
+    #[test]
+    fn test_sponsored_quest_badge() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let sponsor = Address::generate(&env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &reward_asset).mint(&sponsor, &1_000);
+        StellarAssetClient::new(&env, &reward_asset).mint(&creator, &1_000);
+
+        let quest_id = symbol_short!("QSPON");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &100,
+            &verifier,
+            &9999999999,
+            &5,
+            &QuestDifficulty::Beginner,
+        );
+        assert_eq!(client.get_sponsor(&quest_id), None);
+
+        // The badge name must fit in a symbol alongside its prefix
+        assert_eq!(
+            client.try_set_sponsor(
+                &quest_id,
+                &creator,
+                &sponsor,
+                &Symbol::new(&env, "a_very_long_sponsor_name"),
+            ),
+            Err(Ok(Error::SymbolTooLong))
+        );
+        client.set_sponsor(&quest_id, &creator, &sponsor, &symbol_short!("acme"));
+        assert_eq!(client.get_sponsor(&quest_id), Some(sponsor.clone()));
+        assert_eq!(
+            client.get_sponsored_quests(&sponsor),
+            soroban_sdk::vec![&env, quest_id.clone()]
+        );
+
+        // Only the creator or sponsor can co-fund the reward
+        assert_eq!(client.get_quest_escrow(&quest_id), 500);
+        assert_eq!(
+            client.try_contribute_to_reward(&quest_id, &verifier, &100),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.contribute_to_reward(&quest_id, &sponsor, &100);
+        assert_eq!(client.get_quest_escrow(&quest_id), 600);
+
+        // Approval leaves the reward in escrow; the badge comes with the payout
+        let badge = Symbol::new(&env, "sponsored_by_acme");
+        let approved = Address::generate(&env);
+        client.submit_proof(&quest_id, &approved, &BytesN::from_array(&env, &[1u8; 32]));
+        client.approve_submission(&quest_id, &approved, &verifier);
+        assert_eq!(
+            client.get_submission(&quest_id, &approved).status,
+            SubmissionStatus::Approved
+        );
+        assert_eq!(client.get_badge(&approved, &badge), None);
+        assert_eq!(client.get_claimable_balance(&approved), 100);
+
+        client.claim_all_paid(&approved);
+        let awarded = client.get_badge(&approved, &badge).unwrap();
+        assert_eq!(awarded.quest_id, quest_id);
+        assert_eq!(awarded.tier, BadgeTier::Bronze);
+        assert_eq!(client.get_quest_escrow(&quest_id), 500);
+    }
+
+    #[test]
+    fn test_referral_bonus_updates_leaderboard() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &reward_asset).mint(&creator, &10_000);
+
+        let referrer = Address::generate(&env);
+        let quest_id = symbol_short!("QREF");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &1000,
+            &verifier,
+            &9999999999,
+            &2,
+            &QuestDifficulty::Beginner,
+        );
+
+        // Self-referral and re-registration are rejected
+        let submitter = Address::generate(&env);
+        assert!(client
+            .try_register_referrer(&submitter, &submitter)
+            .is_err());
+        client.register_referrer(&submitter, &referrer);
+        assert!(client
+            .try_register_referrer(&submitter, &Address::generate(&env))
+            .is_err());
+        assert_eq!(client.get_referrer(&submitter), Some(referrer.clone()));
+
+        let proof = BytesN::from_array(&env, &[1u8; 32]);
+        client.submit_proof(&quest_id, &submitter, &proof);
+        set_submission_status(
+            &env,
+            &contract_id,
+            &quest_id,
+            &submitter,
+            SubmissionStatus::Approved,
+        );
+        client.claim_all_paid(&submitter);
+
+        // Without a funded referral pool the referral is counted but no bonus is paid,
+        // and the quest escrow only covers rewards
+        let token = TokenClient::new(&env, &reward_asset);
+        assert_eq!(token.balance(&submitter), 1000);
+        assert_eq!(token.balance(&referrer), 0);
+        assert_eq!(client.get_quest_escrow(&quest_id), 1000);
+        assert_eq!(client.get_referral_count(&referrer), 1);
+        assert_eq!(client.get_referral_leaderboard(&10).len(), 0);
+
+        // Referrer receives 5% of the reward out of the referral pool
+        client.fund_referral_pool(&creator, &reward_asset, &80);
+        let second = Address::generate(&env);
+        client.register_referrer(&second, &referrer);
+        client.submit_proof(&quest_id, &second, &proof);
+        set_submission_status(
+            &env,
+            &contract_id,
+            &quest_id,
+            &second,
+            SubmissionStatus::Approved,
+        );
+        client.claim_all_paid(&second);
+        assert_eq!(token.balance(&referrer), 50);
+        assert_eq!(client.get_referral_pool(&reward_asset), 30);
+        assert_eq!(client.get_quest_escrow(&quest_id), 0);
+        assert_eq!(client.get_orphaned_balance(&reward_asset), 0);
+
+        assert_eq!(client.get_referral_count(&referrer), 2);
+        let board = client.get_referral_leaderboard(&10);
+        assert_eq!(board.len(), 1);
+        assert_eq!(board.get(0).unwrap(), (referrer.clone(), 50, 2));
+
+        // The first referral is a default XP milestone
+        assert_eq!(client.get_user_stats(&referrer).total_xp, 10);
+    }
+
+    #[test]
+    fn test_referral_milestone_xp() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &reward_asset).mint(&creator, &10_000);
+        client.initialize(&admin);
+
+        // Milestone counts must be strictly increasing
+        assert_eq!(
+            client
+                .try_set_referral_milestone_xp(&admin, &soroban_sdk::vec![&env, (2, 70), (1, 30)]),
+            Err(Ok(Error::InvalidConfig))
+        );
+        let milestones = soroban_sdk::vec![&env, (1, 30), (3, 70)];
+        client.set_referral_milestone_xp(&admin, &milestones);
+        assert_eq!(client.get_referral_milestone_xp(), milestones);
+
+        let quest_id = symbol_short!("QREF");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &1000,
+            &verifier,
+            &9999999999,
+            &4,
+            &QuestDifficulty::Beginner,
+        );
+
+        let referrer = Address::generate(&env);
+        let mut expected_xp = [30, 30, 100].iter();
+        for i in 0..3u8 {
+            let submitter = Address::generate(&env);
+            client.register_referrer(&submitter, &referrer);
+            client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[i; 32]));
+            set_submission_status(
+                &env,
+                &contract_id,
+                &quest_id,
+                &submitter,
+                SubmissionStatus::Approved,
+            );
+            client.claim_all_paid(&submitter);
+            assert_eq!(
+                client.get_user_stats(&referrer).total_xp,
+                *expected_xp.next().unwrap()
+            );
+        }
+    }
+
+    /// Register a quest and approve one submission so the submitter has stats
+    fn setup_user_with_stats<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address) {
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let creator = Address::generate(env);
+        let verifier = Address::generate(env);
+        let reward_asset = reward_token(env, &creator);
+        let submitter = Address::generate(env);
+        client.initialize(&admin);
+
+        client.register_quest(
+            &symbol_short!("QBADGE"),
+            &creator,
+            &reward_asset,
+            &1000,
+            &verifier,
+            &9999999999,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+        let proof = BytesN::from_array(env, &[1u8; 32]);
+        client.submit_proof(&symbol_short!("QBADGE"), &submitter, &proof);
+        client.approve_submission(&symbol_short!("QBADGE"), &submitter, &verifier);
+
+        (client, admin, submitter)
+    }
+
+    #[test]
+    fn test_platform_badge_supply() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, user) = setup_user_with_stats(&env);
+        let other = Address::generate(&env);
+        env.as_contract(&client.address, || {
+            storage::set_user_stats(&env, &crate::reputation::get_or_init_stats(&env, &other));
+        });
+
+        let symbol = symbol_short!("genesis");
+        let description = BytesN::from_array(&env, &[9u8; 32]);
+        assert!(client
+            .try_create_platform_badge(&user, &symbol, &description, &1)
+            .is_err());
+        client.create_platform_badge(&admin, &symbol, &description, &1);
+        assert_eq!(
+            client.list_platform_badges(),
+            soroban_sdk::vec![&env, symbol.clone()]
+        );
+
+        // Only the admin can grant, so no one else can use up the supply
+        assert_eq!(
+            client.try_grant_badge(
+                &other,
+                &symbol,
+                &symbol_short!("QBADGE"),
+                &BadgeTier::Gold,
+                &other,
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(client.get_platform_badge(&symbol).unwrap().issued, 0);
+
+        // Regranting to a holder does not use up supply
+        for _ in 0..2 {
+            client.grant_badge(
+                &user,
+                &symbol,
+                &symbol_short!("QBADGE"),
+                &BadgeTier::Gold,
+                &admin,
+            );
+        }
+        assert_eq!(client.get_platform_badge(&symbol).unwrap().issued, 1);
+        assert_eq!(
+            client.try_grant_badge(
+                &other,
+                &symbol,
+                &symbol_short!("QBADGE"),
+                &BadgeTier::Gold,
+                &admin,
+            ),
+            Err(Ok(Error::BadgeSupplyExhausted))
+        );
+
+        // Supply can be raised but not lowered below the issued count
+        assert_eq!(
+            client.try_create_platform_badge(&admin, &symbol, &description, &0),
+            Err(Ok(Error::BadgeSupplyExhausted))
+        );
+        client.create_platform_badge(&admin, &symbol, &description, &2);
+        client.grant_badge(
+            &other,
+            &symbol,
+            &symbol_short!("QBADGE"),
+            &BadgeTier::Gold,
+            &admin,
+        );
+        let badge = client.get_platform_badge(&symbol).unwrap();
+        assert_eq!((badge.issued, badge.max_supply), (2, 2));
+        assert_eq!(client.get_platform_badge(&symbol_short!("none")), None);
+    }
+
+    #[test]
+    fn test_grant_badge_with_metadata() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, user) = setup_user_with_stats(&env);
+
+        client.grant_badge(
+            &user,
+            &symbol_short!("first"),
+            &symbol_short!("QBADGE"),
+            &BadgeTier::Gold,
+            &admin,
+        );
+        client.grant_badge(
+            &user,
+            &symbol_short!("early"),
+            &symbol_short!("QBADGE"),
+            &BadgeTier::Bronze,
+            &admin,
+        );
+        // Granting the same badge again is a no-op
+        client.grant_badge(
+            &user,
+            &symbol_short!("first"),
+            &symbol_short!("QBADGE"),
+            &BadgeTier::Silver,
+            &admin,
+        );
+
+        let badge = client.get_badge(&user, &symbol_short!("first")).unwrap();
+        assert_eq!(badge.quest_id, symbol_short!("QBADGE"));
+        assert_eq!(badge.tier, BadgeTier::Gold);
+        assert_eq!(client.get_badge(&user, &symbol_short!("none")), None);
+
+        assert_eq!(client.get_user_stats(&user).badges.len(), 2);
+        assert_eq!(client.get_badges_by_tier(&user, &BadgeTier::Gold).len(), 1);
+        assert_eq!(
+            client.get_badges_by_tier(&user, &BadgeTier::Bronze).len(),
+            1
+        );
+        assert_eq!(
+            client.get_badges_by_tier(&user, &BadgeTier::Platinum).len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_migrate_legacy_badges() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, user) = setup_user_with_stats(&env);
+        let untouched = Address::generate(&env);
+
+        // Overwrite the user's stats with the legacy layout
+        env.as_contract(&client.address, || {
+            let mut badges = soroban_sdk::Vec::new(&env);
+            badges.push_back(symbol_short!("old1"));
+            badges.push_back(symbol_short!("old2"));
+            let legacy = LegacyUserStats {
+                address: user.clone(),
+                total_xp: 100,
+                level: 1,
+                quests_completed: 1,
+                badges,
+            };
+            env.storage()
+                .persistent()
+                .set(&storage::StorageKey::UserStats(user.clone()), &legacy);
+        });
+
+        let users = soroban_sdk::vec![&env, user.clone(), untouched];
+        assert_eq!(client.migrate_badges(&admin, &users), 1);
+
+        let stats = client.get_user_stats(&user);
+        assert_eq!(stats.total_xp, 100);
+        assert_eq!(stats.badges.len(), 2);
+        let badge = client.get_badge(&user, &symbol_short!("old2")).unwrap();
+        assert_eq!(badge.tier, BadgeTier::Bronze);
+        assert_eq!(badge.awarded_at, 0);
+        assert_eq!(badge.quest_id, Symbol::new(&env, "legacy"));
+
+        // Already-migrated stats are skipped
+        assert_eq!(
+            client.migrate_badges(&admin, &soroban_sdk::vec![&env, user]),
+            0
+        );
+    }
+
+    #[test]
+    fn test_atomic_swap_submission_status() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let submitter = Address::generate(&env);
+        let quest_id = symbol_short!("CAS");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(&env, &creator),
+            &100,
+            &verifier,
+            &9999999999,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+        client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+
+        env.as_contract(&contract_id, || {
+            // A stale expectation leaves the submission untouched
+            assert_eq!(
+                storage::atomic_swap_submission_status(
+                    &env,
+                    &quest_id,
+                    &submitter,
+                    SubmissionStatus::Approved,
+                    SubmissionStatus::Paid,
+                ),
+                Err(Error::StatusMismatch)
+            );
+
+            assert_eq!(
+                storage::atomic_swap_submission_status(
+                    &env,
+                    &quest_id,
+                    &submitter,
+                    SubmissionStatus::Pending,
+                    SubmissionStatus::Approved,
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                storage::atomic_swap_submission_status(
+                    &env,
+                    &symbol_short!("none"),
+                    &submitter,
+                    SubmissionStatus::Pending,
+                    SubmissionStatus::Approved,
+                ),
+                Err(Error::SubmissionNotFound)
+            );
+        });
+
+        assert_eq!(
+            client.get_submission(&quest_id, &submitter).status,
+            SubmissionStatus::Approved
+        );
+    }
+
+    #[test]
+    fn test_score_based_rewards() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &reward_asset).mint(&creator, &10_000);
+
+        let quest_id = symbol_short!("SCORED");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &100,
+            &verifier,
+            &9999999999,
+            &2,
+            &QuestDifficulty::Beginner,
+        );
+        client.set_reward_mode(&quest_id, &creator, &RewardMode::ScoreBased);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        for submitter in [&alice, &bob] {
+            client.submit_proof(&quest_id, submitter, &BytesN::from_array(&env, &[1u8; 32]));
+        }
+        assert_eq!(client.get_average_submission_score(&quest_id), None);
+
+        // Scores must be within 1-100 and come from the verifier
+        assert_eq!(
+            client.try_score_submission(&quest_id, &alice, &verifier, &0),
+            Err(Ok(Error::InvalidScore))
+        );
+        assert_eq!(
+            client.try_score_submission(&quest_id, &alice, &verifier, &101),
+            Err(Ok(Error::InvalidScore))
+        );
+        assert_eq!(
+            client.try_score_submission(&quest_id, &alice, &creator, &50),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        // Rescoring replaces the earlier score in the average
+        client.score_submission(&quest_id, &alice, &verifier, &90);
+        client.score_submission(&quest_id, &alice, &verifier, &60);
+        client.score_submission(&quest_id, &bob, &verifier, &100);
+        assert_eq!(client.get_submission_score(&quest_id, &alice), Some(60));
+        // (5 * 50 + 60 + 100) / (5 + 2)
+        assert_eq!(client.get_average_submission_score(&quest_id), Some(58));
+
+        set_submission_status(
+            &env,
+            &contract_id,
+            &quest_id,
+            &alice,
+            SubmissionStatus::Approved,
+        );
+        assert_eq!(client.get_claimable_balance(&alice), 60);
+        assert_eq!(client.claim_all_paid(&alice), 60);
+        assert_eq!(TokenClient::new(&env, &reward_asset).balance(&alice), 60);
+        assert_eq!(client.get_quest_escrow(&quest_id), 140);
+    }
+
+    #[test]
+    fn test_reindex_queues() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let quest_id = symbol_short!("reindex");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(&env, &creator),
+            &100,
+            &verifier,
+            &9999999999,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+        let waiting = Address::generate(&env);
+        let approved = Address::generate(&env);
+        client.submit_proof(&quest_id, &waiting, &BytesN::from_array(&env, &[1u8; 32]));
+        client.submit_proof(&quest_id, &approved, &BytesN::from_array(&env, &[2u8; 32]));
+        client.approve_submission(&quest_id, &approved, &verifier);
+
+        // Corrupt the indices: a settled submission queued, a pending one missing,
+        // and a duplicate quest in the user's list
+        env.as_contract(&contract_id, || {
+            let mut queue = soroban_sdk::Vec::new(&env);
+            queue.push_back((quest_id.clone(), approved.clone()));
+            storage::set_verifier_pending(&env, &verifier, &queue);
+            storage::add_user_submission(&env, &waiting, &quest_id);
+        });
+
+        // Only the admin can repair indices
+        assert_eq!(
+            client.try_reindex_verifier_queue(&creator, &verifier, &quest_id),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.reindex_verifier_queue(&admin, &verifier, &quest_id),
+            2
+        );
+        assert_eq!(
+            client.reindex_verifier_queue(&admin, &verifier, &quest_id),
+            0
+        );
+
+        assert_eq!(client.reindex_user_submission_list(&admin, &waiting), 1);
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                storage::get_verifier_pending(&env, &verifier),
+                soroban_sdk::vec![&env, (quest_id.clone(), waiting.clone())]
+            );
+            assert_eq!(
+                storage::get_user_submissions(&env, &waiting),
+                soroban_sdk::vec![&env, quest_id.clone()]
+            );
+        });
+    }
+
+    #[test]
+    fn test_ledger_index_pruning_records_compaction() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        let quest_id = symbol_short!("quest1");
+        let submitter = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            // Fill the index up to its limit; nothing is pruned yet
+            let mut ledgers = soroban_sdk::Vec::new(&env);
+            for sequence in 0..storage::MAX_INDEXED_LEDGERS - 1 {
+                ledgers.push_back(sequence);
+            }
+            env.storage()
+                .persistent()
+                .set(&storage::StorageKey::IndexedLedgers, &ledgers);
+            storage::add_submission_to_ledger_index(&env, 999, &quest_id, &submitter);
+        });
+        assert_eq!(
+            client.get_storage_metrics(&admin).last_compaction_timestamp,
+            0
+        );
+
+        // Indexing a new ledger drops the oldest one
+        env.as_contract(&contract_id, || {
+            storage::add_submission_to_ledger_index(&env, 1_000, &quest_id, &submitter);
+        });
+        assert_eq!(
+            client.get_storage_metrics(&admin).last_compaction_timestamp,
+            5_000
+        );
+        assert_eq!(client.get_submissions_at_ledger(&0).len(), 0);
+    }
+
+    #[test]
+    fn test_get_all_my_approved_quests() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, EarnQuestContract);
+        let client = EarnQuestContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let submitter = Address::generate(&env);
+        let quests = [
+            (symbol_short!("small"), 50),
+            (symbol_short!("large"), 300),
+            (symbol_short!("pending"), 1000),
+        ];
+        for (quest_id, reward) in quests.iter() {
+            client.register_quest(
+                quest_id,
+                &creator,
+                &reward_token(&env, &creator),
+                reward,
+                &verifier,
+                &9999999999,
+                &10,
+                &QuestDifficulty::Beginner,
+            );
+            client.submit_proof(quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+        }
+        assert_eq!(client.get_all_my_approved_quests(&submitter).len(), 0);
+
+        // Approved but unpaid submissions are listed, largest reward first
+        for (quest_id, _) in quests[..2].iter() {
+            set_submission_status(
+                &env,
+                &contract_id,
+                quest_id,
+                &submitter,
+                SubmissionStatus::Approved,
+            );
+        }
+        assert_eq!(
+            client.get_all_my_approved_quests(&submitter),
+            soroban_sdk::vec![
+                &env,
+                (symbol_short!("large"), 300_i128, false),
+                (symbol_short!("small"), 50_i128, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quest_storage_migration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, _user) = setup_user_with_stats(&env);
+
+        let creator = Address::generate(&env);
+        let quest_id = symbol_short!("legacy");
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(&env, &creator),
+            &100,
+            &Address::generate(&env),
+            &9999999999,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+        let current = client.get_quest(&quest_id);
+        assert_eq!(current.metadata_version, 2);
+
+        // Overwrite the quest with the version 1 layout
+        env.as_contract(&client.address, || {
+            let legacy = QuestV1 {
+                id: current.id.clone(),
+                creator: current.creator.clone(),
+                reward_asset: current.reward_asset.clone(),
+                reward_amount: current.reward_amount,
+                verifier: current.verifier.clone(),
+                deadline: current.deadline,
+                status: current.status.clone(),
+                max_participants: current.max_participants,
+                total_claims: current.total_claims,
+            };
+            env.storage()
+                .persistent()
+                .set(&storage::StorageKey::Quest(quest_id.clone()), &legacy);
+            assert!(!storage::is_quest_current(&env, &quest_id));
+        });
+
+        // Version 1 records are converted on read, newer fields taking their defaults
+        let migrated = Quest {
+            created_at: 0,
+            ..current.clone()
+        };
+        assert_eq!(
+            client.get_quest(&quest_id),
+            Quest {
+                live_until_ledger: 0,
+                ..migrated.clone()
+            }
+        );
+
+        let mut too_many = soroban_sdk::Vec::new(&env);
+        for _ in 0..21 {
+            too_many.push_back(quest_id.clone());
+        }
+        assert_eq!(
+            client.try_run_storage_migration(&admin, &too_many),
+            Err(Ok(Error::BatchTooLarge))
+        );
+        let ids = soroban_sdk::vec![&env, quest_id.clone(), symbol_short!("missing")];
+        assert_eq!(client.run_storage_migration(&admin, &ids), 1);
+        env.as_contract(&client.address, || {
+            assert!(storage::is_quest_current(&env, &quest_id));
+        });
+
+        // Already-migrated quests are skipped
+        assert_eq!(client.run_storage_migration(&admin, &ids), 0);
+        assert_eq!(client.get_quest(&quest_id), migrated);
+
+        // Records in no known layout are reported as such, not as missing
+        let corrupt = symbol_short!("corrupt");
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .set(&storage::StorageKey::Quest(corrupt.clone()), &7u32);
+            assert!(storage::has_quest(&env, &corrupt));
+        });
+        assert_eq!(
+            client.try_get_quest(&corrupt),
+            Err(Ok(Error::QuestDecodeFailed))
+        );
+
+        // So are records written by a newer layout version
+        let future = symbol_short!("future");
+        env.as_contract(&client.address, || {
+            let mut fields = soroban_sdk::Map::<Symbol, soroban_sdk::Val>::new(&env);
+            fields.set(Symbol::new(&env, "metadata_version"), 3u32.into());
+            fields.set(Symbol::new(&env, "id"), future.to_val());
+            env.storage()
+                .persistent()
+                .set(&storage::StorageKey::Quest(future.clone()), &fields);
+            assert!(!storage::is_quest_current(&env, &future));
+        });
+        assert_eq!(
+            client.try_get_quest(&future),
+            Err(Ok(Error::QuestDecodeFailed))
+        );
+        assert_eq!(
+            client.try_submit_proof(
+                &future,
+                &Address::generate(&env),
+                &BytesN::from_array(&env, &[1u8; 32])
+            ),
+            Err(Ok(Error::QuestDecodeFailed))
+        );
+    }
 }
@@ -12,6 +12,10 @@ pub enum QuestStatus {
     Completed,
     /// Quest deadline has passed
     Expired,
+    /// Quest was cancelled by its creator
+    Cancelled,
+    /// Quest was archived by its creator and is kept read-only
+    Archived,
 }
 
 /// Status of a submission
@@ -26,12 +30,72 @@ pub enum SubmissionStatus {
     Rejected,
     /// Reward has been paid out
     Paid,
+    /// Submission was found to be fraudulent
+    Fraudulent,
+    /// Submission was approved automatically after the auto-approve window
+    AutoApproved,
+    /// Submission was rejected automatically after the auto-reject window
+    AutoRejected,
+    /// Submission has been claimed for review by a verifier
+    UnderReview,
+}
+
+impl SubmissionStatus {
+    /// Whether the submission is awaiting a verifier's decision, claimed or not
+    pub fn is_awaiting_review(&self) -> bool {
+        matches!(
+            self,
+            SubmissionStatus::Pending | SubmissionStatus::UnderReview
+        )
+    }
+
+    /// Whether the submission was approved (by a verifier or automatically) but not yet paid
+    pub fn is_approved(&self) -> bool {
+        matches!(
+            self,
+            SubmissionStatus::Approved | SubmissionStatus::AutoApproved
+        )
+    }
+}
+
+/// Difficulty tier of a quest
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QuestDifficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl QuestDifficulty {
+    /// XP multiplier applied to the base XP awarded on approval
+    pub fn xp_multiplier(&self) -> u32 {
+        match self {
+            QuestDifficulty::Beginner => 1,
+            QuestDifficulty::Intermediate => 2,
+            QuestDifficulty::Advanced => 4,
+            QuestDifficulty::Expert => 8,
+        }
+    }
+}
+
+/// How a quest's reward is paid out
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RewardMode {
+    /// Every approved submission receives the full reward
+    Fixed,
+    /// Scored submissions receive `reward_amount * score / 100`
+    ScoreBased,
 }
 
 /// Quest structure with participant limit tracking
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Quest {
+    /// Layout version of the stored record, see `quest::QUEST_METADATA_VERSION`
+    pub metadata_version: u32,
     /// Unique quest identifier
     pub id: Symbol,
     /// Address of the quest creator
@@ -50,6 +114,50 @@ pub struct Quest {
     pub max_participants: u32,
     /// Total number of approved claims
     pub total_claims: u32,
+    /// Difficulty tier, used as an XP multiplier
+    pub difficulty: QuestDifficulty,
+    /// Seconds after which a pending submission can be auto-rejected (0 = disabled)
+    pub auto_reject_after: u64,
+    /// Seconds after which a pending submission can be auto-approved (0 = disabled)
+    pub auto_approve_after: u64,
+    /// Ledger sequence until which the quest entry is kept alive
+    pub live_until_ledger: u32,
+    /// Approved claims required before the creator can mark the quest completed
+    pub min_completions: u32,
+    /// Third party sponsoring the quest, if any
+    pub sponsorship_address: Option<Address>,
+    /// Sponsor display name, used for the `sponsored_by_<name>` badge
+    pub sponsor_name: Option<Symbol>,
+    /// Whether approvals need sign-off from both the verifier and the creator
+    pub dual_approval_required: bool,
+    /// How the reward is paid out
+    pub reward_mode: RewardMode,
+    /// Bayesian average of the scores given to the quest's submissions (1-100)
+    pub avg_score: u32,
+    /// Ledger timestamp when the quest was created
+    pub created_at: u64,
+    /// Verification template the quest is reviewed against, if any
+    pub verification_template_id: Option<u32>,
+    /// Whether rewards are paid out from escrow as soon as a submission is approved
+    pub auto_payout: bool,
+    /// Whether the whitelist root is locked against further changes
+    pub whitelist_sealed: bool,
+}
+
+/// `Quest` layout from before records carried a `metadata_version` (version 1),
+/// read during migration
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestV1 {
+    pub id: Symbol,
+    pub creator: Address,
+    pub reward_asset: Address,
+    pub reward_amount: i128,
+    pub verifier: Address,
+    pub deadline: u64,
+    pub status: QuestStatus,
+    pub max_participants: u32,
+    pub total_claims: u32,
 }
 
 /// Submission structure
@@ -66,6 +174,102 @@ pub struct Submission {
     pub status: SubmissionStatus,
     /// Submission timestamp
     pub timestamp: u64,
+    /// Ledger sequence until which the submission entry is kept alive
+    pub live_until_ledger: u32,
+    /// Verifier responsible for reviewing the submission
+    pub verifier_address: Address,
+    /// Parties that have approved a submission to a dual-approval quest
+    pub partial_approvals: Vec<Address>,
+    /// Quality score given by the verifier (1-100)
+    pub score: Option<u32>,
+    /// Verifier that claimed the submission for review
+    pub reviewing_verifier: Option<Address>,
+    /// Timestamp after which an unfinished review claim can be released (0 if never claimed)
+    pub review_deadline: u64,
+}
+
+/// On-chain attestation that a submitter completed a quest and was paid
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionCertificate {
+    /// Completed quest ID
+    pub quest_id: Symbol,
+    /// Address of the paid submitter
+    pub submitter: Address,
+    /// Reward amount paid
+    pub reward_amount: i128,
+    /// Asset the reward was paid in
+    pub reward_asset: Address,
+    /// Ledger timestamp when the certificate was issued
+    pub completed_at: u64,
+    /// Verifier of the quest at issuance
+    pub verifier: Address,
+    /// sha256(quest_id || submitter || completed_at)
+    pub certificate_hash: BytesN<32>,
+}
+
+/// Creation fee paid for a quest, held by the contract until its refund window passes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationFeePayment {
+    /// Fee amount
+    pub amount: i128,
+    /// Asset the fee was paid in
+    pub asset: Address,
+    /// Treasury the fee is released to once the refund window has passed
+    pub treasury: Address,
+    /// Ledger timestamp when the fee was paid
+    pub paid_at: u64,
+}
+
+/// Condensed copy of a submission kept in instance storage for recovery
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionBackup {
+    /// Hash of the proof data
+    pub proof_hash: BytesN<32>,
+    /// Submission status at backup time
+    pub status: SubmissionStatus,
+    /// Submission timestamp
+    pub timestamp: u64,
+}
+
+/// Tier of a badge
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BadgeTier {
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+}
+
+/// Badge earned by a user
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Badge {
+    /// Badge identifier
+    pub symbol: Symbol,
+    /// Quest the badge was earned for
+    pub quest_id: Symbol,
+    /// Ledger timestamp when the badge was awarded (0 for migrated badges)
+    pub awarded_at: u64,
+    /// Badge tier
+    pub tier: BadgeTier,
+}
+
+/// Curated badge with a limited number of awards
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformBadge {
+    /// Badge identifier
+    pub symbol: Symbol,
+    /// Hash of the off-chain badge description
+    pub description_hash: BytesN<32>,
+    /// Maximum number of users that can hold the badge
+    pub max_supply: u32,
+    /// Number of users the badge has been awarded to
+    pub issued: u32,
 }
 
 /// User reputation statistics
@@ -81,5 +285,171 @@ pub struct UserStats {
     /// Number of quests completed
     pub quests_completed: u32,
     /// Earned badges
+    pub badges: Vec<Badge>,
+}
+
+/// `UserStats` layout from before badges carried metadata, read during migration
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyUserStats {
+    pub address: Address,
+    pub total_xp: u32,
+    pub level: u32,
+    pub quests_completed: u32,
     pub badges: Vec<Symbol>,
 }
+
+/// Review statistics for a verifier
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerifierStats {
+    /// Total number of submissions reviewed (including timeouts)
+    pub total_reviewed: u32,
+    /// Number of submissions approved
+    pub total_approved: u32,
+    /// Number of submissions rejected
+    pub total_rejected: u32,
+    /// Number of submissions auto-rejected because the verifier did not act in time
+    pub total_timeouts: u32,
+}
+
+/// Simulated outcome of approving a submission
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPreview {
+    /// XP the submitter would be awarded
+    pub xp_to_award: u32,
+    /// Whether the award would raise the submitter's level
+    pub will_level_up: bool,
+    /// Submitter's level after the award
+    pub new_level: u32,
+    /// Stream bonus XP the approval would unlock via `check_stream_completion`
+    pub bonus_xp: u32,
+    /// Verifier statistics after recording the approval
+    pub verifier_stats_after: VerifierStats,
+}
+
+/// Contract storage usage metrics
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMetrics {
+    /// Number of persistent entries written by the contract
+    pub entry_count: u32,
+    /// Estimated persistent storage used by all quests, in bytes
+    pub estimated_bytes: u64,
+    /// Timestamp at which the submission ledger index last dropped its oldest ledger
+    /// (0 if never compacted)
+    pub last_compaction_timestamp: u64,
+}
+
+/// TTL status of a quest's storage entries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlHealthReport {
+    /// Ledgers remaining before the quest entry expires
+    pub quest_ttl_remaining: u32,
+    /// Number of submissions for the quest
+    pub submission_count: u32,
+    /// Sampled entries with fewer than `TTL_WARNING_THRESHOLD` ledgers remaining
+    pub entries_expiring_soon: u32,
+    /// Lowest remaining TTL among the sampled entries
+    pub min_ttl_remaining: u32,
+}
+
+/// A series of quests with a bonus for completing all of them
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestStream {
+    /// Unique stream identifier
+    pub id: u32,
+    /// Quests that make up the stream
+    pub quest_ids: Vec<Symbol>,
+    /// Display name of the stream
+    pub name: Symbol,
+    /// XP awarded once a user completes every quest in the stream
+    pub bonus_xp: u32,
+}
+
+/// Quests created together from a template, one per day
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailyQuestSeries {
+    /// Unique series identifier
+    pub series_id: u32,
+    /// Quests of the series, one per cycle in deadline order
+    pub quest_ids: Vec<Symbol>,
+    /// Index in `quest_ids` of the quest currently open for the day
+    pub active_index: u32,
+}
+
+/// Reusable review rubric published by a verifier
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationTemplate {
+    /// Unique template identifier
+    pub id: u32,
+    /// Verifier who published the template
+    pub verifier: Address,
+    /// Hash of the off-chain review criteria
+    pub criteria_hash: BytesN<32>,
+    /// Display name of the template
+    pub name: Symbol,
+}
+
+/// Per-quest counts of review outcomes, split by human and automatic decisions
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SubmissionStatusCounts {
+    /// Submissions approved by the verifier
+    pub approved: u32,
+    /// Submissions approved after the auto-approve window
+    pub auto_approved: u32,
+    /// Submissions rejected by the verifier
+    pub rejected: u32,
+    /// Submissions rejected after the auto-reject window
+    pub auto_rejected: u32,
+}
+
+/// Recent engagement metrics of a quest
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestVelocity {
+    /// Submissions recorded today
+    pub submissions_last_24h: u32,
+    /// Submissions recorded over the last 7 days, including today
+    pub submissions_last_7d: u32,
+    /// Approvals recorded today
+    pub approvals_last_24h: u32,
+    /// Days until the quest fills at the last 7 days' average pace (`u32::MAX` if it never will)
+    pub estimated_fill_days: u32,
+}
+
+/// Platform-wide activity counters
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlatformStats {
+    /// Number of quests created
+    pub total_quests: u32,
+    /// Number of proofs submitted
+    pub total_submissions: u32,
+    /// Number of submissions approved
+    pub total_approved: u32,
+    /// Number of submissions rejected
+    pub total_rejected: u32,
+    /// Number of quests archived
+    pub archived_quests: u32,
+}
+
+/// Point-in-time snapshot of the platform state
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformSnapshot {
+    /// Platform activity counters
+    pub stats: PlatformStats,
+    /// Top users by XP
+    pub top_users: Vec<(Address, u32)>,
+    /// Number of registered quests
+    pub quest_count: u32,
+    /// Ledger timestamp when the snapshot was taken
+    pub snapshot_timestamp: u64,
+}
@@ -0,0 +1,118 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::analytics;
+use crate::errors::Error;
+use crate::storage;
+use crate::types::QuestStatus;
+
+/// Minimum accepted proof URI length in bytes
+pub const MIN_PROOF_URI_LEN: u32 = 10;
+
+/// Maximum accepted proof URI length in bytes
+pub const MAX_PROOF_URI_LEN: u32 = 256;
+
+/// Schemes accepted until the admin configures the list
+const DEFAULT_URI_SCHEMES: [&[u8]; 3] = [b"https://", b"ipfs://", b"ar://"];
+
+/// Get the URI schemes accepted for proofs
+pub fn allowed_uri_schemes(env: &Env) -> Vec<Bytes> {
+    storage::get_allowed_uri_schemes(env).unwrap_or_else(|| {
+        let mut schemes = Vec::new(env);
+        for scheme in DEFAULT_URI_SCHEMES.iter() {
+            schemes.push_back(Bytes::from_slice(env, scheme));
+        }
+        schemes
+    })
+}
+
+/// Check whether a submission has been waiting longer than `max_age_secs`, which suggests
+/// a proof that was prepared well ahead of review. Returns false if there is no submission.
+pub fn flag_old_proof(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    max_age_secs: u64,
+) -> bool {
+    match storage::get_submission(env, quest_id, submitter) {
+        Some(submission) => {
+            env.ledger()
+                .timestamp()
+                .saturating_sub(submission.timestamp)
+                > max_age_secs
+        }
+        None => false,
+    }
+}
+
+/// Get the creator's active quests whose deadline falls on the same day as `new_deadline`,
+/// so front-ends can warn before creating a quest that expires alongside them
+pub fn check_deadline_conflicts(env: &Env, creator: &Address, new_deadline: u64) -> Vec<Symbol> {
+    let day = new_deadline / analytics::SECONDS_PER_DAY;
+    let mut conflicts = Vec::new(env);
+    for quest_id in storage::get_creator_quests(env, creator).iter() {
+        if let Some(quest) = storage::get_quest(env, &quest_id) {
+            if quest.status == QuestStatus::Active
+                && quest.deadline / analytics::SECONDS_PER_DAY == day
+            {
+                conflicts.push_back(quest_id);
+            }
+        }
+    }
+    conflicts
+}
+
+/// Check that every byte is printable ASCII (which also rules out null bytes)
+pub fn is_printable_ascii(bytes: &Bytes) -> bool {
+    bytes.iter().all(|b| (0x20..=0x7e).contains(&b))
+}
+
+/// Check the structural form of a proof URI.
+/// The contract can't fetch the URI, so this only rules out URIs that can never resolve.
+pub fn validate_proof_uri_reachability_hint(env: &Env, uri: &Bytes) -> Result<(), Error> {
+    // Validate length
+    if uri.len() < MIN_PROOF_URI_LEN || uri.len() > MAX_PROOF_URI_LEN {
+        return Err(Error::InvalidProofUri);
+    }
+
+    // Validate characters
+    if !is_printable_ascii(uri) {
+        return Err(Error::InvalidProofUri);
+    }
+
+    // Validate scheme
+    let has_allowed_scheme = allowed_uri_schemes(env)
+        .iter()
+        .any(|scheme| scheme.len() <= uri.len() && uri.slice(..scheme.len()) == scheme);
+    if !has_allowed_scheme {
+        return Err(Error::InvalidProofUri);
+    }
+
+    Ok(())
+}
+
+/// Compute the Merkle leaf of an address: sha256 of its XDR encoding
+pub fn compute_leaf(env: &Env, address: &Address) -> BytesN<32> {
+    env.crypto().sha256(&address.clone().to_xdr(env)).to_bytes()
+}
+
+/// Verify that `address` is a leaf of the Merkle tree with the given root.
+/// Sibling pairs are hashed in sorted order, so the proof needs no position bits.
+pub fn verify_merkle_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    address: &Address,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = compute_leaf(env, address);
+    for sibling in proof.iter() {
+        let (left, right) = if node <= sibling {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut pair = Bytes::from_array(env, &left.to_array());
+        pair.append(&Bytes::from_array(env, &right.to_array()));
+        node = env.crypto().sha256(&pair).to_bytes();
+    }
+    node == *root
+}
@@ -0,0 +1,408 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+use crate::admin;
+use crate::errors::Error;
+use crate::quest;
+use crate::quest_stream;
+use crate::reputation;
+use crate::storage;
+use crate::types::{
+    ApprovalPreview, Quest, Submission, SubmissionStatus, VerificationTemplate, VerifierStats,
+};
+use crate::BASE_QUEST_XP;
+
+/// Set the auto-reject window for pending submissions of a quest (creator only).
+/// A value of 0 disables auto-rejection.
+pub fn set_auto_reject_after(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    seconds: u64,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
+    quest.auto_reject_after = seconds;
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "auto_rej_set"), quest_id.clone()),
+        seconds,
+    );
+
+    Ok(())
+}
+
+/// Set the auto-approve window for pending submissions of a quest (creator only).
+/// A value of 0 disables auto-approval.
+pub fn set_auto_approve_after(
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    seconds: u64,
+) -> Result<(), Error> {
+    // Verify creator authorization
+    creator.require_auth();
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the creator
+    if quest.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
+    quest.auto_approve_after = seconds;
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "auto_appr_set"), quest_id.clone()),
+        seconds,
+    );
+
+    Ok(())
+}
+
+/// Publish a reusable review rubric. Returns the new template ID.
+pub fn create_template(
+    env: &Env,
+    verifier: &Address,
+    name: Symbol,
+    criteria_hash: BytesN<32>,
+) -> Result<u32, Error> {
+    // Verify verifier authorization
+    verifier.require_auth();
+    admin::require_feature_enabled(env, &admin::FLAG_VERIFICATION_TEMPLATES)?;
+
+    let id = storage::get_template_count(env) + 1;
+    let template = VerificationTemplate {
+        id,
+        verifier: verifier.clone(),
+        criteria_hash,
+        name,
+    };
+
+    storage::set_template(env, &template);
+    storage::set_template_count(env, id);
+    storage::add_verifier_template(env, verifier, id);
+
+    // Emit event
+    env.events()
+        .publish((Symbol::new(env, "template_created"), id), verifier.clone());
+
+    Ok(id)
+}
+
+/// Get a verification template
+pub fn get_template(env: &Env, template_id: u32) -> Result<VerificationTemplate, Error> {
+    storage::get_template(env, template_id).ok_or(Error::TemplateNotFound)
+}
+
+/// Get the IDs of the templates a verifier has published, oldest first
+pub fn list_templates_by_verifier(env: &Env, verifier: &Address) -> Vec<u32> {
+    storage::get_verifier_templates(env, verifier)
+}
+
+/// Prior mean the Bayesian average submission score starts from
+pub const SCORE_PRIOR_MEAN: u32 = 50;
+
+/// Number of virtual prior scores weighing the Bayesian average towards `SCORE_PRIOR_MEAN`
+pub const SCORE_PRIOR_WEIGHT: u32 = 5;
+
+/// Score the quality of a submission from 1 to 100 (verifier only).
+/// Rescoring replaces the previous score in the quest's average.
+pub fn score_submission(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    verifier: &Address,
+    score: u32,
+) -> Result<(), Error> {
+    // Verify verifier authorization
+    verifier.require_auth();
+
+    // Validate score
+    if !(1..=100).contains(&score) {
+        return Err(Error::InvalidScore);
+    }
+
+    // Get quest
+    let mut quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the designated verifier
+    if quest.verifier != *verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
+    // Get submission
+    let mut submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    let (mut sum, mut count) = storage::get_quest_score_totals(env, quest_id);
+    match submission.score {
+        Some(previous) => sum -= previous as u64,
+        None => count += 1,
+    }
+    sum += score as u64;
+    storage::set_quest_score_totals(env, quest_id, sum, count);
+
+    submission.score = Some(score);
+    storage::set_submission(env, &submission);
+
+    let prior = (SCORE_PRIOR_MEAN * SCORE_PRIOR_WEIGHT) as u64;
+    quest.avg_score = ((prior + sum) / (SCORE_PRIOR_WEIGHT + count) as u64) as u32;
+    storage::set_quest(env, &quest);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "submission_scored"), quest_id.clone()),
+        (submitter.clone(), score),
+    );
+
+    Ok(())
+}
+
+/// Seconds a verifier's review claim blocks other reviewers before it can be released
+pub const REVIEW_LOCK_SECS: u64 = 24 * 3600;
+
+/// Claim a pending submission for review, so only the claiming verifier can approve or
+/// reject it until `REVIEW_LOCK_SECS` have passed (verifier, or creator on dual-approval quests)
+pub fn claim_for_review(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    verifier: &Address,
+) -> Result<(), Error> {
+    // Verify verifier authorization
+    verifier.require_auth();
+    admin::require_feature_enabled(env, &admin::FLAG_REVIEW_CLAIMS)?;
+
+    // Get quest
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+
+    // Verify caller is the designated verifier, or the creator on dual-approval quests
+    let is_creator_vote = quest.dual_approval_required && quest.creator == *verifier;
+    if quest.verifier != *verifier && !is_creator_vote {
+        return Err(Error::Unauthorized);
+    }
+
+    // Archived quests are read-only
+    quest::ensure_not_archived(&quest)?;
+
+    // Get submission
+    let mut submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    // Check submission is pending and unclaimed
+    if submission.status != SubmissionStatus::Pending {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+
+    let review_deadline = env.ledger().timestamp().saturating_add(REVIEW_LOCK_SECS);
+    submission.status = SubmissionStatus::UnderReview;
+    submission.reviewing_verifier = Some(verifier.clone());
+    submission.review_deadline = review_deadline;
+    storage::set_submission(env, &submission);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "review_claimed"), quest_id.clone()),
+        (submitter.clone(), verifier.clone(), review_deadline),
+    );
+
+    Ok(())
+}
+
+/// Release a review claim once its deadline has passed, returning the submission to
+/// `Pending`. Callable by anyone.
+pub fn unclaim_review(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    let mut submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    if submission.status != SubmissionStatus::UnderReview {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+    if env.ledger().timestamp() < submission.review_deadline {
+        return Err(Error::ReviewLockActive);
+    }
+
+    submission.status = SubmissionStatus::Pending;
+    submission.reviewing_verifier = None;
+    submission.review_deadline = 0;
+    storage::set_submission(env, &submission);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "review_unclaimed"), quest_id.clone()),
+        submitter.clone(),
+    );
+
+    Ok(())
+}
+
+/// Fail with `Unauthorized` if the submission is claimed for review by someone other than `verifier`
+pub fn ensure_review_claimed_by(submission: &Submission, verifier: &Address) -> Result<(), Error> {
+    if submission.status == SubmissionStatus::UnderReview
+        && submission.reviewing_verifier.as_ref() != Some(verifier)
+    {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Record `approver`'s sign-off on a submission to a dual-approval quest.
+/// Returns `true` once both the quest's verifier and its creator have approved;
+/// every vote is stored on the pending submission.
+pub fn require_multi_sig_approval(
+    env: &Env,
+    quest: &Quest,
+    submission: &mut Submission,
+    approver: &Address,
+) -> Result<bool, Error> {
+    // Each party votes once
+    if submission.partial_approvals.contains(approver) {
+        return Err(Error::AlreadyApproved);
+    }
+    submission.partial_approvals.push_back(approver.clone());
+    storage::set_submission(env, submission);
+
+    let approvals = &submission.partial_approvals;
+    if approvals.contains(&quest.verifier) && approvals.contains(&quest.creator) {
+        return Ok(true);
+    }
+
+    // Emit event
+    env.events().publish(
+        (
+            Symbol::new(env, "partial_approval_recorded"),
+            quest.id.clone(),
+        ),
+        (submission.submitter.clone(), approver.clone()),
+    );
+
+    Ok(false)
+}
+
+/// Record a human review decision for a verifier
+pub fn record_review(env: &Env, verifier: &Address, approved: bool) {
+    let mut stats = storage::get_verifier_stats(env, verifier);
+    stats.total_reviewed += 1;
+    if approved {
+        stats.total_approved += 1;
+    } else {
+        stats.total_rejected += 1;
+    }
+    storage::set_verifier_stats(env, verifier, &stats);
+}
+
+/// Record a submission that timed out waiting for a verifier
+pub fn record_timeout(env: &Env, verifier: &Address) {
+    let mut stats = storage::get_verifier_stats(env, verifier);
+    stats.total_reviewed += 1;
+    stats.total_timeouts += 1;
+    storage::set_verifier_stats(env, verifier, &stats);
+}
+
+/// Simulate approving a submission without writing any state
+pub fn preview_approval_effects(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+) -> Result<ApprovalPreview, Error> {
+    // Get quest and submission
+    let quest = storage::get_quest(env, quest_id).ok_or(Error::QuestNotFound)?;
+    let submission =
+        storage::get_submission(env, quest_id, submitter).ok_or(Error::SubmissionNotFound)?;
+
+    // Same preconditions as approve_submission
+    if !submission.status.is_awaiting_review() {
+        return Err(Error::InvalidSubmissionStatus);
+    }
+    if quest::is_quest_full(&quest) {
+        return Err(Error::QuestFull);
+    }
+
+    let stats = reputation::get_or_init_stats(env, submitter);
+    let xp_to_award = BASE_QUEST_XP * quest.difficulty.xp_multiplier();
+    let new_level = reputation::calculate_level(stats.total_xp + xp_to_award);
+
+    let mut verifier_stats_after = storage::get_verifier_stats(env, &quest.verifier);
+    verifier_stats_after.total_reviewed += 1;
+    verifier_stats_after.total_approved += 1;
+
+    Ok(ApprovalPreview {
+        xp_to_award,
+        will_level_up: new_level > stats.level,
+        new_level,
+        bonus_xp: quest_stream::pending_bonus_xp(env, quest_id, submitter),
+        verifier_stats_after,
+    })
+}
+
+/// Get every quest a verifier is assigned to, including quests with no submissions yet
+pub fn get_assigned_quest_ids(env: &Env, verifier: &Address) -> Vec<Symbol> {
+    storage::get_verifier_quests(env, verifier)
+}
+
+/// Get the number of quests a verifier is assigned to
+pub fn get_assigned_quest_count(env: &Env, verifier: &Address) -> u32 {
+    storage::get_verifier_quests(env, verifier).len()
+}
+
+/// Count a verifier's pending submissions waiting longer than `threshold_secs`
+pub fn get_sla_breach_count(env: &Env, verifier: &Address, threshold_secs: u64) -> u32 {
+    let now = env.ledger().timestamp();
+    let mut count = 0;
+    for (quest_id, submitter) in storage::get_verifier_pending(env, verifier).iter() {
+        if let Some(submission) = storage::get_submission(env, &quest_id, &submitter) {
+            if now.saturating_sub(submission.timestamp) > threshold_secs {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Get (verifier, pending_count) for every registered verifier, least loaded first
+/// (ties keep registration order)
+pub fn get_verification_load_balancing_hint(env: &Env) -> Vec<(Address, u32)> {
+    let mut hint: Vec<(Address, u32)> = Vec::new(env);
+
+    for verifier in storage::get_registered_verifiers(env).iter() {
+        let pending = storage::get_verifier_pending(env, &verifier).len();
+
+        // Insert at the sorted position
+        let index = hint
+            .iter()
+            .position(|(_, p)| p > pending)
+            .map(|i| i as u32)
+            .unwrap_or(hint.len());
+        hint.insert(index, (verifier, pending));
+    }
+
+    hint
+}
+
+/// Get review statistics for a verifier
+pub fn get_verifier_stats(env: &Env, verifier: &Address) -> VerifierStats {
+    storage::get_verifier_stats(env, verifier)
+}
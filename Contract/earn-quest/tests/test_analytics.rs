@@ -0,0 +1,291 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+
+    (client, creator, verifier, reward_asset)
+}
+
+#[test]
+fn test_daily_submission_counts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 10);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(86400 * 100),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    // Two submissions on day 10
+    for i in 1..=2u8 {
+        let submitter = Address::generate(&env);
+        let proof = BytesN::from_array(&env, &[i; 32]);
+        client.submit_proof(&quest_id, &submitter, &proof);
+    }
+
+    // One submission on day 12
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 12 + 60);
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[3u8; 32]));
+
+    let counts = client.get_daily_submission_counts(&quest_id, &0, &20);
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts.get(0).unwrap(), (10, 2));
+    assert_eq!(counts.get(1).unwrap(), (12, 1));
+
+    // Peak is day 10
+    assert_eq!(client.get_peak_submission_day(&quest_id), Some((10, 2)));
+}
+
+#[test]
+fn test_daily_submission_counts_range_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 200);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(86400 * 300),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+
+    // Day 200 lies outside the first 90 days of the requested range
+    let counts = client.get_daily_submission_counts(&quest_id, &100, &250);
+    assert_eq!(counts.len(), 0);
+
+    let counts = client.get_daily_submission_counts(&quest_id, &150, &250);
+    assert_eq!(counts.len(), 1);
+}
+
+#[test]
+fn test_peak_submission_day_none_without_submissions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, _verifier, _reward_asset) = setup_env(&env);
+
+    assert_eq!(
+        client.get_peak_submission_day(&symbol_short!("quest1")),
+        None
+    );
+}
+
+#[test]
+fn test_user_activity_heatmap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 10);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let submitter = Address::generate(&env);
+    for (i, quest_id) in [
+        symbol_short!("quest1"),
+        symbol_short!("quest2"),
+        symbol_short!("quest3"),
+    ]
+    .iter()
+    .enumerate()
+    {
+        client.register_quest(
+            quest_id,
+            &creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &(86400 * 100),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+
+        // Two submissions on day 10, one on day 12
+        if i == 2 {
+            env.ledger().with_mut(|li| li.timestamp = 86400 * 12 + 60);
+        }
+        client.submit_proof(
+            quest_id,
+            &submitter,
+            &BytesN::from_array(&env, &[i as u8; 32]),
+        );
+    }
+
+    let heatmap = client.get_user_activity_heatmap(&submitter, &4);
+    assert_eq!(heatmap.len(), 4);
+    assert_eq!(heatmap.get(0).unwrap(), (9, 0));
+    assert_eq!(heatmap.get(1).unwrap(), (10, 2));
+    assert_eq!(heatmap.get(2).unwrap(), (11, 0));
+    assert_eq!(heatmap.get(3).unwrap(), (12, 1));
+
+    // Ranges are capped and never reach before day zero
+    assert_eq!(
+        client.get_user_activity_heatmap(&submitter, &1000).len(),
+        13
+    );
+    assert_eq!(client.get_user_activity_heatmap(&submitter, &0).len(), 0);
+}
+
+#[test]
+fn test_quest_velocity_and_trending() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 10);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quests = [
+        symbol_short!("quest1"),
+        symbol_short!("quest2"),
+        symbol_short!("quest3"),
+    ];
+    for quest_id in quests.iter() {
+        client.register_quest(
+            quest_id,
+            &creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &(86400 * 100),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    // Two submissions to quest1 on day 10
+    for i in 1..=2u8 {
+        let submitter = Address::generate(&env);
+        client.submit_proof(&quests[0], &submitter, &BytesN::from_array(&env, &[i; 32]));
+    }
+
+    // One approved submission to quest1 and two submissions to quest2 on day 12
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 12 + 60);
+    let submitter = Address::generate(&env);
+    client.submit_proof(
+        &quests[0],
+        &submitter,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+    client.approve_submission(&quests[0], &submitter, &verifier);
+    for i in 4..=5u8 {
+        let submitter = Address::generate(&env);
+        client.submit_proof(&quests[1], &submitter, &BytesN::from_array(&env, &[i; 32]));
+    }
+
+    // 9 open slots at 3 submissions a week
+    let velocity = client.get_quest_velocity(&quests[0]);
+    assert_eq!(velocity.submissions_last_24h, 1);
+    assert_eq!(velocity.submissions_last_7d, 3);
+    assert_eq!(velocity.approvals_last_24h, 1);
+    assert_eq!(velocity.estimated_fill_days, 21);
+
+    // A quest without submissions never fills
+    assert_eq!(
+        client.get_quest_velocity(&quests[2]).estimated_fill_days,
+        u32::MAX
+    );
+
+    assert_eq!(
+        client.get_trending_quests(&10),
+        soroban_sdk::vec![&env, quests[1].clone(), quests[0].clone()]
+    );
+    assert_eq!(client.get_trending_quests(&1).len(), 1);
+
+    // Submissions from a week ago no longer count
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 17);
+    let velocity = client.get_quest_velocity(&quests[0]);
+    assert_eq!(velocity.submissions_last_24h, 0);
+    assert_eq!(velocity.submissions_last_7d, 1);
+    assert_eq!(client.get_trending_quests(&10).len(), 0);
+}
+
+#[test]
+fn test_submission_and_approval_timelines() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 10);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(86400 * 300),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    // Two submissions on day 10
+    let first = Address::generate(&env);
+    client.submit_proof(&quest_id, &first, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_proof(
+        &quest_id,
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    // One approval on day 11, one submission on day 12
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 11);
+    client.approve_submission(&quest_id, &first, &verifier);
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 12 + 60);
+    client.submit_proof(
+        &quest_id,
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    let timeline = client.get_submission_timeline(&quest_id);
+    assert_eq!(timeline, soroban_sdk::vec![&env, (10, 2), (11, 2), (12, 3)]);
+    let timeline = client.get_approval_timeline(&quest_id);
+    assert_eq!(timeline, soroban_sdk::vec![&env, (10, 0), (11, 1), (12, 1)]);
+
+    // Timelines are capped to the first 90 days
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 200);
+    let timeline = client.get_submission_timeline(&quest_id);
+    assert_eq!(timeline.len(), 90);
+    assert_eq!(timeline.last().unwrap(), (99, 3));
+    assert_eq!(
+        client.get_submission_timeline(&symbol_short!("none")).len(),
+        0
+    );
+}
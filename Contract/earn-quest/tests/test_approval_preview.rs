@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use earn_quest::{
+    EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty, SubmissionStatus,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, vec, Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    for quest_id in [symbol_short!("day1"), symbol_short!("day2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(env, &creator),
+            &1000_i128,
+            &verifier,
+            &(env.ledger().timestamp() + 86400 * 7),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    (client, creator, verifier)
+}
+
+#[test]
+fn test_preview_approval_effects() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier) = setup_env(&env);
+    let day1 = symbol_short!("day1");
+    let day2 = symbol_short!("day2");
+    let quest_ids = vec![&env, day1.clone(), day2.clone()];
+    client.create_stream(&creator, &quest_ids, &symbol_short!("week1"), &200);
+
+    let user = Address::generate(&env);
+    client.submit_proof(&day1, &user, &BytesN::from_array(&env, &[1u8; 32]));
+
+    let preview = client.preview_approval_effects(&day1, &user);
+    assert_eq!(preview.xp_to_award, 100);
+    assert!(preview.will_level_up);
+    assert_eq!(preview.new_level, 2);
+    assert_eq!(preview.bonus_xp, 0);
+    assert_eq!(preview.verifier_stats_after.total_reviewed, 1);
+    assert_eq!(preview.verifier_stats_after.total_approved, 1);
+
+    // Preview writes no state
+    assert_eq!(
+        client.get_submission(&day1, &user).status,
+        SubmissionStatus::Pending
+    );
+    assert_eq!(client.get_verifier_stats(&verifier).total_reviewed, 0);
+
+    client.approve_submission(&day1, &user, &verifier);
+    client.submit_proof(&day2, &user, &BytesN::from_array(&env, &[2u8; 32]));
+
+    // Approving the last quest of the stream unlocks its bonus
+    let preview = client.preview_approval_effects(&day2, &user);
+    assert_eq!(preview.bonus_xp, 200);
+    assert_eq!(preview.new_level, 3);
+    assert_eq!(preview.verifier_stats_after.total_approved, 2);
+}
+
+#[test]
+fn test_preview_processed_submission_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, verifier) = setup_env(&env);
+    let quest_id = symbol_short!("day1");
+    let user = Address::generate(&env);
+    client.submit_proof(&quest_id, &user, &BytesN::from_array(&env, &[1u8; 32]));
+    client.approve_submission(&quest_id, &user, &verifier);
+
+    assert_eq!(
+        client.try_preview_approval_effects(&quest_id, &user),
+        Err(Ok(Error::InvalidSubmissionStatus))
+    );
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty, SubmissionStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+
+    let deadline = env.ledger().timestamp() + 86400 * 10;
+    client.register_quest(
+        &symbol_short!("quest1"),
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Intermediate,
+    );
+
+    (client, creator, verifier)
+}
+
+#[test]
+fn test_auto_approve_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, _verifier) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.set_auto_approve_after(&quest_id, &creator, &3600);
+
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+
+    // Too early
+    env.ledger().with_mut(|li| li.timestamp += 3599);
+    assert!(!client.try_auto_approve(&quest_id, &submitter));
+
+    // Window elapsed
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert!(client.try_auto_approve(&quest_id, &submitter));
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).status,
+        SubmissionStatus::AutoApproved
+    );
+    assert_eq!(client.get_user_stats(&submitter).total_xp, 200);
+    assert_eq!(client.get_quest(&quest_id).total_claims, 1);
+    assert_eq!(client.get_quest_pending_count(&quest_id), 0);
+    assert_eq!(client.get_claimable_balance(&submitter), 1000);
+
+    // Already processed
+    assert!(client.try_try_auto_approve(&quest_id, &submitter).is_err());
+}
+
+#[test]
+fn test_auto_approve_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    assert_eq!(client.get_auto_approve_rate(&quest_id), 0);
+
+    client.set_auto_approve_after(&quest_id, &creator, &60);
+
+    let submitters: [Address; 4] = core::array::from_fn(|_| Address::generate(&env));
+    for (i, submitter) in submitters.iter().enumerate() {
+        client.submit_proof(
+            &quest_id,
+            submitter,
+            &BytesN::from_array(&env, &[i as u8 + 1; 32]),
+        );
+    }
+
+    // One human approval and one rejection before the window elapses
+    client.approve_submission(&quest_id, &submitters[0], &verifier);
+    client.reject_submission(&quest_id, &submitters[1], &verifier);
+
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert!(client.try_auto_approve(&quest_id, &submitters[2]));
+    assert!(client.try_auto_approve(&quest_id, &submitters[3]));
+
+    // 2 of 3 approvals were automatic; rejections are excluded
+    assert_eq!(client.get_auto_approve_rate(&quest_id), 6666);
+}
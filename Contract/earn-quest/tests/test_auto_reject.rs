@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty, SubmissionStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_quest_with_submission<'a>(
+    env: &Env,
+) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400 * 10;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let submitter = Address::generate(env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(env, &[1u8; 32]));
+
+    (client, creator, verifier, submitter)
+}
+
+#[test]
+fn test_auto_reject_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+    client.set_auto_reject_after(&quest_id, &creator, &3600);
+
+    // Too early
+    env.ledger().with_mut(|li| li.timestamp += 3599);
+    assert!(!client.try_auto_reject(&quest_id, &submitter));
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).status,
+        SubmissionStatus::Pending
+    );
+
+    // Window elapsed
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert!(client.try_auto_reject(&quest_id, &submitter));
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).status,
+        SubmissionStatus::AutoRejected
+    );
+
+    // Timeout counts against the verifier
+    let stats = client.get_verifier_stats(&verifier);
+    assert_eq!(stats.total_timeouts, 1);
+    assert_eq!(stats.total_reviewed, 1);
+}
+
+#[test]
+fn test_auto_reject_disabled_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, _verifier, submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+
+    env.ledger().with_mut(|li| li.timestamp += 86400 * 5);
+    assert!(!client.try_auto_reject(&quest_id, &submitter));
+}
+
+#[test]
+fn test_auto_reject_processed_submission_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+    client.set_auto_reject_after(&quest_id, &creator, &60);
+    client.approve_submission(&quest_id, &submitter, &verifier);
+
+    env.ledger().with_mut(|li| li.timestamp += 120);
+    let result = client.try_try_auto_reject(&quest_id, &submitter);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_set_auto_reject_after_non_creator_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, verifier, _submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+
+    let result = client.try_set_auto_reject_after(&quest_id, &verifier, &60);
+    assert!(result.is_err());
+}
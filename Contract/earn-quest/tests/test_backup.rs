@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(
+    env: &Env,
+) -> (
+    EarnQuestContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+    client.initialize(&admin);
+
+    (client, admin, creator, verifier, reward_asset)
+}
+
+#[test]
+fn test_backup_submission_to_instance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[7u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &proof);
+
+    // Pending submissions are not backed up
+    assert_eq!(
+        client.try_backup_submission_to_instance(&admin, &quest_id, &submitter),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(client.get_submission_backup(&quest_id, &submitter), None);
+
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    client.backup_submission_to_instance(&admin, &quest_id, &submitter);
+
+    let submission = client.get_submission(&quest_id, &submitter);
+    let backup = client.get_submission_backup(&quest_id, &submitter).unwrap();
+    assert_eq!(backup.proof_hash, proof);
+    assert_eq!(backup.status, submission.status);
+    assert_eq!(backup.timestamp, submission.timestamp);
+}
+
+#[test]
+fn test_backup_submission_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _creator, _verifier, _reward_asset) = setup_env(&env);
+    let other = Address::generate(&env);
+
+    assert_eq!(
+        client.try_backup_submission_to_instance(&other, &symbol_short!("quest1"), &other),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty, QuestStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env, IntoVal, Symbol,
+};
+
+fn setup_env<'a>(
+    env: &Env,
+) -> (
+    EarnQuestContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin);
+    StellarAssetClient::new(env, &asset).mint(&creator, &10_000);
+
+    (client, admin, creator, verifier, asset)
+}
+
+fn register(
+    client: &EarnQuestContractClient,
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    verifier: &Address,
+    asset: &Address,
+) {
+    client.register_quest(
+        quest_id,
+        creator,
+        asset,
+        &100_i128,
+        verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+}
+
+#[test]
+fn test_creation_fee_swept_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, creator, verifier, asset) = setup_env(&env);
+    let treasury = Address::generate(&env);
+    assert_eq!(client.get_treasury(), Some(admin.clone()));
+    client.set_treasury(&admin, &treasury);
+    client.set_quest_creation_fee(&admin, &250, &asset);
+    assert_eq!(client.get_creation_fee(), Some((250, asset.clone())));
+
+    register(
+        &client,
+        &env,
+        &symbol_short!("quest1"),
+        &creator,
+        &verifier,
+        &asset,
+    );
+
+    // The fee and the reward pool are both held by the contract, the fee only
+    // during the refund window
+    let quest_id = symbol_short!("quest1");
+    let token = TokenClient::new(&env, &asset);
+    assert_eq!(token.balance(&treasury), 0);
+    assert_eq!(token.balance(&creator), 10_000 - 250 - 1_000);
+    assert_eq!(client.get_quest_escrow(&quest_id), 1_000);
+    assert_eq!(client.get_orphaned_balance(&asset), 0);
+    assert_eq!(client.sweep_creation_fee(&quest_id), 0);
+
+    // Anyone can sweep it to the treasury afterwards, once
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    assert_eq!(client.sweep_creation_fee(&quest_id), 250);
+    assert_eq!(token.balance(&treasury), 250);
+    assert_eq!(client.sweep_creation_fee(&quest_id), 0);
+
+    // Negative fees are rejected
+    assert!(client
+        .try_set_quest_creation_fee(&admin, &-1, &asset)
+        .is_err());
+}
+
+#[test]
+fn test_cancel_quest_refunds_fee_within_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, creator, verifier, asset) = setup_env(&env);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&admin, &treasury);
+    client.set_quest_creation_fee(&admin, &250, &asset);
+
+    let early = symbol_short!("early");
+    let late = symbol_short!("late");
+    register(&client, &env, &early, &creator, &verifier, &asset);
+    register(&client, &env, &late, &creator, &verifier, &asset);
+
+    // Cancelled within the window: escrow and fee are refunded by the contract
+    // without the treasury's involvement
+    env.mock_auths(&[MockAuth {
+        address: &creator,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "cancel_quest",
+            args: (&early, &creator).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.cancel_quest(&early, &creator);
+    env.mock_all_auths();
+    let token = TokenClient::new(&env, &asset);
+    assert_eq!(token.balance(&creator), 10_000 - 1_250);
+    assert_eq!(client.get_quest_escrow(&early), 0);
+    assert_eq!(client.get_quest(&early).status, QuestStatus::Cancelled);
+
+    // Cancelled after the window: the fee goes to the treasury
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    client.cancel_quest(&late, &creator);
+    assert_eq!(token.balance(&creator), 9_750);
+    assert_eq!(token.balance(&treasury), 250);
+
+    // Cancelled quests cannot be cancelled again
+    assert!(client.try_cancel_quest(&late, &creator).is_err());
+}
+
+#[test]
+fn test_daily_series_charges_fee_and_limit_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, creator, verifier, asset) = setup_env(&env);
+    client.set_quest_creation_fee(&admin, &250, &asset);
+    client.set_max_quests_per_creator(&admin, &1);
+    let template_id = client.create_verification_template(
+        &verifier,
+        &symbol_short!("daily"),
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+
+    // Three cycles of 100 plus a single fee, held against the first cycle
+    let ids = client.create_recurring_daily_quest(&creator, &template_id, &3, &100, &1, &asset);
+    let token = TokenClient::new(&env, &asset);
+    assert_eq!(token.balance(&creator), 10_000 - 300 - 250);
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+    assert_eq!(client.sweep_creation_fee(&ids.get(0).unwrap()), 250);
+    assert_eq!(client.sweep_creation_fee(&ids.get(1).unwrap()), 0);
+
+    // The whole series used one slot of the creator's limit
+    assert_eq!(
+        client.try_register_quest(
+            &symbol_short!("quest1"),
+            &creator,
+            &asset,
+            &100_i128,
+            &verifier,
+            &(env.ledger().timestamp() + 86400),
+            &10,
+            &QuestDifficulty::Beginner,
+        ),
+        Err(Ok(Error::QuestCreationLimitReached))
+    );
+}
@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env, Symbol,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+
+    (client, creator, verifier, reward_asset)
+}
+
+fn create_quest(
+    client: &EarnQuestContractClient,
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    verifier: &Address,
+    reward_asset: &Address,
+    difficulty: QuestDifficulty,
+) {
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        quest_id,
+        creator,
+        reward_asset,
+        &1000_i128,
+        verifier,
+        &deadline,
+        &10,
+        &difficulty,
+    );
+}
+
+#[test]
+fn test_xp_multiplier_applied_on_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("hard");
+    create_quest(
+        &client,
+        &env,
+        &quest_id,
+        &creator,
+        &verifier,
+        &reward_asset,
+        QuestDifficulty::Advanced,
+    );
+
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+    client.approve_submission(&quest_id, &submitter, &verifier);
+
+    // Advanced quests award 4x the base XP
+    let stats = client.get_user_stats(&submitter);
+    assert_eq!(stats.total_xp, 400);
+}
+
+#[test]
+fn test_get_quests_by_difficulty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let easy = symbol_short!("easy");
+    let expert = symbol_short!("expert");
+    create_quest(
+        &client,
+        &env,
+        &easy,
+        &creator,
+        &verifier,
+        &reward_asset,
+        QuestDifficulty::Beginner,
+    );
+    create_quest(
+        &client,
+        &env,
+        &expert,
+        &creator,
+        &verifier,
+        &reward_asset,
+        QuestDifficulty::Expert,
+    );
+
+    let beginner = client.get_quests_by_difficulty(&QuestDifficulty::Beginner);
+    assert_eq!(beginner.len(), 1);
+    assert_eq!(beginner.get(0).unwrap(), easy);
+
+    let experts = client.get_quests_by_difficulty(&QuestDifficulty::Expert);
+    assert_eq!(experts.len(), 1);
+    assert_eq!(experts.get(0).unwrap(), expert);
+
+    assert!(client
+        .get_quests_by_difficulty(&QuestDifficulty::Intermediate)
+        .is_empty());
+}
+
+#[test]
+fn test_xp_by_difficulty_breakdown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let easy = symbol_short!("easy");
+    let expert = symbol_short!("expert");
+    create_quest(
+        &client,
+        &env,
+        &easy,
+        &creator,
+        &verifier,
+        &reward_asset,
+        QuestDifficulty::Beginner,
+    );
+    create_quest(
+        &client,
+        &env,
+        &expert,
+        &creator,
+        &verifier,
+        &reward_asset,
+        QuestDifficulty::Expert,
+    );
+
+    let submitter = Address::generate(&env);
+    for quest_id in [&easy, &expert] {
+        client.submit_proof(quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+        client.approve_submission(quest_id, &submitter, &verifier);
+    }
+
+    let breakdown = client.get_xp_by_difficulty(&submitter);
+    assert_eq!(breakdown.len(), 4);
+    assert_eq!(breakdown.get(0).unwrap(), (QuestDifficulty::Beginner, 100));
+    assert_eq!(
+        breakdown.get(1).unwrap(),
+        (QuestDifficulty::Intermediate, 0)
+    );
+    assert_eq!(breakdown.get(2).unwrap(), (QuestDifficulty::Advanced, 0));
+    assert_eq!(breakdown.get(3).unwrap(), (QuestDifficulty::Expert, 800));
+}
@@ -1,19 +1,29 @@
 #![cfg(test)]
 
-use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestStatus};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty, QuestStatus};
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
     Address, BytesN, Env,
 };
 
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
 fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
     let contract_id = env.register_contract(None, EarnQuestContract);
     let client = EarnQuestContractClient::new(env, &contract_id);
 
     let creator = Address::generate(env);
     let verifier = Address::generate(env);
-    let reward_asset = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
 
     (client, creator, verifier, reward_asset)
 }
@@ -39,6 +49,7 @@ fn test_register_quest_with_past_deadline_fails() {
         &verifier,
         &past_deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     assert!(result.is_err());
@@ -63,6 +74,7 @@ fn test_register_quest_with_current_time_deadline_fails() {
         &verifier,
         &current_time,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     assert!(result.is_err());
@@ -87,6 +99,7 @@ fn test_register_quest_with_future_deadline_succeeds() {
         &verifier,
         &future_deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Verify quest exists and is active
@@ -114,6 +127,7 @@ fn test_check_expired_returns_false_before_deadline() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Check if expired - should be false
@@ -140,6 +154,7 @@ fn test_check_expired_returns_true_after_deadline() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Advance time past deadline
@@ -171,6 +186,7 @@ fn test_submission_rejected_after_deadline() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Advance time past deadline
@@ -205,6 +221,7 @@ fn test_submission_accepted_before_deadline() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Submit proof before deadline - should succeed
@@ -237,6 +254,7 @@ fn test_manual_expire_quest_by_creator() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Manually expire quest
@@ -266,6 +284,7 @@ fn test_manual_expire_quest_by_non_creator_fails() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Try to expire quest with non-creator - should fail
@@ -294,6 +313,7 @@ fn test_cannot_expire_already_expired_quest() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Expire quest
@@ -323,6 +343,7 @@ fn test_cannot_expire_completed_quest() {
         &verifier,
         &deadline,
         &1,
+        &QuestDifficulty::Beginner,
     );
 
     // Submit and approve to complete quest
@@ -359,6 +380,7 @@ fn test_auto_expire_on_submission_attempt() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Verify quest is active
@@ -403,6 +425,7 @@ fn test_expired_quest_cannot_accept_submissions() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Manually expire quest
@@ -436,6 +459,7 @@ fn test_timestamp_validation_edge_case() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Should not be expired yet
@@ -486,6 +510,7 @@ fn test_multiple_quests_with_different_deadlines() {
         &verifier,
         &deadline_1,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Register quest 2 with long deadline
@@ -499,6 +524,7 @@ fn test_multiple_quests_with_different_deadlines() {
         &verifier,
         &deadline_2,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Advance time past first deadline but before second
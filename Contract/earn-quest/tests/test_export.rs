@@ -0,0 +1,371 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, BytesN, Env, Vec,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let verifier = Address::generate(env);
+    client.initialize(&admin);
+
+    let creator = Address::generate(env);
+    client.register_quest(
+        &symbol_short!("quest1"),
+        &creator,
+        &reward_token(env, &creator),
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    (client, admin, verifier)
+}
+
+#[test]
+fn test_export_user_stats_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, verifier) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    let active = Address::generate(&env);
+    let inactive = Address::generate(&env);
+
+    client.submit_proof(&quest_id, &active, &BytesN::from_array(&env, &[1u8; 32]));
+    client.approve_submission(&quest_id, &active, &verifier);
+
+    let stats =
+        client.export_user_stats_batch(&admin, &vec![&env, active.clone(), inactive.clone()]);
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats.get(0).unwrap().total_xp, 100);
+    assert_eq!(stats.get(1).unwrap().address, inactive);
+    assert_eq!(stats.get(1).unwrap().total_xp, 0);
+}
+
+#[test]
+fn test_export_user_stats_batch_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _verifier) = setup_env(&env);
+
+    let mut users = Vec::new(&env);
+    for _ in 0..101 {
+        users.push_back(Address::generate(&env));
+    }
+    assert!(client.try_export_user_stats_batch(&admin, &users).is_err());
+
+    // Non-admin cannot export
+    let result = client.try_export_user_stats_batch(&Address::generate(&env), &vec![&env]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_export_platform_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, verifier) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+
+    client.submit_proof(&quest_id, &winner, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_proof(&quest_id, &loser, &BytesN::from_array(&env, &[2u8; 32]));
+    client.approve_submission(&quest_id, &winner, &verifier);
+    client.reject_submission(&quest_id, &loser, &verifier);
+
+    let snapshot = client.export_platform_snapshot(&admin);
+    assert_eq!(snapshot.quest_count, 1);
+    assert_eq!(snapshot.stats.total_quests, 1);
+    assert_eq!(snapshot.stats.total_submissions, 2);
+    assert_eq!(snapshot.stats.total_approved, 1);
+    assert_eq!(snapshot.stats.total_rejected, 1);
+    assert_eq!(snapshot.top_users, vec![&env, (winner, 100)]);
+
+    // Snapshots are rate limited
+    assert!(client.try_export_platform_snapshot(&admin).is_err());
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert!(client.try_export_platform_snapshot(&admin).is_ok());
+}
+
+#[test]
+fn test_leaderboard_ordering() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, verifier) = setup_env(&env);
+    let creator = Address::generate(&env);
+    client.register_quest(
+        &symbol_short!("hard"),
+        &creator,
+        &reward_token(&env, &creator),
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Expert,
+    );
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.submit_proof(
+        &symbol_short!("quest1"),
+        &second,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.approve_submission(&symbol_short!("quest1"), &second, &verifier);
+    client.submit_proof(
+        &symbol_short!("hard"),
+        &first,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+    client.approve_submission(&symbol_short!("hard"), &first, &verifier);
+
+    let board = client.get_leaderboard(&10);
+    assert_eq!(board, vec![&env, (first, 800), (second.clone(), 100)]);
+    assert_eq!(client.get_leaderboard(&1).len(), 1);
+    assert_eq!(client.get_user_rank(&second), Some(2));
+    assert_eq!(client.get_user_rank(&Address::generate(&env)), None);
+}
+
+#[test]
+fn test_monthly_leaderboard_reset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, verifier) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    let veteran = Address::generate(&env);
+    client.submit_proof(&quest_id, &veteran, &BytesN::from_array(&env, &[1u8; 32]));
+    client.approve_submission(&quest_id, &veteran, &verifier);
+    assert_eq!(
+        client.get_monthly_leaderboard(&10),
+        vec![&env, (veteran.clone(), 100)]
+    );
+
+    // Ending the season archives the top users and clears monthly XP
+    env.ledger().with_mut(|li| li.timestamp += 86400 * 30);
+    client.reset_monthly_leaderboard(&admin);
+    assert_eq!(client.get_monthly_leaderboard(&10).len(), 0);
+    let archive = client.get_monthly_archive();
+    assert_eq!(archive.len(), 1);
+    assert_eq!(
+        archive.get(0).unwrap(),
+        (env.ledger().timestamp(), vec![&env, (veteran.clone(), 100)])
+    );
+
+    // The global leaderboard is unaffected
+    assert_eq!(client.get_user_rank(&veteran), Some(1));
+
+    // XP earned in the new season counts from zero
+    let next = symbol_short!("quest2");
+    let creator = Address::generate(&env);
+    client.register_quest(
+        &next,
+        &creator,
+        &reward_token(&env, &creator),
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+    client.submit_proof(&next, &veteran, &BytesN::from_array(&env, &[2u8; 32]));
+    client.approve_submission(&next, &veteran, &verifier);
+    assert_eq!(
+        client.get_monthly_leaderboard(&10),
+        vec![&env, (veteran.clone(), 100)]
+    );
+
+    // Only the admin can end a season
+    assert!(client
+        .try_reset_monthly_leaderboard(&Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_xp_percentile_and_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, verifier) = setup_env(&env);
+    let creator = Address::generate(&env);
+    client.register_quest(
+        &symbol_short!("hard"),
+        &creator,
+        &reward_token(&env, &creator),
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Expert,
+    );
+
+    let mut users = Vec::new(&env);
+    for i in 0..10u8 {
+        let user = Address::generate(&env);
+        client.submit_proof(
+            &symbol_short!("quest1"),
+            &user,
+            &BytesN::from_array(&env, &[i; 32]),
+        );
+        client.approve_submission(&symbol_short!("quest1"), &user, &verifier);
+        users.push_back(user);
+    }
+    let top = users.get(9).unwrap();
+    client.submit_proof(
+        &symbol_short!("hard"),
+        &top,
+        &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    client.approve_submission(&symbol_short!("hard"), &top, &verifier);
+    assert_eq!(client.get_total_user_count(), 10);
+
+    // Ranks map to (rank - 1) * 10000 / total_users
+    assert_eq!(client.get_xp_percentile(&top), 0);
+    assert_eq!(client.get_user_rank(&users.get(1).unwrap()), Some(3));
+    assert_eq!(client.get_xp_percentile(&users.get(1).unwrap()), 2000);
+    assert_eq!(client.get_xp_percentile(&Address::generate(&env)), 0);
+
+    assert_eq!(
+        client.get_xp_distribution(),
+        vec![
+            &env,
+            (100, 9),
+            (100, 7),
+            (100, 5),
+            (100, 2),
+            (900, 1),
+            (0, 0),
+            (0, 0)
+        ]
+    );
+}
+
+#[test]
+fn test_xp_decay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Keep the contract instance alive while the ledger advances
+    env.ledger()
+        .with_mut(|li| li.min_persistent_entry_ttl = 1_000_000);
+
+    let (client, admin, verifier) = setup_env(&env);
+    assert_eq!(
+        client.try_configure_xp_decay(&admin, &10_001, &7),
+        Err(Ok(Error::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_configure_xp_decay(&admin, &1000, &0),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.configure_xp_decay(&admin, &1000, &7);
+    assert_eq!(client.get_xp_decay_config(), (1000, 7));
+
+    let user = Address::generate(&env);
+    client.submit_proof(
+        &symbol_short!("quest1"),
+        &user,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.approve_submission(&symbol_short!("quest1"), &user, &verifier);
+
+    // Nothing decays within the first interval
+    env.ledger().with_mut(|li| li.timestamp += 6 * 86_400);
+    assert_eq!(client.get_user_stats(&user).total_xp, 100);
+
+    // Two full intervals compound: 100 -> 90 -> 81, applied on read
+    env.ledger().with_mut(|li| li.timestamp += 9 * 86_400);
+    assert_eq!(client.get_user_stats(&user).total_xp, 81);
+    assert_eq!(client.get_leaderboard(&1), vec![&env, (user.clone(), 81)]);
+
+    // Decay already applied is not applied again
+    assert_eq!(client.apply_xp_decay(&user), 0);
+    env.ledger().with_mut(|li| li.timestamp += 6 * 86_400);
+    assert_eq!(client.apply_xp_decay(&user), 8);
+    assert_eq!(client.get_user_stats(&user).total_xp, 73);
+}
+
+#[test]
+fn test_user_rank_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 10);
+
+    let (client, _admin, verifier) = setup_env(&env);
+    let creator = Address::generate(&env);
+    for quest_id in [symbol_short!("q1"), symbol_short!("q2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(&env, &creator),
+            &1000_i128,
+            &verifier,
+            &(86400 * 100),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    let leader = Address::generate(&env);
+    let climber = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&symbol_short!("q1"), &leader, &proof);
+    client.submit_proof(&symbol_short!("q1"), &climber, &proof);
+    client.submit_proof(&symbol_short!("q2"), &climber, &proof);
+
+    // Day 10: the climber ranks second behind an earlier tie
+    client.approve_submission(&symbol_short!("q1"), &leader, &verifier);
+    client.approve_submission(&symbol_short!("q1"), &climber, &verifier);
+
+    // Day 12: the climber takes the lead
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 12);
+    client.approve_submission(&symbol_short!("q2"), &climber, &verifier);
+
+    assert_eq!(
+        client.get_user_rank_history(&climber, &5),
+        vec![
+            &env,
+            (8, None),
+            (9, None),
+            (10, Some(2)),
+            (11, Some(2)),
+            (12, Some(1))
+        ]
+    );
+    assert_eq!(client.get_user_rank_history(&climber, &365).len(), 13);
+
+    // Unranked a week ago, first today
+    assert_eq!(client.get_rank_change_7d(&climber), -100);
+
+    // Day 18: compared against rank 2 on day 11
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 18);
+    assert_eq!(client.get_rank_change_7d(&climber), -1);
+    assert_eq!(client.get_rank_change_7d(&Address::generate(&env)), 0);
+
+    // History is capped to 90 days
+    env.ledger().with_mut(|li| li.timestamp = 86400 * 200);
+    let history = client.get_user_rank_history(&climber, &365);
+    assert_eq!(history.len(), 90);
+    assert_eq!(history.last().unwrap(), (200, None));
+}
@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty, SubmissionStatus};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env, Symbol,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    client.initialize(&admin);
+
+    for quest_id in [symbol_short!("quest1"), symbol_short!("quest2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(env, &creator),
+            &1000_i128,
+            &verifier,
+            &(env.ledger().timestamp() + 86400),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    (client, admin, creator, verifier)
+}
+
+fn submit(client: &EarnQuestContractClient, env: &Env, quest_id: &Symbol, submitter: &Address) {
+    client.submit_proof(quest_id, submitter, &BytesN::from_array(env, &[1u8; 32]));
+}
+
+#[test]
+fn test_mark_fraudulent_bans_and_penalizes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _creator, verifier) = setup_env(&env);
+    let quest1 = symbol_short!("quest1");
+    let quest2 = symbol_short!("quest2");
+    let submitter = Address::generate(&env);
+
+    // Earn some XP first
+    submit(&client, &env, &quest1, &submitter);
+    client.approve_submission(&quest1, &submitter, &verifier);
+    assert_eq!(client.get_user_stats(&submitter).total_xp, 100);
+
+    submit(&client, &env, &quest2, &submitter);
+    client.mark_fraudulent(&quest2, &submitter, &verifier, &admin);
+
+    assert_eq!(
+        client.get_submission(&quest2, &submitter).status,
+        SubmissionStatus::Fraudulent
+    );
+    assert!(client.is_fraudulent(&submitter));
+    assert_eq!(client.get_user_stats(&submitter).total_xp, 0);
+}
+
+#[test]
+fn test_fraudulent_user_cannot_submit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _creator, verifier) = setup_env(&env);
+    let submitter = Address::generate(&env);
+
+    submit(&client, &env, &symbol_short!("quest1"), &submitter);
+    client.mark_fraudulent(&symbol_short!("quest1"), &submitter, &verifier, &admin);
+
+    let result = client.try_submit_proof(
+        &symbol_short!("quest2"),
+        &submitter,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_mark_fraudulent_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _creator, verifier) = setup_env(&env);
+    let submitter = Address::generate(&env);
+    submit(&client, &env, &symbol_short!("quest1"), &submitter);
+
+    let not_admin = Address::generate(&env);
+    let result =
+        client.try_mark_fraudulent(&symbol_short!("quest1"), &submitter, &verifier, &not_admin);
+    assert!(result.is_err());
+    assert!(!client.is_fraudulent(&submitter));
+}
+
+#[test]
+fn test_freeze_user_holds_submissions_and_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _creator, verifier) = setup_env(&env);
+    let quest1 = symbol_short!("quest1");
+    let quest2 = symbol_short!("quest2");
+    let submitter = Address::generate(&env);
+
+    submit(&client, &env, &quest1, &submitter);
+    client.freeze_user(&admin, &submitter);
+    assert!(client.is_user_frozen(&submitter));
+    assert_eq!(client.get_frozen_users().len(), 1);
+
+    // Frozen users cannot submit
+    let result =
+        client.try_submit_proof(&quest2, &submitter, &BytesN::from_array(&env, &[2u8; 32]));
+    assert!(result.is_err());
+
+    // Approval is recorded but the payout is held
+    client.approve_submission(&quest1, &submitter, &verifier);
+    assert_eq!(
+        client.get_submission(&quest1, &submitter).status,
+        SubmissionStatus::Approved
+    );
+
+    client.unfreeze_user(&admin, &submitter);
+    assert!(!client.is_user_frozen(&submitter));
+    assert_eq!(client.get_frozen_users().len(), 0);
+    assert_eq!(client.get_claimable_balance(&submitter), 1000);
+    submit(&client, &env, &quest2, &submitter);
+
+    // Only the admin can freeze
+    let result = client.try_freeze_user(&Address::generate(&env), &submitter);
+    assert!(result.is_err());
+    assert!(!client.is_fraudulent(&submitter));
+}
@@ -0,0 +1,297 @@
+#![cfg(test)]
+
+use earn_quest::{
+    EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty, QuestStatus,
+    SubmissionStatus,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env, Symbol,
+};
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let reward_asset = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    (client, creator, verifier, reward_asset)
+}
+
+#[test]
+fn test_fund_additional_slots() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    StellarAssetClient::new(&env, &reward_asset).mint(&creator, &5000);
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &100_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    client.fund_additional_slots(&quest_id, &creator, &10);
+
+    // Slots increased and reward pool escrowed
+    let quest = client.get_quest(&quest_id);
+    assert_eq!(quest.max_participants, 20);
+    assert_eq!(client.get_quest_escrow(&quest_id), 2000);
+
+    let token = TokenClient::new(&env, &reward_asset);
+    assert_eq!(token.balance(&creator), 3000);
+    assert_eq!(token.balance(&client.address), 2000);
+}
+
+#[test]
+fn test_fund_additional_slots_non_creator_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let other = Address::generate(&env);
+    let asset_client = StellarAssetClient::new(&env, &reward_asset);
+    asset_client.mint(&creator, &5000);
+    asset_client.mint(&other, &5000);
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &100_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let result = client.try_fund_additional_slots(&quest_id, &other, &5);
+    assert!(result.is_err());
+    assert_eq!(client.get_quest(&quest_id).max_participants, 10);
+}
+
+#[test]
+fn test_fund_additional_slots_inactive_quest_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    StellarAssetClient::new(&env, &reward_asset).mint(&creator, &5000);
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &100_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+    client.update_quest_status(&quest_id, &creator, &QuestStatus::Paused);
+
+    let result = client.try_fund_additional_slots(&quest_id, &creator, &5);
+    assert!(result.is_err());
+    assert_eq!(client.get_quest_escrow(&quest_id), 1000);
+}
+
+#[test]
+fn test_drain_orphaned_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_treasury(&admin, &treasury);
+
+    let asset_client = StellarAssetClient::new(&env, &reward_asset);
+    asset_client.mint(&creator, &5000);
+
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &100_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+    client.fund_additional_slots(&quest_id, &creator, &5);
+    assert_eq!(client.get_orphaned_balance(&reward_asset), 0);
+
+    // Tokens sent straight to the contract are not part of any escrow
+    asset_client.mint(&client.address, &250);
+    assert_eq!(client.get_orphaned_balance(&reward_asset), 250);
+
+    // Only the admin can drain
+    assert!(client
+        .try_drain_orphaned_escrow(&creator, &reward_asset)
+        .is_err());
+
+    assert_eq!(client.drain_orphaned_escrow(&admin, &reward_asset), 250);
+    let token = TokenClient::new(&env, &reward_asset);
+    assert_eq!(token.balance(&treasury), 250);
+    assert_eq!(token.balance(&client.address), 1500);
+    assert_eq!(client.get_quest_escrow(&quest_id), 1500);
+    assert_eq!(client.drain_orphaned_escrow(&admin, &reward_asset), 0);
+}
+
+#[test]
+fn test_auto_payout_on_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    StellarAssetClient::new(&env, &reward_asset).mint(&creator, &5000);
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &100_i128,
+        &verifier,
+        &deadline,
+        &1,
+        &QuestDifficulty::Beginner,
+    );
+
+    assert!(!client.auto_payout_on_approval(&quest_id));
+    assert_eq!(
+        client.try_set_auto_payout(&quest_id, &verifier, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_auto_payout(&quest_id, &creator, &true);
+    assert!(client.auto_payout_on_approval(&quest_id));
+
+    let paid = Address::generate(&env);
+    let late = Address::generate(&env);
+    client.submit_proof(&quest_id, &paid, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_proof(&quest_id, &late, &BytesN::from_array(&env, &[2u8; 32]));
+
+    // Approval pays the reward out of escrow in the same call
+    client.approve_submission(&quest_id, &paid, &verifier);
+    let token = TokenClient::new(&env, &reward_asset);
+    assert_eq!(token.balance(&paid), 100);
+    assert_eq!(client.get_quest_escrow(&quest_id), 0);
+    assert_eq!(
+        client.get_submission(&quest_id, &paid).status,
+        SubmissionStatus::Paid
+    );
+
+    // Escrow holds one reward per slot, so the quest fills before it runs dry
+    assert_eq!(
+        client.try_approve_submission(&quest_id, &late, &verifier),
+        Err(Ok(Error::QuestFull))
+    );
+    assert_eq!(
+        client.get_submission(&quest_id, &late).status,
+        SubmissionStatus::Pending
+    );
+    assert_eq!(client.get_quest(&quest_id).total_claims, 1);
+
+    // The payout mode is fixed after the first approval
+    assert_eq!(
+        client.try_set_auto_payout(&quest_id, &creator, &false),
+        Err(Ok(Error::AlreadyApproved))
+    );
+}
+
+#[test]
+fn test_recurring_daily_quest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    StellarAssetClient::new(&env, &reward_asset).mint(&creator, &1000);
+    let template_id = client.create_verification_template(
+        &verifier,
+        &symbol_short!("daily"),
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+
+    assert_eq!(
+        client.try_create_recurring_daily_quest(&creator, &99, &3, &100, &2, &reward_asset),
+        Err(Ok(Error::TemplateNotFound))
+    );
+    for cycles in [0, 31] {
+        assert_eq!(
+            client.try_create_recurring_daily_quest(
+                &creator,
+                &template_id,
+                &cycles,
+                &100,
+                &2,
+                &reward_asset
+            ),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+
+    let start = env.ledger().timestamp();
+    let ids =
+        client.create_recurring_daily_quest(&creator, &template_id, &3, &100, &2, &reward_asset);
+    assert_eq!(
+        ids,
+        soroban_sdk::vec![
+            &env,
+            Symbol::new(&env, "daily1_1"),
+            Symbol::new(&env, "daily1_2"),
+            Symbol::new(&env, "daily1_3"),
+        ]
+    );
+
+    // Every cycle is escrowed upfront and expires a day after the previous one
+    let token = TokenClient::new(&env, &reward_asset);
+    assert_eq!(token.balance(&creator), 400);
+    for (i, quest_id) in ids.iter().enumerate() {
+        let quest = client.get_quest(&quest_id);
+        assert_eq!(quest.deadline, start + (i as u64 + 1) * 86400);
+        assert_eq!(quest.verifier, verifier);
+        assert_eq!(quest.verification_template_id, Some(template_id));
+        assert_eq!(quest.max_participants, 2);
+        assert_eq!(client.get_quest_escrow(&quest_id), 200);
+    }
+
+    let user = Address::generate(&env);
+    let first = ids.get(0).unwrap();
+    client.submit_proof(&first, &user, &BytesN::from_array(&env, &[1u8; 32]));
+    client.approve_submission(&first, &user, &verifier);
+    assert_eq!(client.get_series_progress(&1, &user), (1, 3));
+
+    // The keeper moves the series along as each day's quest expires
+    assert_eq!(client.advance_daily_series(&1), first);
+    env.ledger().with_mut(|l| l.timestamp = start + 86400);
+    assert_eq!(client.advance_daily_series(&1), ids.get(1).unwrap());
+    env.ledger().with_mut(|l| l.timestamp = start + 10 * 86400);
+    assert_eq!(client.advance_daily_series(&1), ids.get(2).unwrap());
+    assert_eq!(client.get_daily_series(&1).active_index, 2);
+    assert_eq!(
+        client.try_advance_daily_series(&2),
+        Err(Ok(Error::SeriesNotFound))
+    );
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(
+    env: &Env,
+) -> (
+    EarnQuestContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+    client.initialize(&admin);
+
+    (client, admin, creator, verifier, reward_asset)
+}
+
+#[test]
+fn test_quest_creation_blocked_until_go_live() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (client, admin, creator, verifier, reward_asset) = setup_env(&env);
+    assert_eq!(client.get_deployment_ledger(), Some(100));
+
+    // Live by default until a go-live ledger is configured
+    assert!(client.is_live());
+
+    client.set_go_live_ledger(&admin, &200);
+    assert!(!client.is_live());
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400;
+    let result = client.try_register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+    assert_eq!(result, Err(Ok(Error::ContractNotLive)));
+
+    // Reaching the go-live ledger enables quest creation
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    assert!(client.is_live());
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+}
+
+#[test]
+fn test_go_live_ledger_cannot_decrease() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _creator, _verifier, _reward_asset) = setup_env(&env);
+
+    client.set_go_live_ledger(&admin, &500);
+    assert_eq!(
+        client.try_set_go_live_ledger(&admin, &400),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.set_go_live_ledger(&admin, &600);
+
+    // Only the admin can configure it
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_set_go_live_ledger(&other, &700),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+#[test]
+fn test_submissions_indexed_by_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 10);
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_token(&env, &creator),
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    // Two submissions at ledger 10, one at ledger 11
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.submit_proof(&quest_id, &alice, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_proof(&quest_id, &bob, &BytesN::from_array(&env, &[2u8; 32]));
+
+    env.ledger().with_mut(|li| li.sequence_number = 11);
+    let carol = Address::generate(&env);
+    client.submit_proof(&quest_id, &carol, &BytesN::from_array(&env, &[3u8; 32]));
+
+    let at_10 = client.get_submissions_at_ledger(&10);
+    assert_eq!(at_10.len(), 2);
+    assert_eq!(at_10.get(0).unwrap().submitter, alice);
+    assert_eq!(at_10.get(1).unwrap().submitter, bob);
+
+    let at_11 = client.get_submissions_at_ledger(&11);
+    assert_eq!(at_11.len(), 1);
+    assert_eq!(at_11.get(0).unwrap().submitter, carol);
+
+    assert_eq!(client.get_submissions_at_ledger(&12).len(), 0);
+}
@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, Symbol,
+};
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    (client, admin)
+}
+
+#[test]
+fn test_metadata_populated_on_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin) = setup_env(&env);
+
+    assert_eq!(
+        client.get_contract_metadata(&Symbol::new(&env, "version")),
+        Some(Bytes::from_slice(&env, b"1.0.0"))
+    );
+
+    // Features are a big-endian u32 bitmask; bit 0 is referrals
+    let features = client
+        .get_contract_metadata(&Symbol::new(&env, "features"))
+        .unwrap();
+    assert_eq!(features.len(), 4);
+    assert_eq!(features.get(3).unwrap() & 1, 1);
+
+    assert_eq!(client.get_all_metadata_keys().len(), 2);
+}
+
+#[test]
+fn test_set_contract_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = setup_env(&env);
+    let audit_key = Symbol::new(&env, "audit_url");
+    let audit_url = Bytes::from_slice(&env, b"https://example.com/audit.pdf");
+
+    assert_eq!(client.get_contract_metadata(&audit_key), None);
+    client.set_contract_metadata(&admin, &audit_key, &audit_url);
+    assert_eq!(client.get_contract_metadata(&audit_key), Some(audit_url));
+    assert!(client.get_all_metadata_keys().contains(&audit_key));
+
+    // Existing entries can be overwritten
+    let version = Symbol::new(&env, "version");
+    client.set_contract_metadata(&admin, &version, &Bytes::from_slice(&env, b"1.1.0"));
+    assert_eq!(
+        client.get_contract_metadata(&version),
+        Some(Bytes::from_slice(&env, b"1.1.0"))
+    );
+    assert_eq!(client.get_all_metadata_keys().len(), 3);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_contract_metadata(&stranger, &version, &Bytes::new(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_rotation_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = setup_env(&env);
+    let new_admin = Address::generate(&env);
+    let key = Symbol::new(&env, "audit_url");
+    let value = Bytes::from_slice(&env, b"https://example.com/audit");
+
+    // Only the admin can propose
+    assert_eq!(
+        client.try_propose_admin_rotation(&new_admin, &new_admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    let effective_at = client.propose_admin_rotation(&admin, &new_admin);
+    assert_eq!(effective_at, env.ledger().timestamp() + 48 * 3600);
+    assert_eq!(
+        client.get_pending_rotation(),
+        Some((new_admin.clone(), effective_at))
+    );
+
+    // The timelock must pass, and only the proposed admin can confirm
+    assert_eq!(
+        client.try_confirm_admin_rotation(&new_admin),
+        Err(Ok(Error::RotationTimelockActive))
+    );
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+    assert_eq!(
+        client.try_confirm_admin_rotation(&Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // A cancelled rotation cannot be confirmed
+    client.cancel_admin_rotation(&admin);
+    assert_eq!(client.get_pending_rotation(), None);
+    assert_eq!(
+        client.try_confirm_admin_rotation(&new_admin),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let effective_at = client.propose_admin_rotation(&admin, &new_admin);
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+    client.confirm_admin_rotation(&new_admin);
+    assert_eq!(client.get_pending_rotation(), None);
+
+    // The role has moved
+    assert!(client
+        .try_set_contract_metadata(&admin, &key, &value)
+        .is_err());
+    client.set_contract_metadata(&new_admin, &key, &value);
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    for quest_id in [symbol_short!("quest1"), symbol_short!("quest2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(env, &creator),
+            &1000_i128,
+            &verifier,
+            &(env.ledger().timestamp() + 86400),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    (client, verifier)
+}
+
+#[test]
+fn test_pending_counts_track_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, verifier) = setup_env(&env);
+    let quest1 = symbol_short!("quest1");
+    let quest2 = symbol_short!("quest2");
+    assert_eq!(client.get_global_pending_count(), 0);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest1, &alice, &proof);
+    client.submit_proof(&quest1, &bob, &proof);
+    client.submit_proof(&quest2, &alice, &proof);
+
+    assert_eq!(client.get_global_pending_count(), 3);
+    assert_eq!(client.get_quest_pending_count(&quest1), 2);
+    assert_eq!(client.get_quest_pending_count(&quest2), 1);
+
+    client.approve_submission(&quest1, &alice, &verifier);
+    client.reject_submission(&quest1, &bob, &verifier);
+
+    assert_eq!(client.get_global_pending_count(), 1);
+    assert_eq!(client.get_quest_pending_count(&quest1), 0);
+    assert_eq!(client.get_quest_pending_count(&quest2), 1);
+}
+
+#[test]
+fn test_failed_approval_keeps_pending_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _verifier) = setup_env(&env);
+    let quest1 = symbol_short!("quest1");
+    let alice = Address::generate(&env);
+    client.submit_proof(&quest1, &alice, &BytesN::from_array(&env, &[1u8; 32]));
+
+    let impostor = Address::generate(&env);
+    assert!(client
+        .try_approve_submission(&quest1, &alice, &impostor)
+        .is_err());
+    assert_eq!(client.get_quest_pending_count(&quest1), 1);
+}
@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+
+    (client, creator, verifier, reward_asset)
+}
+
+/// Sign the permit message the contract expects for a submission
+fn sign_permit(
+    env: &Env,
+    client: &EarnQuestContractClient,
+    signer: &SigningKey,
+    submitter: &Address,
+    proof_hash: &BytesN<32>,
+    expiry: u64,
+) -> BytesN<64> {
+    let message =
+        client.get_permit_message(&symbol_short!("quest1"), submitter, proof_hash, &expiry);
+    BytesN::from_array(env, &signer.sign(&message.to_array()).to_bytes())
+}
+
+#[test]
+fn test_submit_proof_with_permit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(86400 * 10),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let submitter = Address::generate(&env);
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = sign_permit(&env, &client, &signer, &submitter, &proof_hash, 2000);
+
+    // The submitter must register a permit key first
+    assert_eq!(
+        client.try_submit_proof_with_permit(&quest_id, &submitter, &proof_hash, &signature, &2000),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.register_permit_key(
+        &submitter,
+        &BytesN::from_array(&env, &signer.verifying_key().to_bytes()),
+    );
+
+    // Submitted without the submitter's live auth
+    env.set_auths(&[]);
+    client.submit_proof_with_permit(&quest_id, &submitter, &proof_hash, &signature, &2000);
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).proof_hash,
+        proof_hash
+    );
+
+    // Replaying the permit is refused
+    assert_eq!(
+        client.try_submit_proof_with_permit(&quest_id, &submitter, &proof_hash, &signature, &2000),
+        Err(Ok(Error::PermitAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_permit_expired_or_forged() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(86400 * 10),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let submitter = Address::generate(&env);
+    let signer = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_permit_key(
+        &submitter,
+        &BytesN::from_array(&env, &signer.verifying_key().to_bytes()),
+    );
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    // Expiry must lie in the future
+    let signature = sign_permit(&env, &client, &signer, &submitter, &proof_hash, 1000);
+    assert_eq!(
+        client.try_submit_proof_with_permit(&quest_id, &submitter, &proof_hash, &signature, &1000),
+        Err(Ok(Error::PermitExpired))
+    );
+
+    // A signature from another key fails verification
+    let forger = SigningKey::from_bytes(&[9u8; 32]);
+    let forged = sign_permit(&env, &client, &forger, &submitter, &proof_hash, 2000);
+    assert!(client
+        .try_submit_proof_with_permit(&quest_id, &submitter, &proof_hash, &forged, &2000)
+        .is_err());
+    assert!(client.try_get_submission(&quest_id, &submitter).is_err());
+}
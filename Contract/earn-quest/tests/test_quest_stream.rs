@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, vec, Address, BytesN, Env,
+    Symbol,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    for quest_id in [symbol_short!("day1"), symbol_short!("day2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_token(env, &creator),
+            &1000_i128,
+            &verifier,
+            &(env.ledger().timestamp() + 86400 * 7),
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    (client, creator, verifier)
+}
+
+fn complete(
+    client: &EarnQuestContractClient,
+    env: &Env,
+    quest_id: &Symbol,
+    user: &Address,
+    verifier: &Address,
+) {
+    client.submit_proof(quest_id, user, &BytesN::from_array(env, &[1u8; 32]));
+    client.approve_submission(quest_id, user, verifier);
+}
+
+#[test]
+fn test_stream_completion_awards_bonus_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier) = setup_env(&env);
+    let quest_ids = vec![&env, symbol_short!("day1"), symbol_short!("day2")];
+    let stream_id = client.create_stream(&creator, &quest_ids, &symbol_short!("week1"), &200);
+    assert_eq!(client.get_stream(&stream_id).quest_ids, quest_ids);
+
+    let user = Address::generate(&env);
+    complete(&client, &env, &symbol_short!("day1"), &user, &verifier);
+    assert!(!client.check_stream_completion(&stream_id, &user));
+
+    complete(&client, &env, &symbol_short!("day2"), &user, &verifier);
+    assert!(client.check_stream_completion(&stream_id, &user));
+    assert_eq!(client.get_user_stats(&user).total_xp, 400);
+
+    // Checking again does not award the bonus twice
+    assert!(client.check_stream_completion(&stream_id, &user));
+    let stats = client.get_user_stats(&user);
+    assert_eq!(stats.total_xp, 400);
+    assert_eq!(stats.quests_completed, 2);
+}
+
+#[test]
+fn test_create_stream_with_foreign_quest_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, _verifier) = setup_env(&env);
+    let other = Address::generate(&env);
+    let quest_ids = vec![&env, symbol_short!("day1")];
+
+    let result = client.try_create_stream(&other, &quest_ids, &symbol_short!("week1"), &250);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_create_empty_stream_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, _verifier) = setup_env(&env);
+    let result = client.try_create_stream(&creator, &vec![&env], &symbol_short!("week1"), &250);
+    assert!(result.is_err());
+    assert!(client.try_get_stream(&1).is_err());
+}
+
+#[test]
+fn test_stream_bonus_is_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, _verifier) = setup_env(&env);
+    let quest_ids = vec![&env, symbol_short!("day1"), symbol_short!("day2")];
+
+    // The bonus cannot exceed the base XP of the stream's quests
+    assert_eq!(
+        client.try_create_stream(&creator, &quest_ids, &symbol_short!("week1"), &201),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.create_stream(&creator, &quest_ids, &symbol_short!("week1"), &200);
+
+    // A quest cannot be stacked into a second stream
+    let overlap = vec![&env, symbol_short!("day2")];
+    assert_eq!(
+        client.try_create_stream(&creator, &overlap, &symbol_short!("week2"), &100),
+        Err(Ok(Error::InvalidConfig))
+    );
+}
@@ -1,7 +1,21 @@
 #![cfg(test)]
 
-use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestStatus};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty, QuestStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, Symbol,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
 
 fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
     let contract_id = env.register_contract(None, EarnQuestContract);
@@ -9,7 +23,7 @@ fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, A
 
     let creator = Address::generate(env);
     let verifier = Address::generate(env);
-    let reward_asset = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
 
     (client, creator, verifier, reward_asset)
 }
@@ -33,7 +47,8 @@ fn test_register_quest_success() {
         &reward_amount,
         &verifier,
         &deadline,
-        &10, // max_participants
+        &10, // max_participants,
+        &QuestDifficulty::Beginner,
     );
 
     // Verify quest exists by getting it
@@ -69,6 +84,7 @@ fn test_register_quest_duplicate_fails() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Try to register same quest again - should panic
@@ -80,6 +96,7 @@ fn test_register_quest_duplicate_fails() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
     assert!(result.is_err());
 }
@@ -103,6 +120,7 @@ fn test_register_quest_invalid_reward_fails() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
     assert!(result.is_err());
 }
@@ -126,6 +144,7 @@ fn test_pause_quest() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Pause quest
@@ -155,6 +174,7 @@ fn test_resume_quest() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
     client.update_quest_status(&quest_id, &creator, &QuestStatus::Paused);
 
@@ -185,6 +205,7 @@ fn test_complete_quest() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Complete quest
@@ -214,6 +235,7 @@ fn test_unauthorized_status_update() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Try to pause with non-creator - should fail
@@ -241,6 +263,7 @@ fn test_invalid_status_transition() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
     client.update_quest_status(&quest_id, &creator, &QuestStatus::Completed);
 
@@ -268,6 +291,7 @@ fn test_is_quest_active() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Should be active
@@ -305,9 +329,834 @@ fn test_quest_exists() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Should exist now
     let result = client.try_get_quest(&quest_id);
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_validate_quest_params() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+
+    let quest_id = symbol_short!("quest1");
+    let deadline = env.ledger().timestamp() + 86400;
+
+    // Valid parameters
+    assert!(client
+        .try_validate_quest_params(&quest_id, &1000_i128, &deadline, &10)
+        .is_ok());
+
+    // Invalid reward, participant limit and deadline
+    assert_eq!(
+        client.try_validate_quest_params(&quest_id, &0_i128, &deadline, &10),
+        Err(Ok(Error::InvalidRewardAmount))
+    );
+    assert_eq!(
+        client.try_validate_quest_params(&quest_id, &1000_i128, &deadline, &0),
+        Err(Ok(Error::InvalidParticipantLimit))
+    );
+    assert_eq!(
+        client.try_validate_quest_params(&quest_id, &1000_i128, &0, &10),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    // Validation does not create the quest
+    assert!(client.try_get_quest(&quest_id).is_err());
+
+    // Existing IDs are reported
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+    assert_eq!(
+        client.try_validate_quest_params(&quest_id, &1000_i128, &deadline, &10),
+        Err(Ok(Error::QuestAlreadyExists))
+    );
+}
+
+#[test]
+fn test_fill_percentage_and_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let deadline = env.ledger().timestamp() + 86400;
+    let quest_a = symbol_short!("quest_a");
+    let quest_b = symbol_short!("quest_b");
+    for (quest_id, max) in [(&quest_a, 4u32), (&quest_b, 10u32)] {
+        client.register_quest(
+            quest_id,
+            &creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &deadline,
+            &max,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    // 3 of 4 slots claimed on quest A, 1 of 10 on quest B
+    for (quest_id, claims) in [(&quest_a, 3u8), (&quest_b, 1u8)] {
+        for i in 0..claims {
+            let submitter = Address::generate(&env);
+            client.submit_proof(quest_id, &submitter, &BytesN::from_array(&env, &[i; 32]));
+            client.approve_submission(quest_id, &submitter, &verifier);
+        }
+    }
+
+    assert_eq!(client.compute_fill_percentage(&quest_a), 7500);
+    assert_eq!(client.compute_fill_percentage(&quest_b), 1000);
+
+    let above = client.get_quests_above_fill_pct(&5000);
+    assert_eq!(above.len(), 1);
+    assert_eq!(above.get(0).unwrap(), quest_a);
+    assert_eq!(client.get_quests_above_fill_pct(&1000).len(), 2);
+    assert_eq!(client.get_quests_above_fill_pct(&7600).len(), 0);
+}
+
+#[test]
+fn test_reward_per_slot_and_total_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &250_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &8,
+        &QuestDifficulty::Beginner,
+    );
+
+    // Every approval pays the full reward; the pool is one reward per slot
+    assert_eq!(client.get_reward_per_slot(&quest_id), 250);
+    assert_eq!(client.get_total_potential_payout(&quest_id), 2000);
+
+    let missing = symbol_short!("missing");
+    assert_eq!(
+        client.try_get_reward_per_slot(&missing),
+        Err(Ok(Error::QuestNotFound))
+    );
+    assert_eq!(
+        client.try_get_total_potential_payout(&missing),
+        Err(Ok(Error::QuestNotFound))
+    );
+}
+
+#[test]
+fn test_mark_completed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, _) = setup_env(&env);
+    let reward_asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &reward_asset).mint(&creator, &5000);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &5,
+        &QuestDifficulty::Beginner,
+    );
+    client.set_min_completions(&quest_id, &creator, &1);
+    assert_eq!(
+        client.try_set_min_completions(&quest_id, &creator, &6),
+        Err(Ok(Error::InvalidParticipantLimit))
+    );
+
+    // Not enough claims yet
+    assert_eq!(
+        client.try_mark_completed(&quest_id, &creator),
+        Err(Ok(Error::MinCompletionsNotReached))
+    );
+
+    let paid = Address::generate(&env);
+    client.submit_proof(&quest_id, &paid, &BytesN::from_array(&env, &[1u8; 32]));
+    client.approve_submission(&quest_id, &paid, &verifier);
+
+    // An approved reward must be claimed first
+    assert_eq!(
+        client.try_mark_completed(&quest_id, &creator),
+        Err(Ok(Error::UnsettledSubmissions))
+    );
+    client.claim_all_paid(&paid);
+
+    // A pending submission blocks completion
+    let pending = Address::generate(&env);
+    client.submit_proof(&quest_id, &pending, &BytesN::from_array(&env, &[2u8; 32]));
+    assert_eq!(
+        client.try_mark_completed(&quest_id, &creator),
+        Err(Ok(Error::UnsettledSubmissions))
+    );
+
+    client.reject_submission(&quest_id, &pending, &verifier);
+
+    // Only the creator can complete the quest
+    assert_eq!(
+        client.try_mark_completed(&quest_id, &verifier),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.mark_completed(&quest_id, &creator);
+    assert_eq!(client.get_quest(&quest_id).status, QuestStatus::Completed);
+    assert_eq!(
+        client.try_mark_completed(&quest_id, &creator),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+}
+
+#[test]
+fn test_mark_completed_requires_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &5,
+        &QuestDifficulty::Beginner,
+    );
+
+    // An auto-approved submission stays approved until its reward is claimed
+    let submitter = Address::generate(&env);
+    client.set_auto_approve_after(&quest_id, &creator, &60);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert!(client.try_auto_approve(&quest_id, &submitter));
+
+    assert_eq!(
+        client.try_mark_completed(&quest_id, &creator),
+        Err(Ok(Error::UnsettledSubmissions))
+    );
+}
+
+#[test]
+fn test_duplicate_to_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("season");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &500_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &20,
+        &QuestDifficulty::Advanced,
+    );
+    assert_eq!(client.get_chain(&quest_id).len(), 1);
+
+    let second = client.duplicate_to_chain(&quest_id, &creator, &2);
+    assert_eq!(second, Symbol::new(&env, "season_v2"));
+    let copy = client.get_quest(&second);
+    assert_eq!(copy.reward_amount, 500);
+    assert_eq!(copy.max_participants, 20);
+    assert_eq!(copy.total_claims, 0);
+    assert_eq!(copy.difficulty, QuestDifficulty::Advanced);
+
+    // Duplicating a later version still numbers from the original
+    let third = client.duplicate_to_chain(&second, &creator, &10);
+    assert_eq!(third, Symbol::new(&env, "season_v10"));
+
+    let chain = client.get_chain(&second);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(0).unwrap(), quest_id);
+    assert_eq!(client.get_latest_in_chain(&quest_id), third);
+
+    // Reusing an index collides with the existing quest
+    assert_eq!(
+        client.try_duplicate_to_chain(&quest_id, &creator, &2),
+        Err(Ok(Error::QuestAlreadyExists))
+    );
+    assert_eq!(
+        client.try_duplicate_to_chain(&quest_id, &verifier, &3),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_get_latest_in_chain(&symbol_short!("missing")),
+        Err(Ok(Error::QuestNotFound))
+    );
+}
+
+#[test]
+fn test_duplicate_to_chain_id_too_long() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = Symbol::new(&env, "a_very_long_campaign_quest_id");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &500_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &20,
+        &QuestDifficulty::Beginner,
+    );
+
+    assert_eq!(
+        client.duplicate_to_chain(&quest_id, &creator, &9),
+        Symbol::new(&env, "a_very_long_campaign_quest_id_v9")
+    );
+    assert_eq!(
+        client.try_duplicate_to_chain(&quest_id, &creator, &10),
+        Err(Ok(Error::SymbolTooLong))
+    );
+}
+
+#[test]
+fn test_search_quests_by_min_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let other_asset = reward_token(&env, &creator);
+    let deadline = env.ledger().timestamp() + 86400;
+    for (quest_id, asset, reward) in [
+        (symbol_short!("q_mid"), &reward_asset, 500_i128),
+        (symbol_short!("q_low"), &reward_asset, 100_i128),
+        (symbol_short!("q_high"), &reward_asset, 2000_i128),
+        (symbol_short!("q_other"), &other_asset, 5000_i128),
+    ] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            asset,
+            &reward,
+            &verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    // Highest reward first, only for the requested asset
+    let found = client.search_quests_by_min_reward(&reward_asset, &500);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.get(0).unwrap(), symbol_short!("q_high"));
+    assert_eq!(found.get(1).unwrap(), symbol_short!("q_mid"));
+    assert_eq!(
+        client.search_quests_by_min_reward(&reward_asset, &0).len(),
+        3
+    );
+    assert_eq!(
+        client
+            .search_quests_by_min_reward(&reward_asset, &2001)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client.search_quests_by_min_reward(&other_asset, &1).len(),
+        1
+    );
+
+    // Cancelled quests leave the index
+    client.cancel_quest(&symbol_short!("q_high"), &creator);
+    let found = client.search_quests_by_min_reward(&reward_asset, &500);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(0).unwrap(), symbol_short!("q_mid"));
+}
+
+#[test]
+fn test_archive_quest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &5,
+        &QuestDifficulty::Beginner,
+    );
+
+    // Only closed quests can be archived
+    assert_eq!(
+        client.try_archive_quest(&quest_id, &creator),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(
+        client.try_update_quest_status(&quest_id, &creator, &QuestStatus::Archived),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+    client.cancel_quest(&quest_id, &creator);
+
+    // A pending submission blocks archiving
+    assert_eq!(
+        client.try_archive_quest(&quest_id, &creator),
+        Err(Ok(Error::UnsettledSubmissions))
+    );
+    client.reject_submission(&quest_id, &submitter, &verifier);
+
+    assert_eq!(
+        client.try_archive_quest(&quest_id, &verifier),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.archive_quest(&quest_id, &creator);
+
+    // The quest stays readable
+    let quest = client.get_quest(&quest_id);
+    assert_eq!(quest.status, QuestStatus::Archived);
+    assert_eq!(quest.reward_amount, 1000);
+    assert_eq!(client.get_platform_stats().archived_quests, 1);
+
+    // Every mutation is rejected
+    assert_eq!(
+        client.try_archive_quest(&quest_id, &creator),
+        Err(Ok(Error::QuestArchived))
+    );
+    assert_eq!(
+        client.try_set_verifier(&quest_id, &creator, &Address::generate(&env)),
+        Err(Ok(Error::QuestArchived))
+    );
+    assert_eq!(
+        client.try_set_auto_approve_after(&quest_id, &creator, &60),
+        Err(Ok(Error::QuestArchived))
+    );
+    assert_eq!(
+        client.try_expire_quest(&quest_id, &creator),
+        Err(Ok(Error::QuestArchived))
+    );
+    assert_eq!(
+        client.try_submit_proof(
+            &quest_id,
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[2u8; 32])
+        ),
+        Err(Ok(Error::QuestArchived))
+    );
+}
+
+#[test]
+fn test_get_active_quests_for_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let deadline = env.ledger().timestamp() + 86400;
+    for id in [
+        symbol_short!("q_done"),
+        symbol_short!("q_open"),
+        symbol_short!("q_paused"),
+        symbol_short!("q_wl"),
+        symbol_short!("q_other"),
+    ] {
+        client.register_quest(
+            &id,
+            &creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &deadline,
+            &5,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    let user = Address::generate(&env);
+    client.submit_proof(
+        &symbol_short!("q_done"),
+        &user,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.update_quest_status(&symbol_short!("q_paused"), &creator, &QuestStatus::Paused);
+    client.set_whitelist_merkle_root(
+        &symbol_short!("q_wl"),
+        &creator,
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+
+    // Submitted, paused and whitelisted quests are filtered out
+    let open = client.get_active_quests_for_user(&user);
+    assert_eq!(open.len(), 2);
+    assert_eq!(open.get(0).unwrap(), symbol_short!("q_open"));
+    assert_eq!(open.get(1).unwrap(), symbol_short!("q_other"));
+
+    let page = client.get_active_quests_for_user_paged(&user, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), symbol_short!("q_other"));
+
+    // Every open quest is eligible for a new user
+    assert_eq!(
+        client
+            .get_active_quests_for_user(&Address::generate(&env))
+            .len(),
+        3
+    );
+
+    // Nothing is open once the deadlines pass
+    env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    assert_eq!(client.get_active_quests_for_user(&user).len(), 0);
+}
+
+#[test]
+fn test_quest_creation_limit_per_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.get_max_quests_per_creator(), 100);
+    client.set_max_quests_per_creator(&admin, &2);
+
+    let register = |creator: &Address, quest_id: &Symbol| {
+        client.try_register_quest(
+            quest_id,
+            creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &(env.ledger().timestamp() + 86400),
+            &10,
+            &QuestDifficulty::Beginner,
+        )
+    };
+
+    assert!(register(&creator, &symbol_short!("q1")).is_ok());
+    assert!(register(&creator, &symbol_short!("q2")).is_ok());
+    assert_eq!(
+        register(&creator, &symbol_short!("q3")),
+        Err(Ok(Error::QuestCreationLimitReached))
+    );
+
+    // Other creators have their own allowance
+    let other = Address::generate(&env);
+    StellarAssetClient::new(&env, &reward_asset).mint(&other, &10_000);
+    assert!(register(&other, &symbol_short!("q4")).is_ok());
+
+    // A trusted partner's override replaces the global limit
+    client.override_creator_limit(&admin, &creator, &3);
+    assert!(register(&creator, &symbol_short!("q3")).is_ok());
+    assert_eq!(
+        register(&creator, &symbol_short!("q5")),
+        Err(Ok(Error::QuestCreationLimitReached))
+    );
+
+    assert!(client
+        .try_set_max_quests_per_creator(&creator, &10)
+        .is_err());
+}
+
+#[test]
+fn test_quests_sorted_by_reward_and_recent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let deadline = env.ledger().timestamp() + 86400;
+    for (quest_id, reward) in [
+        (symbol_short!("q_mid"), 500_i128),
+        (symbol_short!("q_low"), 100_i128),
+        (symbol_short!("q_high"), 2000_i128),
+        (symbol_short!("q_top"), 3000_i128),
+    ] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &reward,
+            &verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    // Paused quests are left out of reward listings
+    client.update_quest_status(&symbol_short!("q_top"), &creator, &QuestStatus::Paused);
+    assert_eq!(
+        client.get_quests_sorted_by_reward(&reward_asset, &0, &2),
+        soroban_sdk::vec![
+            &env,
+            (symbol_short!("q_high"), 2000_i128),
+            (symbol_short!("q_mid"), 500_i128)
+        ]
+    );
+    assert_eq!(
+        client.get_quests_sorted_by_reward(&reward_asset, &2, &10),
+        soroban_sdk::vec![&env, (symbol_short!("q_low"), 100_i128)]
+    );
+    assert_eq!(
+        client
+            .get_quests_sorted_by_reward(&Address::generate(&env), &0, &10)
+            .len(),
+        0
+    );
+
+    assert_eq!(
+        client.get_quests_sorted_by_recent(&1, &2),
+        soroban_sdk::vec![&env, symbol_short!("q_high"), symbol_short!("q_low")]
+    );
+    assert_eq!(client.get_quests_sorted_by_recent(&0, &100).len(), 4);
+    assert_eq!(client.get_quests_sorted_by_recent(&4, &10).len(), 0);
+}
+
+#[test]
+fn test_recommended_quests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let deadline = env.ledger().timestamp() + 86400;
+    for (quest_id, reward, difficulty) in [
+        (
+            symbol_short!("done"),
+            1000_i128,
+            QuestDifficulty::Intermediate,
+        ),
+        (
+            symbol_short!("b_rich"),
+            5000_i128,
+            QuestDifficulty::Beginner,
+        ),
+        (
+            symbol_short!("i_low"),
+            100_i128,
+            QuestDifficulty::Intermediate,
+        ),
+        (
+            symbol_short!("i_high"),
+            300_i128,
+            QuestDifficulty::Intermediate,
+        ),
+    ] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &reward,
+            &verifier,
+            &deadline,
+            &10,
+            &difficulty,
+        );
+    }
+
+    // Earning XP on an intermediate quest makes it the favorite difficulty
+    let user = Address::generate(&env);
+    client.submit_proof(
+        &symbol_short!("done"),
+        &user,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.approve_submission(&symbol_short!("done"), &user, &verifier);
+    assert_eq!(
+        client.get_recommended_quests(&user, &10),
+        soroban_sdk::vec![&env, symbol_short!("i_high"), symbol_short!("i_low")]
+    );
+    assert_eq!(client.get_recommended_quests(&user, &1).len(), 1);
+
+    // Without a favorite, every open quest is ranked by reward
+    let newcomer = Address::generate(&env);
+    assert_eq!(
+        client.get_recommended_quests(&newcomer, &10),
+        soroban_sdk::vec![
+            &env,
+            symbol_short!("b_rich"),
+            symbol_short!("done"),
+            symbol_short!("i_high"),
+            symbol_short!("i_low")
+        ]
+    );
+}
+
+#[test]
+fn test_check_deadline_conflicts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let other_creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &reward_asset).mint(&other_creator, &10_000);
+    let day = 86400_u64;
+    for (quest_id, owner, deadline) in [
+        (symbol_short!("morning"), &creator, 10 * day + 3600),
+        (symbol_short!("evening"), &creator, 10 * day + 80000),
+        (symbol_short!("later"), &creator, 11 * day),
+        (symbol_short!("paused"), &creator, 10 * day + 100),
+        (symbol_short!("others"), &other_creator, 10 * day),
+    ] {
+        client.register_quest(
+            &quest_id,
+            owner,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+    client.update_quest_status(&symbol_short!("paused"), &creator, &QuestStatus::Paused);
+
+    assert_eq!(
+        client.check_deadline_conflicts(&creator, &(10 * day + 50_000)),
+        soroban_sdk::vec![&env, symbol_short!("morning"), symbol_short!("evening")]
+    );
+    assert_eq!(
+        client.check_deadline_conflicts(&creator, &(12 * day)).len(),
+        0
+    );
+}
+
+#[test]
+fn test_quest_health_score() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("health");
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &100,
+        &verifier,
+        &(1_000 + 30 * 86400),
+        &4,
+        &QuestDifficulty::Beginner,
+    );
+
+    // A fresh quest has no fill or submitters, but nothing counts against it either
+    assert_eq!(client.compute_quest_health_score(&quest_id), 40);
+    assert_eq!(client.get_healthy_quests(&0, &10).len(), 0);
+
+    let finisher = Address::generate(&env);
+    let rejected = Address::generate(&env);
+    client.submit_proof(&quest_id, &finisher, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_proof(&quest_id, &rejected, &BytesN::from_array(&env, &[2u8; 32]));
+
+    // Reviews take half the SLA
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 36 * 3600);
+    client.approve_submission(&quest_id, &finisher, &verifier);
+
+    // fill 10 + review 10 + completion 10 (one of two submitters finished) + rejections 20
+    assert_eq!(client.compute_quest_health_score(&quest_id), 50);
+    assert_eq!(
+        client.get_healthy_quests(&50, &10),
+        soroban_sdk::vec![&env, (quest_id.clone(), 50)]
+    );
+
+    client.reject_submission(&quest_id, &rejected, &verifier);
+
+    // Half of the reviewed submissions were rejected
+    assert_eq!(client.compute_quest_health_score(&quest_id), 40);
+    assert_eq!(client.get_healthy_quests(&50, &10).len(), 0);
+    assert_eq!(
+        client.get_healthy_quests(&40, &10),
+        soroban_sdk::vec![&env, (quest_id.clone(), 40)]
+    );
+    assert_eq!(
+        client.try_compute_quest_health_score(&symbol_short!("missing")),
+        Err(Ok(Error::QuestNotFound))
+    );
+}
+
+#[test]
+fn test_verifier_quality_thresholds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let deadline = env.ledger().timestamp() + 86400;
+    let register = |id: &Symbol, verifier: &Address| {
+        client.try_register_quest(
+            id,
+            &creator,
+            &reward_asset,
+            &100,
+            verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        )
+    };
+
+    // Give the verifier one on-time review before thresholds apply
+    register(&symbol_short!("first"), &verifier)
+        .unwrap()
+        .unwrap();
+    let submitter = Address::generate(&env);
+    client.submit_proof(
+        &symbol_short!("first"),
+        &submitter,
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    client.approve_submission(&symbol_short!("first"), &submitter, &verifier);
+
+    assert_eq!(
+        client.try_set_verifier_quality_thresholds(&creator, &1, &90),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_verifier_quality_thresholds(&admin, &1, &101),
+        Err(Ok(Error::InvalidScore))
+    );
+    client.set_verifier_quality_thresholds(&admin, &1, &90);
+    assert_eq!(client.get_verifier_quality_thresholds(), (1, 90));
+
+    // A verifier without history is refused until exempted
+    let newcomer = Address::generate(&env);
+    assert_eq!(
+        register(&symbol_short!("second"), &newcomer),
+        Err(Ok(Error::VerifierInsufficientHistory))
+    );
+    register(&symbol_short!("second"), &verifier)
+        .unwrap()
+        .unwrap();
+    client.exempt_verifier(&admin, &newcomer);
+    register(&symbol_short!("third"), &newcomer)
+        .unwrap()
+        .unwrap();
+
+    client.set_verifier_quality_thresholds(&admin, &2, &0);
+    assert_eq!(
+        register(&symbol_short!("fourth"), &verifier),
+        Err(Ok(Error::VerifierInsufficientHistory))
+    );
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, Symbol,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(
+    env: &Env,
+) -> (
+    EarnQuestContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+    client.initialize(&admin);
+
+    (client, admin, creator, verifier, reward_asset)
+}
+
+fn create_quests(
+    client: &EarnQuestContractClient,
+    env: &Env,
+    ids: &[Symbol],
+    creator: &Address,
+    verifier: &Address,
+    reward_asset: &Address,
+) {
+    let deadline = env.ledger().timestamp() + 86400 * 10;
+    for id in ids {
+        client.register_quest(
+            id,
+            creator,
+            reward_asset,
+            &1000_i128,
+            verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _creator, _verifier, _reward_asset) = setup_env(&env);
+
+    let result = client.try_initialize(&Address::generate(&env));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_daily_limit_enforced_across_quests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, creator, verifier, reward_asset) = setup_env(&env);
+    let ids = [
+        symbol_short!("q1"),
+        symbol_short!("q2"),
+        symbol_short!("q3"),
+    ];
+    create_quests(&client, &env, &ids, &creator, &verifier, &reward_asset);
+    client.set_max_daily_submissions(&admin, &2);
+
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&ids[0], &submitter, &proof);
+    client.submit_proof(&ids[1], &submitter, &proof);
+    assert_eq!(client.get_daily_count(&submitter), 2);
+
+    // Third submission on the same day exceeds the limit
+    let result = client.try_submit_proof(&ids[2], &submitter, &proof);
+    assert!(result.is_err());
+    assert_eq!(client.get_daily_count(&submitter), 2);
+}
+
+#[test]
+fn test_daily_limit_resets_next_day() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, creator, verifier, reward_asset) = setup_env(&env);
+    let ids = [symbol_short!("q1"), symbol_short!("q2")];
+    create_quests(&client, &env, &ids, &creator, &verifier, &reward_asset);
+    client.set_max_daily_submissions(&admin, &1);
+
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&ids[0], &submitter, &proof);
+
+    env.ledger().with_mut(|li| li.timestamp += 86400);
+    assert_eq!(client.get_daily_count(&submitter), 0);
+    client.submit_proof(&ids[1], &submitter, &proof);
+    assert_eq!(client.get_daily_count(&submitter), 1);
+}
+
+#[test]
+fn test_set_max_daily_submissions_non_admin_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _creator, _verifier, _reward_asset) = setup_env(&env);
+
+    let result = client.try_set_max_daily_submissions(&Address::generate(&env), &5);
+    assert!(result.is_err());
+}
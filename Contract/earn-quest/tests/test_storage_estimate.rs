@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestDifficulty};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, Symbol,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
+
+fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
+
+    (client, creator, verifier, reward_asset)
+}
+
+fn create_quest(
+    client: &EarnQuestContractClient,
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    verifier: &Address,
+    reward_asset: &Address,
+) {
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        quest_id,
+        creator,
+        reward_asset,
+        &1000_i128,
+        verifier,
+        &deadline,
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+}
+
+#[test]
+fn test_estimate_grows_with_submissions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+
+    // Unknown quest uses no storage
+    assert_eq!(client.estimate_storage_cost(&quest_id), 0);
+
+    create_quest(&client, &env, &quest_id, &creator, &verifier, &reward_asset);
+    let empty = client.estimate_storage_cost(&quest_id);
+    assert!(empty > 0);
+
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+    let one = client.estimate_storage_cost(&quest_id);
+    assert!(one > empty);
+
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[2u8; 32]));
+    let two = client.estimate_storage_cost(&quest_id);
+    assert!(two > one);
+}
+
+#[test]
+fn test_estimate_counts_per_quest_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    create_quest(&client, &env, &quest_id, &creator, &verifier, &reward_asset);
+    let created = client.estimate_storage_cost(&quest_id);
+
+    // Approval writes review records such as the outcome counts
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+    let submitted = client.estimate_storage_cost(&quest_id);
+    assert!(submitted > created);
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    assert!(client.estimate_storage_cost(&quest_id) > submitted);
+
+    // The whitelist root is part of the quest's footprint
+    let gated = symbol_short!("gated");
+    create_quest(&client, &env, &gated, &creator, &verifier, &reward_asset);
+    let open = client.estimate_storage_cost(&gated);
+    client.set_whitelist_merkle_root(&gated, &creator, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(client.estimate_storage_cost(&gated), open + 48 + 40);
+}
+
+#[test]
+fn test_contract_storage_estimate_sums_quests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest1 = symbol_short!("quest1");
+    let quest2 = symbol_short!("quest2");
+
+    assert_eq!(client.get_contract_storage_estimate(), 0);
+
+    create_quest(&client, &env, &quest1, &creator, &verifier, &reward_asset);
+    create_quest(&client, &env, &quest2, &creator, &verifier, &reward_asset);
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest1, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+
+    let total = client.get_contract_storage_estimate();
+    assert_eq!(
+        total,
+        client.estimate_storage_cost(&quest1) + client.estimate_storage_cost(&quest2)
+    );
+}
+
+#[test]
+fn test_storage_metrics_count_new_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let quest_a = symbol_short!("quest_a");
+    create_quest(&client, &env, &quest_a, &creator, &verifier, &reward_asset);
+    let after_first = client.get_storage_metrics(&admin);
+    assert!(after_first.entry_count > 0);
+    assert_eq!(
+        after_first.estimated_bytes,
+        client.get_contract_storage_estimate()
+    );
+    assert_eq!(after_first.last_compaction_timestamp, 0);
+
+    // A second quest only adds its own entry and escrow; shared indexes are overwritten
+    let quest_b = symbol_short!("quest_b");
+    create_quest(&client, &env, &quest_b, &creator, &verifier, &reward_asset);
+    let after_second = client.get_storage_metrics(&admin);
+    assert_eq!(after_second.entry_count, after_first.entry_count + 2);
+
+    // Only the admin can read metrics
+    assert!(client
+        .try_get_storage_metrics(&Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_ttl_health_check() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Keep the contract instance alive while the ledger advances
+    env.ledger()
+        .with_mut(|li| li.min_persistent_entry_ttl = 1_000_000);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let quest_id = symbol_short!("quest1");
+    create_quest(&client, &env, &quest_id, &creator, &verifier, &reward_asset);
+    for i in 1..=2u8 {
+        let submitter = Address::generate(&env);
+        client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[i; 32]));
+    }
+
+    // Fresh entries are healthy (about 30 days for the quest, 14 for pending submissions)
+    let report = client.ttl_health_check(&admin, &quest_id);
+    assert_eq!(report.quest_ttl_remaining, 30 * 17_280);
+    assert_eq!(report.submission_count, 2);
+    assert_eq!(report.min_ttl_remaining, 14 * 17_280);
+    assert_eq!(report.entries_expiring_soon, 0);
+
+    // With under 7 days of ledgers left the pending submissions are flagged
+    env.ledger().with_mut(|li| li.sequence_number += 8 * 17_280);
+    let report = client.ttl_health_check(&admin, &quest_id);
+    assert_eq!(report.quest_ttl_remaining, 22 * 17_280);
+    assert_eq!(report.min_ttl_remaining, 6 * 17_280);
+    assert_eq!(report.entries_expiring_soon, 2);
+
+    // Extending the quest refreshes every entry
+    client.extend_quest_ttl(&quest_id);
+    let report = client.ttl_health_check(&admin, &quest_id);
+    assert_eq!(report.quest_ttl_remaining, 30 * 17_280);
+    assert_eq!(report.min_ttl_remaining, 14 * 17_280);
+    assert_eq!(report.entries_expiring_soon, 0);
+}
+
+#[test]
+fn test_submission_ttl_by_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Keep the contract instance alive while the ledger advances
+    env.ledger()
+        .with_mut(|li| li.min_persistent_entry_ttl = 1_000_000);
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    create_quest(&client, &env, &quest_id, &creator, &verifier, &reward_asset);
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+
+    // Pending submissions get a short TTL and cannot be refreshed as approved
+    let sequence = env.ledger().sequence();
+    assert_eq!(
+        client
+            .get_submission(&quest_id, &submitter)
+            .live_until_ledger,
+        sequence + 14 * 17_280
+    );
+    assert!(!client.refresh_approved_submission_ttl(&quest_id, &submitter));
+    assert!(!client.refresh_approved_submission_ttl(&quest_id, &Address::generate(&env)));
+
+    // Approval keeps the submission for the long TTL
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    assert_eq!(
+        client
+            .get_submission(&quest_id, &submitter)
+            .live_until_ledger,
+        sequence + 180 * 17_280
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 17_280);
+    assert!(client.refresh_approved_submission_ttl(&quest_id, &submitter));
+    assert_eq!(
+        client
+            .get_submission(&quest_id, &submitter)
+            .live_until_ledger,
+        sequence + 181 * 17_280
+    );
+}
@@ -1,7 +1,23 @@
 #![cfg(test)]
 
-use earn_quest::{EarnQuestContract, EarnQuestContractClient, SubmissionStatus};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+use earn_quest::{
+    EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty, SubmissionStatus,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Bytes, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
 
 fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
     let contract_id = env.register_contract(None, EarnQuestContract);
@@ -9,7 +25,7 @@ fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, A
 
     let creator = Address::generate(env);
     let verifier = Address::generate(env);
-    let reward_asset = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
 
     (client, creator, verifier, reward_asset)
 }
@@ -31,6 +47,7 @@ fn create_quest<'a>(
         verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 }
 
@@ -133,6 +150,7 @@ fn test_multiple_user_submissions() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
     client.register_quest(
         &quest2_id,
@@ -142,6 +160,7 @@ fn test_multiple_user_submissions() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     let submitter = Address::generate(&env);
@@ -158,3 +177,422 @@ fn test_multiple_user_submissions() {
     let submission2 = client.get_submission(&quest2_id, &submitter);
     assert_eq!(submission2.quest_id, quest2_id);
 }
+
+#[test]
+fn test_estimate_gas_for_batch_approve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    let one = client.estimate_gas_for_batch_approve(&1);
+    let ten = client.estimate_gas_for_batch_approve(&10);
+    assert_eq!(
+        ten - one,
+        9 * (one - client.estimate_gas_for_batch_approve(&0))
+    );
+
+    // The per-item estimate covers a measured approval on the test host
+    let submitter = Address::generate(&env);
+    client.submit_proof(&quest_id, &submitter, &BytesN::from_array(&env, &[1u8; 32]));
+    env.budget().reset_default();
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    assert!(env.budget().cpu_instruction_cost() < one);
+}
+
+#[test]
+fn test_validate_proof_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, _verifier, _reward_asset) = setup_env(&env);
+    let uri = |s: &str| Bytes::from_slice(&env, s.as_bytes());
+
+    assert!(client
+        .try_validate_proof_uri(&uri("https://example.com/proof"))
+        .is_ok());
+    assert!(client
+        .try_validate_proof_uri(&uri("ipfs://bafyproofcid"))
+        .is_ok());
+    assert!(client
+        .try_validate_proof_uri(&uri("ar://txid12345"))
+        .is_ok());
+
+    // Unknown scheme, too short, non-printable and oversized URIs are rejected
+    for bad in [
+        uri("ftp://example.com/proof"),
+        uri("ar://x"),
+        uri("https://exa\0mple.com"),
+        uri("https://caf\u{e9}.example"),
+        Bytes::from_slice(&env, &[b'a'; 257]),
+    ] {
+        assert_eq!(
+            client.try_validate_proof_uri(&bad),
+            Err(Ok(Error::InvalidProofUri))
+        );
+    }
+}
+
+#[test]
+fn test_add_allowed_uri_scheme() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, _verifier, _reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let scheme = Bytes::from_slice(&env, b"ipns://");
+    let uri = Bytes::from_slice(&env, b"ipns://k51proofname");
+    assert_eq!(client.list_allowed_uri_schemes().len(), 3);
+    assert!(client.try_validate_proof_uri(&uri).is_err());
+
+    client.add_allowed_uri_scheme(&admin, &scheme);
+    client.add_allowed_uri_scheme(&admin, &scheme);
+    assert_eq!(client.list_allowed_uri_schemes().len(), 4);
+    assert!(client.try_validate_proof_uri(&uri).is_ok());
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_add_allowed_uri_scheme(&stranger, &Bytes::from_slice(&env, b"s3://")),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_submit_proof_with_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    // An invalid URI leaves no submission behind
+    let submitter = Address::generate(&env);
+    let bad_uri = Bytes::from_slice(&env, b"http://example.com/proof");
+    assert_eq!(
+        client.try_submit_proof_with_uri(&quest_id, &submitter, &proof, &bad_uri),
+        Err(Ok(Error::InvalidProofUri))
+    );
+    assert!(client.try_get_submission(&quest_id, &submitter).is_err());
+
+    let uri = Bytes::from_slice(&env, b"https://example.com/proof");
+    client.submit_proof_with_uri(&quest_id, &submitter, &proof, &uri);
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).proof_hash,
+        proof
+    );
+    assert_eq!(client.get_proof_uri(&quest_id, &submitter), Some(uri));
+}
+
+#[test]
+fn test_submit_proof_with_document() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+    let submitter = Address::generate(&env);
+
+    let document = Bytes::from_slice(&env, br#"{"repo":"stellar_Earn","pr":42}"#);
+    let hash = env.crypto().sha256(&document).to_bytes();
+
+    // The admin can switch inline documents off and back on
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_feature_flag(&admin, &symbol_short!("inline_dc"), &false);
+    assert_eq!(
+        client.try_submit_proof_with_document(&quest_id, &submitter, &hash, &document),
+        Err(Ok(Error::FeatureDisabled))
+    );
+    client.set_feature_flag(&admin, &symbol_short!("inline_dc"), &true);
+
+    // The claimed hash must match the document
+    assert_eq!(
+        client.try_submit_proof_with_document(
+            &quest_id,
+            &submitter,
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &document,
+        ),
+        Err(Ok(Error::InvalidProofHash))
+    );
+
+    // Documents over 1KB are rejected
+    let large = Bytes::from_array(&env, &[b'a'; 1025]);
+    let large_hash = env.crypto().sha256(&large).to_bytes();
+    assert_eq!(
+        client.try_submit_proof_with_document(&quest_id, &submitter, &large_hash, &large),
+        Err(Ok(Error::InvalidProofHash))
+    );
+    assert!(client.try_get_submission(&quest_id, &submitter).is_err());
+
+    client.submit_proof_with_document(&quest_id, &submitter, &hash, &document);
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).proof_hash,
+        hash
+    );
+}
+
+/// Hash two Merkle nodes in sorted order
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from_array(env, &left.to_array());
+    pair.append(&Bytes::from_array(env, &right.to_array()));
+    env.crypto().sha256(&pair).to_bytes()
+}
+
+#[test]
+fn test_whitelist_merkle_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+
+    // Four-leaf tree: root = H(H(a, b), H(c, d))
+    let members: [Address; 4] = core::array::from_fn(|_| Address::generate(&env));
+    let leaves: [BytesN<32>; 4] =
+        core::array::from_fn(|i| client.compute_whitelist_leaf(&members[i]));
+    let ab = hash_pair(&env, &leaves[0], &leaves[1]);
+    let cd = hash_pair(&env, &leaves[2], &leaves[3]);
+    let root = hash_pair(&env, &ab, &cd);
+
+    // Only the creator can set the root
+    assert_eq!(
+        client.try_set_whitelist_merkle_root(&quest_id, &verifier, &root),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_whitelist_merkle_root(&quest_id, &creator, &root);
+    assert_eq!(client.get_whitelist_root(&quest_id), Some(root));
+
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    // A member with a valid proof can submit
+    let proof = vec![&env, leaves[3].clone(), ab.clone()];
+    client.submit_whitelisted_proof(&quest_id, &members[2], &proof_hash, &proof);
+    assert_eq!(
+        client.get_submission(&quest_id, &members[2]).status,
+        SubmissionStatus::Pending
+    );
+
+    // A member with someone else's proof, an outsider, and a proof-less submission are refused
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_submit_whitelisted_proof(&quest_id, &members[0], &proof_hash, &proof),
+        Err(Ok(Error::NotWhitelisted))
+    );
+    let proof = vec![&env, leaves[1].clone(), cd.clone()];
+    assert_eq!(
+        client.try_submit_whitelisted_proof(&quest_id, &outsider, &proof_hash, &proof),
+        Err(Ok(Error::NotWhitelisted))
+    );
+    assert_eq!(
+        client.try_submit_proof(&quest_id, &members[0], &proof_hash),
+        Err(Ok(Error::NotWhitelisted))
+    );
+    client.submit_whitelisted_proof(&quest_id, &members[0], &proof_hash, &proof);
+}
+
+#[test]
+fn test_resubmit_after_rejection() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+    let submitter = Address::generate(&env);
+    let first_proof = BytesN::from_array(&env, &[1u8; 32]);
+    let second_proof = BytesN::from_array(&env, &[2u8; 32]);
+
+    // Resubmitting requires an earlier rejection
+    assert_eq!(
+        client.try_resubmit_after_rejection(&quest_id, &submitter, &first_proof),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+
+    client.submit_proof(&quest_id, &submitter, &first_proof);
+    assert_eq!(
+        client.try_submit_proof(&quest_id, &submitter, &second_proof),
+        Err(Ok(Error::SubmissionAlreadyExists))
+    );
+    client.reject_submission(&quest_id, &submitter, &verifier);
+
+    // The rejected record is still visible until a new submission replaces it
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).status,
+        SubmissionStatus::Rejected
+    );
+
+    client.resubmit_after_rejection(&quest_id, &submitter, &second_proof);
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+    assert_eq!(submission.proof_hash, second_proof);
+
+    let history = client.get_rejection_history(&quest_id, &submitter);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().proof_hash, first_proof);
+    assert_eq!(history.get(0).unwrap().status, SubmissionStatus::Rejected);
+
+    // The live submission can be approved as usual
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    assert_eq!(client.get_quest(&quest_id).total_claims, 1);
+    assert_eq!(client.get_user_submissions(&submitter).len(), 1);
+}
+
+#[test]
+fn test_proof_hash_lookup() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+
+    let first = Address::generate(&env);
+    let copier = Address::generate(&env);
+    let other = Address::generate(&env);
+    let shared = BytesN::from_array(&env, &[7u8; 32]);
+    let unique = BytesN::from_array(&env, &[8u8; 32]);
+    client.submit_proof(&quest_id, &first, &shared);
+    client.submit_proof(&quest_id, &copier, &shared);
+    client.submit_proof(&quest_id, &other, &unique);
+
+    // A reused hash traces back to its first submitter
+    assert_eq!(
+        client.get_submitter_for_proof_hash(&quest_id, &shared),
+        Some(first.clone())
+    );
+    assert_eq!(
+        client.get_submitter_for_proof_hash(&quest_id, &unique),
+        Some(other.clone())
+    );
+    assert_eq!(
+        client.get_submitter_for_proof_hash(&quest_id, &BytesN::from_array(&env, &[9u8; 32])),
+        None
+    );
+
+    let all = client.get_all_proof_hashes(&quest_id, &0, &100);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap(), (shared, first));
+    let page = client.get_all_proof_hashes(&quest_id, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), (unique, other));
+}
+
+#[test]
+fn test_proof_hash_age() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+    let submitter = Address::generate(&env);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.submit_proof(&quest_id, &submitter, &proof_hash);
+    env.ledger().with_mut(|l| l.timestamp = 4_600);
+
+    assert_eq!(
+        client.get_proof_hash_age(&quest_id, &proof_hash),
+        Some(3_600)
+    );
+    assert_eq!(
+        client.get_proof_hash_age(&quest_id, &BytesN::from_array(&env, &[9u8; 32])),
+        None
+    );
+
+    // Submissions older than the allowed age are flagged
+    assert!(client.flag_old_proof(&quest_id, &submitter, &1_800));
+    assert!(!client.flag_old_proof(&quest_id, &submitter, &3_600));
+    assert!(!client.flag_old_proof(&quest_id, &Address::generate(&env), &0));
+}
+
+#[test]
+fn test_seal_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+    let root = BytesN::from_array(&env, &[5u8; 32]);
+    client.set_whitelist_merkle_root(&quest_id, &creator, &root);
+
+    assert!(!client.is_whitelist_sealed(&quest_id));
+    assert_eq!(
+        client.try_seal_whitelist(&quest_id, &verifier),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.seal_whitelist(&quest_id, &creator);
+    assert!(client.is_whitelist_sealed(&quest_id));
+
+    // Sealing is irreversible and freezes the root
+    assert_eq!(
+        client.try_set_whitelist_merkle_root(
+            &quest_id,
+            &creator,
+            &BytesN::from_array(&env, &[6u8; 32])
+        ),
+        Err(Ok(Error::WhitelistSealed))
+    );
+    assert_eq!(
+        client.try_seal_whitelist(&quest_id, &creator),
+        Err(Ok(Error::WhitelistSealed))
+    );
+    assert_eq!(client.get_whitelist_root(&quest_id), Some(root));
+}
+
+#[test]
+fn test_submission_by_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    create_quest(&client, &env, &creator, &verifier, &reward_asset);
+    let quest_id = symbol_short!("quest1");
+    assert_eq!(client.get_quest_submission_count(&quest_id), 0);
+
+    let submitters: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    for (i, submitter) in submitters.iter().enumerate() {
+        client.submit_proof(
+            &quest_id,
+            submitter,
+            &BytesN::from_array(&env, &[i as u8 + 1; 32]),
+        );
+    }
+    client.reject_submission(&quest_id, &submitters[1], &verifier);
+
+    // Submissions are addressed by arrival order, rejected ones included
+    assert_eq!(client.get_quest_submission_count(&quest_id), 3);
+    for (i, submitter) in submitters.iter().enumerate() {
+        let submission = client.get_submission_by_index(&quest_id, &(i as u32));
+        assert_eq!(submission.submitter, *submitter);
+    }
+    assert_eq!(
+        client.get_submission_by_index(&quest_id, &1).status,
+        SubmissionStatus::Rejected
+    );
+    assert_eq!(
+        client.try_get_submission_by_index(&quest_id, &3),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+}
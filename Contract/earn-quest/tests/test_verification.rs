@@ -1,7 +1,23 @@
 #![cfg(test)]
 
-use earn_quest::{EarnQuestContract, EarnQuestContractClient, SubmissionStatus};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+use earn_quest::{
+    EarnQuestContract, EarnQuestContractClient, Error, QuestDifficulty, SubmissionStatus,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, BytesN, Env,
+};
+
+/// Register a reward token and mint enough of it to `holder` to fund quests
+fn reward_token(env: &Env, holder: &Address) -> Address {
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &asset).mint(holder, &1_000_000_000);
+    asset
+}
 
 fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
     let contract_id = env.register_contract(None, EarnQuestContract);
@@ -9,7 +25,7 @@ fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, A
 
     let creator = Address::generate(env);
     let verifier = Address::generate(env);
-    let reward_asset = Address::generate(env);
+    let reward_asset = reward_token(env, &creator);
 
     (client, creator, verifier, reward_asset)
 }
@@ -37,6 +53,7 @@ fn setup_quest_with_submission<'a>(
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Create submitter and submit proof
@@ -58,9 +75,9 @@ fn test_approve_submission_success() {
     // Approve submission
     client.approve_submission(&quest_id, &submitter, &verifier);
 
-    // Verify status changed to Paid (approval includes automatic payment)
+    // Verify status changed; the reward stays in escrow until claimed
     let submission = client.get_submission(&quest_id, &submitter);
-    assert_eq!(submission.status, SubmissionStatus::Paid);
+    assert_eq!(submission.status, SubmissionStatus::Approved);
 }
 
 #[test]
@@ -126,6 +143,7 @@ fn test_approve_nonexistent_submission_fails() {
         &verifier,
         &deadline,
         &10,
+        &QuestDifficulty::Beginner,
     );
 
     // Try to approve non-existent submission
@@ -181,3 +199,390 @@ fn test_approve_after_rejection_fails() {
     let result = client.try_approve_submission(&quest_id, &submitter, &verifier);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_verifier_assignment_roster() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let deadline = env.ledger().timestamp() + 86400;
+    for quest_id in [symbol_short!("quest1"), symbol_short!("quest2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    // Assigned quests are listed before any submissions arrive
+    assert_eq!(client.get_assigned_quest_count(&verifier), 2);
+
+    let new_verifier = Address::generate(&env);
+    client.set_verifier(&symbol_short!("quest1"), &creator, &new_verifier);
+
+    let ids = client.get_assigned_quest_ids(&verifier);
+    assert_eq!(ids.len(), 1);
+    assert_eq!(ids.get(0).unwrap(), symbol_short!("quest2"));
+    let ids = client.get_assigned_quest_ids(&new_verifier);
+    assert_eq!(ids.len(), 1);
+    assert_eq!(ids.get(0).unwrap(), symbol_short!("quest1"));
+    assert_eq!(
+        client.get_quest(&symbol_short!("quest1")).verifier,
+        new_verifier
+    );
+
+    // Only the creator can reassign
+    let result = client.try_set_verifier(&symbol_short!("quest2"), &verifier, &new_verifier);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_verifier_action_queue_and_sla() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let quest_id = symbol_short!("quest1");
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &(env.ledger().timestamp() + 86400 * 10),
+        &10,
+        &QuestDifficulty::Beginner,
+    );
+
+    // Submissions arrive an hour apart
+    let submitters: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    for (i, submitter) in submitters.iter().enumerate() {
+        client.submit_proof(
+            &quest_id,
+            submitter,
+            &BytesN::from_array(&env, &[i as u8 + 1; 32]),
+        );
+        env.ledger().with_mut(|li| li.timestamp += 3600);
+    }
+
+    // Ages are 3h, 2h and 1h
+    let queue = client.get_verifier_action_queue(&verifier, &3600);
+    assert_eq!(queue.len(), 2);
+    assert_eq!(
+        queue.get(0).unwrap(),
+        (quest_id.clone(), submitters[0].clone(), 3 * 3600)
+    );
+    assert_eq!(
+        queue.get(1).unwrap(),
+        (quest_id.clone(), submitters[1].clone(), 2 * 3600)
+    );
+    assert_eq!(client.get_sla_breach_count(&verifier, &0), 3);
+
+    // Reviewed submissions leave the queue
+    client.approve_submission(&quest_id, &submitters[0], &verifier);
+    client.reject_submission(&quest_id, &submitters[1], &verifier);
+    assert_eq!(client.get_sla_breach_count(&verifier, &0), 1);
+    assert_eq!(client.get_verifier_action_queue(&verifier, &3600).len(), 0);
+
+    // Reassigning the quest moves its pending submissions
+    let new_verifier = Address::generate(&env);
+    client.set_verifier(&quest_id, &creator, &new_verifier);
+    assert_eq!(client.get_sla_breach_count(&verifier, &0), 0);
+    assert_eq!(client.get_sla_breach_count(&new_verifier, &0), 1);
+}
+
+#[test]
+fn test_transfer_pending_queue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset) = setup_env(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let deadline = env.ledger().timestamp() + 86400;
+    for quest_id in [symbol_short!("quest1"), symbol_short!("quest2")] {
+        client.register_quest(
+            &quest_id,
+            &creator,
+            &reward_asset,
+            &1000_i128,
+            &verifier,
+            &deadline,
+            &10,
+            &QuestDifficulty::Beginner,
+        );
+    }
+
+    let quest_id = symbol_short!("quest1");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.submit_proof(&quest_id, &alice, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_proof(&quest_id, &bob, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_proof(
+        &symbol_short!("quest2"),
+        &alice,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+    assert_eq!(
+        client.get_submission(&quest_id, &alice).verifier_address,
+        verifier
+    );
+
+    // The old verifier must match the quest's current verifier
+    let new_verifier = Address::generate(&env);
+    assert_eq!(
+        client.try_transfer_pending_queue(&admin, &new_verifier, &verifier, &quest_id),
+        Err(Ok(Error::UnauthorizedVerifier))
+    );
+
+    let moved = client.transfer_pending_queue(&admin, &verifier, &new_verifier, &quest_id);
+    assert_eq!(moved, 2);
+    assert_eq!(client.get_quest(&quest_id).verifier, new_verifier);
+    assert_eq!(
+        client.get_submission(&quest_id, &bob).verifier_address,
+        new_verifier
+    );
+
+    // Other quests stay with the old verifier
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert_eq!(client.get_sla_breach_count(&verifier, &0), 1);
+    assert_eq!(client.get_sla_breach_count(&new_verifier, &0), 2);
+
+    // The new verifier can now review the moved submissions
+    client.approve_submission(&quest_id, &alice, &new_verifier);
+    assert_eq!(client.get_sla_breach_count(&new_verifier, &0), 1);
+}
+
+#[test]
+fn test_dual_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, _reward_asset, submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+
+    // The creator cannot approve until dual approval is enabled
+    assert_eq!(
+        client.try_approve_submission(&quest_id, &submitter, &creator),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_dual_approval_required(&quest_id, &creator, &true);
+
+    // The first approval is only recorded
+    client.approve_submission(&quest_id, &submitter, &creator);
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+    assert_eq!(
+        client.get_partial_approvals(&quest_id, &submitter),
+        soroban_sdk::vec![&env, creator.clone()]
+    );
+    assert_eq!(client.get_quest(&quest_id).total_claims, 0);
+
+    // No party can vote twice
+    assert_eq!(
+        client.try_approve_submission(&quest_id, &submitter, &creator),
+        Err(Ok(Error::AlreadyApproved))
+    );
+
+    // The second distinct party finalizes the approval
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::Approved);
+    assert_eq!(submission.partial_approvals.len(), 2);
+    assert_eq!(client.get_quest(&quest_id).total_claims, 1);
+    assert_eq!(client.get_quest_pending_count(&quest_id), 0);
+    assert_eq!(client.get_verifier_stats(&verifier).total_approved, 1);
+}
+
+#[test]
+fn test_verification_templates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, _reward_asset, _submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+    let criteria = BytesN::from_array(&env, &[7u8; 32]);
+
+    // Templates shipped before toggles, so they are on until the admin switches them off
+    assert!(client.is_feature_enabled(&symbol_short!("templates")));
+    assert!(!client.is_feature_enabled(&symbol_short!("unknown")));
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_feature_flag(&admin, &symbol_short!("templates"), &false);
+    assert_eq!(
+        client.try_create_verification_template(&verifier, &symbol_short!("code"), &criteria),
+        Err(Ok(Error::FeatureDisabled))
+    );
+    client.set_feature_flag(&admin, &symbol_short!("templates"), &true);
+
+    let first = client.create_verification_template(&verifier, &symbol_short!("code"), &criteria);
+    let second = client.create_verification_template(&verifier, &symbol_short!("docs"), &criteria);
+    assert_eq!((first, second), (1, 2));
+    assert_eq!(
+        client.list_templates_by_verifier(&verifier),
+        soroban_sdk::vec![&env, 1, 2]
+    );
+
+    let template = client.get_verification_template(&second);
+    assert_eq!(template.verifier, verifier);
+    assert_eq!(template.name, symbol_short!("docs"));
+    assert_eq!(template.criteria_hash, criteria);
+    assert_eq!(
+        client.try_get_verification_template(&3),
+        Err(Ok(Error::TemplateNotFound))
+    );
+
+    // Only the creator can attach an existing template
+    assert_eq!(
+        client.try_attach_verification_template(&quest_id, &verifier, &first),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_attach_verification_template(&quest_id, &creator, &3),
+        Err(Ok(Error::TemplateNotFound))
+    );
+    client.attach_verification_template(&quest_id, &creator, &first);
+    assert_eq!(
+        client.get_quest(&quest_id).verification_template_id,
+        Some(first)
+    );
+}
+
+#[test]
+fn test_claim_for_review() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, _reward_asset, submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+    client.set_dual_approval_required(&quest_id, &creator, &true);
+
+    // Only a party that may approve can claim
+    assert_eq!(
+        client.try_claim_for_review(&quest_id, &submitter, &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.claim_for_review(&quest_id, &submitter, &verifier);
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::UnderReview);
+    assert_eq!(submission.reviewing_verifier, Some(verifier.clone()));
+    assert_eq!(
+        submission.review_deadline,
+        env.ledger().timestamp() + 24 * 3600
+    );
+    assert_eq!(
+        client.try_claim_for_review(&quest_id, &submitter, &creator),
+        Err(Ok(Error::InvalidSubmissionStatus))
+    );
+
+    // The claim locks out other reviewers until it expires
+    assert_eq!(
+        client.try_approve_submission(&quest_id, &submitter, &creator),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_unclaim_review(&quest_id, &submitter),
+        Err(Ok(Error::ReviewLockActive))
+    );
+    env.ledger().with_mut(|li| li.timestamp += 24 * 3600);
+    client.unclaim_review(&quest_id, &submitter);
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+    assert_eq!(submission.reviewing_verifier, None);
+
+    // The claiming reviewer can decide on a claimed submission
+    client.claim_for_review(&quest_id, &submitter, &creator);
+    client.approve_submission(&quest_id, &submitter, &creator);
+    assert_eq!(
+        client.get_submission(&quest_id, &submitter).status,
+        SubmissionStatus::UnderReview
+    );
+    assert_eq!(
+        client.try_reject_submission(&quest_id, &submitter, &verifier),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_predict_approval_time_and_queue_position() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _creator, verifier, _reward_asset, submitter) = setup_quest_with_submission(&env);
+    let quest_id = symbol_short!("quest1");
+    let start = env.ledger().timestamp();
+
+    // No review history yet
+    assert_eq!(client.predict_approval_time(&quest_id), None);
+    assert_eq!(client.get_queue_position(&quest_id, &submitter), 1);
+
+    env.ledger().with_mut(|l| l.timestamp = start + 3600);
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    assert_eq!(client.predict_approval_time(&quest_id), Some(3600));
+    assert_eq!(
+        client.try_get_queue_position(&quest_id, &submitter),
+        Err(Ok(Error::InvalidSubmissionStatus))
+    );
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.submit_proof(&quest_id, &first, &BytesN::from_array(&env, &[2u8; 32]));
+    env.ledger().with_mut(|l| l.timestamp = start + 4000);
+    client.submit_proof(&quest_id, &second, &BytesN::from_array(&env, &[3u8; 32]));
+
+    // Each queued submission adds an average review time
+    assert_eq!(client.predict_approval_time(&quest_id), Some(3 * 3600));
+    assert_eq!(client.get_queue_position(&quest_id, &first), 1);
+    assert_eq!(client.get_queue_position(&quest_id, &second), 2);
+    assert_eq!(
+        client.try_get_queue_position(&quest_id, &Address::generate(&env)),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+    assert_eq!(
+        client.predict_approval_time(&symbol_short!("missing")),
+        None
+    );
+}
+
+#[test]
+fn test_verifier_load_balancing_hint() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // `verifier` has one pending submission on quest1
+    let (client, _creator, verifier, _reward_asset, _submitter) = setup_quest_with_submission(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let idle = Address::generate(&env);
+
+    assert_eq!(
+        client.try_register_verifier(&verifier, &verifier),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.register_verifier(&admin, &verifier);
+    client.register_verifier(&admin, &idle);
+    client.register_verifier(&admin, &idle);
+    assert_eq!(
+        client.list_registered_verifiers(),
+        vec![&env, verifier.clone(), idle.clone()]
+    );
+
+    // The least loaded verifier comes first
+    assert_eq!(
+        client.get_load_balancing_hint(),
+        vec![&env, (idle.clone(), 0), (verifier.clone(), 1)]
+    );
+
+    client.deregister_verifier(&admin, &idle);
+    assert_eq!(
+        client.get_load_balancing_hint(),
+        vec![&env, (verifier.clone(), 1)]
+    );
+}